@@ -0,0 +1,106 @@
+use crate::{math::Float, units::coordinate::Coordinate};
+
+/// A latitude and longitude aligned area. The west edge is east of the east edge when the area
+/// crosses the antimeridian.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CoordinateBounds {
+    pub north: Float,
+    pub east: Float,
+    pub south: Float,
+    pub west: Float,
+}
+
+impl CoordinateBounds {
+    pub fn new(north: Float, east: Float, south: Float, west: Float) -> Self {
+        CoordinateBounds {
+            north,
+            east,
+            south,
+            west,
+        }
+    }
+
+    /// The height of the area in degrees of latitude
+    pub fn height(&self) -> Float {
+        self.north - self.south
+    }
+
+    /// The width of the area in degrees of longitude
+    pub fn width(&self) -> Float {
+        if self.crosses_antimeridian() {
+            360.0 - self.west + self.east
+        } else {
+            self.east - self.west
+        }
+    }
+
+    pub fn center(&self) -> Coordinate {
+        let mut longitude = self.west + self.width() / 2.0;
+        if longitude > 180.0 {
+            longitude -= 360.0;
+        }
+        Coordinate::new(self.south + self.height() / 2.0, longitude)
+    }
+
+    /// Returns true if the coordinate is inside the area or on its edge
+    pub fn contains(&self, coordinate: &Coordinate) -> bool {
+        let in_latitude = (self.south..=self.north).contains(&coordinate.latitude);
+        let in_longitude = if self.crosses_antimeridian() {
+            coordinate.longitude >= self.west || coordinate.longitude <= self.east
+        } else {
+            (self.west..=self.east).contains(&coordinate.longitude)
+        };
+        in_latitude && in_longitude
+    }
+
+    fn crosses_antimeridian(&self) -> bool {
+        self.west > self.east
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::assert_approx_eq;
+
+    #[rstest]
+    #[case(CoordinateBounds::new(10.0, 20.0, 0.0, 10.0), 10.0, 10.0, 5.0, 15.0)]
+    #[case(CoordinateBounds::new(10.0, -170.0, -10.0, 170.0), 20.0, 20.0, 0.0, 180.0)]
+    #[case(CoordinateBounds::new(10.0, -160.0, -10.0, 170.0), 20.0, 30.0, 0.0, -175.0)]
+    fn test_size_and_center(
+        #[case] bounds: CoordinateBounds,
+        #[case] height: Float,
+        #[case] width: Float,
+        #[case] latitude: Float,
+        #[case] longitude: Float,
+    ) {
+        assert_approx_eq!(bounds.height(), height);
+        assert_approx_eq!(bounds.width(), width);
+        let center = bounds.center();
+        assert_approx_eq!(center.latitude, latitude);
+        assert_approx_eq!(center.longitude, longitude);
+    }
+
+    #[rstest]
+    #[case(CoordinateBounds::new(10.0, 20.0, 0.0, 10.0), 5.0, 15.0, true)]
+    #[case(CoordinateBounds::new(10.0, 20.0, 0.0, 10.0), 10.0, 20.0, true)]
+    #[case(CoordinateBounds::new(10.0, 20.0, 0.0, 10.0), 11.0, 15.0, false)]
+    #[case(CoordinateBounds::new(10.0, 20.0, 0.0, 10.0), 5.0, 21.0, false)]
+    #[case(CoordinateBounds::new(10.0, -170.0, -10.0, 170.0), 0.0, 175.0, true)]
+    #[case(CoordinateBounds::new(10.0, -170.0, -10.0, 170.0), 0.0, -175.0, true)]
+    #[case(CoordinateBounds::new(10.0, -170.0, -10.0, 170.0), 0.0, 0.0, false)]
+    fn test_contains(
+        #[case] bounds: CoordinateBounds,
+        #[case] latitude: Float,
+        #[case] longitude: Float,
+        #[case] expected: bool,
+    ) {
+        assert_eq!(
+            bounds.contains(&Coordinate::new(latitude, longitude)),
+            expected
+        );
+    }
+}
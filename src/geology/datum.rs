@@ -0,0 +1,210 @@
+use crate::{
+    math::{
+        Float,
+        linear_algebra::{Matrix, Vector},
+    },
+    units::coordinate::Coordinate,
+};
+
+use super::{
+    ecef::{ecef_to_geodetic, geodetic_to_ecef},
+    ellipsoid::Ellipsoid,
+};
+
+/// A seven parameter (Helmert) transformation between Earth-centered Earth-fixed frames, using the
+/// position vector rotation convention
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct HelmertTransform {
+    /// Translation in meters
+    pub translation: [Float; 3],
+    /// Scale in parts per million
+    pub scale: Float,
+    /// Rotation in arc seconds
+    pub rotation: [Float; 3],
+}
+
+impl HelmertTransform {
+    pub const IDENTITY: HelmertTransform = HelmertTransform {
+        translation: [0.0, 0.0, 0.0],
+        scale: 0.0,
+        rotation: [0.0, 0.0, 0.0],
+    };
+
+    pub fn new(translation: [Float; 3], scale: Float, rotation: [Float; 3]) -> Self {
+        HelmertTransform {
+            translation,
+            scale,
+            rotation,
+        }
+    }
+
+    /// Returns the reverse transformation. This negates the parameters, which is accurate to a few
+    /// millimeters for the small rotations and scales used between datums.
+    pub fn inverse(&self) -> Self {
+        HelmertTransform {
+            translation: self.translation.map(|t| -t),
+            scale: -self.scale,
+            rotation: self.rotation.map(|r| -r),
+        }
+    }
+
+    /// Transforms an Earth-centered Earth-fixed position in meters
+    pub fn apply(&self, point: &Vector) -> Vector {
+        let [rx, ry, rz] = self.rotation.map(|r| (r / 3600.0).to_radians());
+        let s = 1.0 + self.scale * 1e-6;
+        let [tx, ty, tz] = self.translation;
+        let matrix = Matrix::from(vec![vec![s, -rz, ry], vec![rz, s, -rx], vec![-ry, rx, s]]);
+        matrix.dot(point).to_vector() + Vector::new_3d(tx, ty, tz)
+    }
+}
+
+/// A geodetic datum, which places an ellipsoid relative to the Earth
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Datum {
+    pub ellipsoid: Ellipsoid,
+    /// The transformation from WGS84 to this datum
+    pub from_wgs84: HelmertTransform,
+}
+
+impl Datum {
+    pub const WGS84: Datum = Datum {
+        ellipsoid: Ellipsoid::WGS84,
+        from_wgs84: HelmertTransform::IDENTITY,
+    };
+
+    /// North American Datum of 1983
+    pub const NAD83: Datum = Datum {
+        ellipsoid: Ellipsoid::GRS80,
+        from_wgs84: HelmertTransform {
+            translation: [0.9956, -1.9103, -0.5215],
+            scale: -0.00062,
+            rotation: [0.025915, 0.009426, 0.011599],
+        },
+    };
+
+    /// North American Datum of 1927, using the mean shift for the contiguous United States (about 5 m)
+    pub const NAD27: Datum = Datum {
+        ellipsoid: Ellipsoid::CLARKE_1866,
+        from_wgs84: HelmertTransform {
+            translation: [8.0, -160.0, -176.0],
+            scale: 0.0,
+            rotation: [0.0, 0.0, 0.0],
+        },
+    };
+
+    /// European Datum 1950
+    pub const ED50: Datum = Datum {
+        ellipsoid: Ellipsoid::INTERNATIONAL_1924,
+        from_wgs84: HelmertTransform {
+            translation: [89.5, 93.8, 123.1],
+            scale: -1.2,
+            rotation: [0.0, 0.0, 0.156],
+        },
+    };
+
+    /// The Ordnance Survey datum of Great Britain (about 5 m)
+    pub const OSGB36: Datum = Datum {
+        ellipsoid: Ellipsoid::AIRY_1830,
+        from_wgs84: HelmertTransform {
+            translation: [-446.448, 125.157, -542.060],
+            scale: 20.4894,
+            rotation: [-0.1502, -0.2470, -0.8421],
+        },
+    };
+
+    pub fn new(ellipsoid: Ellipsoid, from_wgs84: HelmertTransform) -> Self {
+        Datum {
+            ellipsoid,
+            from_wgs84,
+        }
+    }
+}
+
+/// Converts a coordinate and height in meters above the ellipsoid from one datum to another, going
+/// through WGS84
+pub fn transform_datum(
+    coordinate: &Coordinate,
+    altitude: Float,
+    from: &Datum,
+    to: &Datum,
+) -> (Coordinate, Float) {
+    let mut point = geodetic_to_ecef(coordinate, altitude, &from.ellipsoid);
+    if from.from_wgs84 != HelmertTransform::IDENTITY {
+        point = from.from_wgs84.inverse().apply(&point);
+    }
+    if to.from_wgs84 != HelmertTransform::IDENTITY {
+        point = to.from_wgs84.apply(&point);
+    }
+    ecef_to_geodetic(&point, &to.ellipsoid)
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::assert_approx_eq;
+
+    #[rstest]
+    #[case(Coordinate::new(51.47788, -0.00147), Datum::WGS84, Datum::OSGB36, 51.47736, 0.00015, -45.905)]
+    #[case(Coordinate::new(40.0, -100.0), Datum::WGS84, Datum::NAD27, 39.99999, -99.99958, 35.216)]
+    #[case(Coordinate::new(40.0, -100.0), Datum::NAD27, Datum::WGS84, 40.00001, -100.00042, -35.216)]
+    #[case(Coordinate::new(40.0, -100.0), Datum::WGS84, Datum::NAD83, 39.99998, -99.99998, 0.967)]
+    #[case(Coordinate::new(48.8584, 2.2945), Datum::WGS84, Datum::ED50, 48.85931, 2.29577, -52.882)]
+    #[case(
+        Coordinate::new(48.8584, 2.2945),
+        Datum::ED50,
+        Datum::OSGB36,
+        48.85663,
+        2.29499,
+        14.253
+    )]
+    #[case(
+        Coordinate::new(48.8584, 2.2945),
+        Datum::WGS84,
+        Datum::WGS84,
+        48.8584,
+        2.2945,
+        0.0
+    )]
+    fn test_transform_datum(
+        #[case] coordinate: Coordinate,
+        #[case] from: Datum,
+        #[case] to: Datum,
+        #[case] latitude: Float,
+        #[case] longitude: Float,
+        #[case] altitude: Float,
+    ) {
+        let (actual, actual_altitude) = transform_datum(&coordinate, 0.0, &from, &to);
+        assert_approx_eq!(actual.latitude, latitude, 1e-5);
+        assert_approx_eq!(actual.longitude, longitude, 1e-5);
+        assert_approx_eq!(actual_altitude, altitude, 1.0);
+    }
+
+    #[test]
+    fn test_transform_datum_round_trip() {
+        let coordinate = Coordinate::new(48.8584, 2.2945);
+        let (ed50, altitude) = transform_datum(&coordinate, 100.0, &Datum::WGS84, &Datum::ED50);
+        let (actual, actual_altitude) =
+            transform_datum(&ed50, altitude, &Datum::ED50, &Datum::WGS84);
+        assert_approx_eq!(actual.latitude, coordinate.latitude, 1e-5);
+        assert_approx_eq!(actual.longitude, coordinate.longitude, 1e-5);
+        assert_approx_eq!(actual_altitude, 100.0, 1.0);
+    }
+
+    #[test]
+    fn test_helmert_transform() {
+        let transform = HelmertTransform::new([1.0, 2.0, 3.0], 10.0, [0.0, 0.0, 1.0]);
+        let point = transform.apply(&Vector::new_3d(100000.0, 0.0, 0.0));
+        assert_approx_eq!(point.x, 100002.0, 0.01);
+        assert_approx_eq!(point.y, 2.485, 0.01);
+        assert_approx_eq!(point.z, 3.0, 0.01);
+
+        let inverse = transform.inverse().apply(&point);
+        assert_approx_eq!(inverse.x, 100000.0, 0.01);
+        assert_approx_eq!(inverse.y, 0.0, 0.01);
+        assert_approx_eq!(inverse.z, 0.0, 0.01);
+    }
+}
@@ -0,0 +1,142 @@
+use crate::{
+    math::{
+        Float,
+        linear_algebra::{Matrix, Vector},
+    },
+    units::coordinate::Coordinate,
+};
+
+use super::ellipsoid::Ellipsoid;
+
+/// Converts a coordinate and height in meters above the ellipsoid to an Earth-centered Earth-fixed
+/// (ECEF) position in meters. X points to the prime meridian at the equator, Y to 90° east and Z to
+/// the north pole.
+pub fn geodetic_to_ecef(coordinate: &Coordinate, altitude: Float, ellipsoid: &Ellipsoid) -> Vector {
+    let (sin_phi, cos_phi) = coordinate.latitude.to_radians().sin_cos();
+    let (sin_lambda, cos_lambda) = coordinate.longitude.to_radians().sin_cos();
+    let e_sq = ellipsoid.eccentricity_squared();
+    let nu = ellipsoid.semi_major_axis / (1.0 - e_sq * sin_phi * sin_phi).sqrt();
+    Vector::new_3d(
+        (nu + altitude) * cos_phi * cos_lambda,
+        (nu + altitude) * cos_phi * sin_lambda,
+        (nu * (1.0 - e_sq) + altitude) * sin_phi,
+    )
+}
+
+/// Converts an Earth-centered Earth-fixed position in meters to a coordinate and height in meters
+/// above the ellipsoid
+pub fn ecef_to_geodetic(point: &Vector, ellipsoid: &Ellipsoid) -> (Coordinate, Float) {
+    let e_sq = ellipsoid.eccentricity_squared();
+    let p = point.x.hypot(point.y);
+    let mut phi = point.z.atan2(p * (1.0 - e_sq));
+    for _ in 0..10 {
+        let nu = ellipsoid.semi_major_axis / (1.0 - e_sq * phi.sin().powi(2)).sqrt();
+        phi = (point.z + e_sq * nu * phi.sin()).atan2(p);
+    }
+    let (sin_phi, cos_phi) = phi.sin_cos();
+    // This form of the height stays accurate near the poles
+    let altitude = p * cos_phi + point.z * sin_phi
+        - ellipsoid.semi_major_axis * (1.0 - e_sq * sin_phi * sin_phi).sqrt();
+    (
+        Coordinate::new(phi.to_degrees(), point.y.atan2(point.x).to_degrees()),
+        altitude,
+    )
+}
+
+/// Returns the Earth-centered Earth-fixed position in meters east, north and up of the origin, in the
+/// plane tangent to the ellipsoid at the origin
+pub fn ecef_to_enu(
+    point: &Vector,
+    origin: &Coordinate,
+    origin_altitude: Float,
+    ellipsoid: &Ellipsoid,
+) -> Vector {
+    let offset = point.clone() - geodetic_to_ecef(origin, origin_altitude, ellipsoid);
+    enu_rotation(origin).dot(&offset).to_vector()
+}
+
+/// Returns the Earth-centered Earth-fixed position in meters of a point east, north and up of the
+/// origin in meters
+pub fn enu_to_ecef(
+    point: &Vector,
+    origin: &Coordinate,
+    origin_altitude: Float,
+    ellipsoid: &Ellipsoid,
+) -> Vector {
+    let offset = enu_rotation(origin).transpose().dot(point).to_vector();
+    geodetic_to_ecef(origin, origin_altitude, ellipsoid) + offset
+}
+
+/// The rotation from Earth-centered Earth-fixed to east, north and up at the origin. The rows are the
+/// east, north and up directions.
+fn enu_rotation(origin: &Coordinate) -> Matrix {
+    let (sin_phi, cos_phi) = origin.latitude.to_radians().sin_cos();
+    let (sin_lambda, cos_lambda) = origin.longitude.to_radians().sin_cos();
+    Matrix::from(vec![
+        vec![-sin_lambda, cos_lambda, 0.0],
+        vec![-sin_phi * cos_lambda, -sin_phi * sin_lambda, cos_phi],
+        vec![cos_phi * cos_lambda, cos_phi * sin_lambda, sin_phi],
+    ])
+}
+
+#[cfg(test)]
+#[allow(clippy::excessive_precision)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::assert_approx_eq;
+
+    #[rstest]
+    #[case(0.0, 0.0, 0.0, 6378137.0, 0.0, 0.0)]
+    #[case(90.0, 0.0, 0.0, 0.0, 0.0, 6356752.314)]
+    #[case(0.0, 90.0, 100.0, 0.0, 6378237.0, 0.0)]
+    #[case(45.0, 45.0, 1000.0, 3194919.145, 3194919.145, 4488055.516)]
+    #[case(-33.8568, 151.2153, 58.0, -4647010.851, 2553100.113, -3533299.440)]
+    fn test_geodetic_to_ecef(
+        #[case] latitude: Float,
+        #[case] longitude: Float,
+        #[case] altitude: Float,
+        #[case] x: Float,
+        #[case] y: Float,
+        #[case] z: Float,
+    ) {
+        let coordinate = Coordinate::new(latitude, longitude);
+        let point = geodetic_to_ecef(&coordinate, altitude, &Ellipsoid::WGS84);
+        assert_approx_eq!(point.x, x, 1.0);
+        assert_approx_eq!(point.y, y, 1.0);
+        assert_approx_eq!(point.z, z, 1.0);
+
+        let (actual, actual_altitude) = ecef_to_geodetic(&point, &Ellipsoid::WGS84);
+        assert_approx_eq!(actual.latitude, latitude, 1e-5);
+        assert_approx_eq!(actual_altitude, altitude, 1.0);
+        // The longitude is undefined at the poles
+        if latitude.abs() < 90.0 {
+            assert_approx_eq!(actual.longitude, longitude, 1e-5);
+        }
+    }
+
+    #[rstest]
+    #[case(Coordinate::new(0.01, 0.0), 0.0, Coordinate::new(0.0, 0.0), 0.0, (0.0, 1105.743, -0.096))]
+    #[case(Coordinate::new(0.0, 0.01), 0.0, Coordinate::new(0.0, 0.0), 0.0, (1113.195, 0.0, -0.097))]
+    #[case(Coordinate::new(0.0, 0.0), 50.0, Coordinate::new(0.0, 0.0), 0.0, (0.0, 0.0, 50.0))]
+    #[case(Coordinate::new(45.001, 45.002), 1100.0, Coordinate::new(45.0, 45.0), 1000.0, (157.718, 111.153, 99.997))]
+    fn test_ecef_to_enu(
+        #[case] coordinate: Coordinate,
+        #[case] altitude: Float,
+        #[case] origin: Coordinate,
+        #[case] origin_altitude: Float,
+        #[case] expected: (Float, Float, Float),
+    ) {
+        let point = geodetic_to_ecef(&coordinate, altitude, &Ellipsoid::WGS84);
+        let enu = ecef_to_enu(&point, &origin, origin_altitude, &Ellipsoid::WGS84);
+        assert_approx_eq!(enu.x, expected.0, 1.0);
+        assert_approx_eq!(enu.y, expected.1, 1.0);
+        assert_approx_eq!(enu.z, expected.2, 1.0);
+
+        let actual = enu_to_ecef(&enu, &origin, origin_altitude, &Ellipsoid::WGS84);
+        assert_approx_eq!(actual.x, point.x, 1.0);
+        assert_approx_eq!(actual.y, point.y, 1.0);
+        assert_approx_eq!(actual.z, point.z, 1.0);
+    }
+}
@@ -0,0 +1,73 @@
+use crate::math::Float;
+
+/// A reference ellipsoid used to model the shape of the Earth
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Ellipsoid {
+    /// The equatorial radius in meters
+    pub semi_major_axis: Float,
+    pub flattening: Float,
+}
+
+// Constants are written at full precision so they are exact when Float is f64
+#[allow(clippy::excessive_precision)]
+impl Ellipsoid {
+    pub const WGS84: Ellipsoid = Ellipsoid {
+        semi_major_axis: 6378137.0,
+        flattening: 1.0 / 298.257_223_563,
+    };
+
+    /// The ellipsoid of the Ordnance Survey's OSGB36 datum (Great Britain)
+    pub const AIRY_1830: Ellipsoid = Ellipsoid {
+        semi_major_axis: 6377563.396,
+        flattening: 1.0 / 299.324_964_6,
+    };
+
+    /// The ellipsoid of NAD83 and ETRS89, which differs from WGS84 by less than a millimeter
+    pub const GRS80: Ellipsoid = Ellipsoid {
+        semi_major_axis: 6378137.0,
+        flattening: 1.0 / 298.257_222_101,
+    };
+
+    /// The ellipsoid of the NAD27 datum (North America)
+    pub const CLARKE_1866: Ellipsoid = Ellipsoid {
+        semi_major_axis: 6378206.4,
+        flattening: 1.0 / 294.978_698_214,
+    };
+
+    /// The ellipsoid of the ED50 datum (Europe), also known as Hayford
+    pub const INTERNATIONAL_1924: Ellipsoid = Ellipsoid {
+        semi_major_axis: 6378388.0,
+        flattening: 1.0 / 297.0,
+    };
+
+    pub fn new(semi_major_axis: Float, flattening: Float) -> Self {
+        Ellipsoid {
+            semi_major_axis,
+            flattening,
+        }
+    }
+
+    /// The polar radius in meters
+    pub fn semi_minor_axis(&self) -> Float {
+        self.semi_major_axis * (1.0 - self.flattening)
+    }
+
+    /// The square of the first eccentricity
+    pub fn eccentricity_squared(&self) -> Float {
+        self.flattening * (2.0 - self.flattening)
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::excessive_precision)]
+mod tests {
+    use super::*;
+    use crate::assert_approx_eq;
+
+    #[test]
+    fn test_wgs84() {
+        assert_approx_eq!(Ellipsoid::WGS84.semi_minor_axis(), 6356752.314245, 0.5);
+        assert_approx_eq!(Ellipsoid::WGS84.eccentricity_squared(), 0.00669438, 1e-7);
+    }
+}
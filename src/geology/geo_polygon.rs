@@ -0,0 +1,300 @@
+use crate::{
+    math::{Float, consts::PI},
+    units::{area::Area, coordinate::Coordinate, distance::Distance, quantity::Quantity},
+};
+
+use super::{
+    bounds::CoordinateBounds,
+    ellipsoid::Ellipsoid,
+    geodesy::{great_circle_distance_to_segment, haversine_distance, normalize_longitude},
+};
+
+/// A polygon on the Earth with geodesic (shortest path) edges. The last vertex connects back to the
+/// first. Polygons may cross the antimeridian or go around a pole, but must be smaller than a
+/// hemisphere.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GeoPolygon {
+    pub vertices: Vec<Coordinate>,
+}
+
+impl GeoPolygon {
+    pub fn new(vertices: Vec<Coordinate>) -> Self {
+        GeoPolygon { vertices }
+    }
+
+    /// Returns the area on the WGS84 ellipsoid. The edges are treated as great circles on the sphere
+    /// with the same area as the ellipsoid, which is accurate to about 0.1% for polygons up to a few
+    /// thousand kilometers across.
+    pub fn area(&self) -> Quantity<Area> {
+        if self.vertices.len() < 3 {
+            return Quantity::new(0.0, Area::SquareMeters);
+        }
+        let ellipsoid = Ellipsoid::WGS84;
+        let q_pole = authalic_q(PI / 2.0, &ellipsoid);
+        let tan_halves: Vec<Float> = self
+            .vertices
+            .iter()
+            .map(|vertex| {
+                let q = authalic_q(vertex.latitude.to_radians(), &ellipsoid);
+                ((q / q_pole).clamp(-1.0, 1.0).asin() / 2.0).tan()
+            })
+            .collect();
+
+        // Sum the signed areas between each edge and the south pole
+        let mut excess = 0.0;
+        for (i, j) in self.edge_indices() {
+            let delta = self.longitude_delta(i, j).to_radians();
+            let t1 = tan_halves[i];
+            let t2 = tan_halves[j];
+            excess += 2.0 * ((delta / 2.0).tan() * (t1 + t2)).atan2(1.0 + t1 * t2);
+        }
+        let mut steradians = excess.abs();
+        if self.encircles_pole() {
+            steradians = 2.0 * PI - steradians;
+        }
+        // Use the smaller of the two areas the polygon splits the Earth into
+        steradians = steradians.min(4.0 * PI - steradians);
+
+        let radius_squared = ellipsoid.semi_major_axis.powi(2) * q_pole / 2.0;
+        Quantity::new(steradians * radius_squared, Area::SquareMeters)
+    }
+
+    /// Returns the length of the edges on the WGS84 ellipsoid
+    pub fn perimeter(&self) -> Quantity<Distance> {
+        if self.vertices.len() < 2 {
+            return Quantity::new(0.0, Distance::Meters);
+        }
+        let meters = self
+            .edge_indices()
+            .map(|(i, j)| self.vertices[i].distance_to(&self.vertices[j]).amount)
+            .sum();
+        Quantity::new(meters, Distance::Meters)
+    }
+
+    /// Returns true if the coordinate is inside the polygon. Points exactly on an edge may be inside or
+    /// outside.
+    pub fn contains(&self, coordinate: &Coordinate) -> bool {
+        if self.vertices.len() < 3 {
+            return false;
+        }
+
+        // Count the edges crossed by the meridian from the coordinate to the north pole
+        let mut crossings = 0;
+        for (i, j) in self.edge_indices() {
+            let start = &self.vertices[i];
+            let end = &self.vertices[j];
+            let edge = self.longitude_delta(i, j);
+            let offset = normalize_longitude(coordinate.longitude - start.longitude);
+            if (0.0 > offset) == (edge > offset) {
+                continue;
+            }
+            let edge_radians = edge.to_radians();
+            let offset_radians = offset.to_radians();
+            let tan_latitude = (start.latitude.to_radians().tan()
+                * (edge_radians - offset_radians).sin()
+                + end.latitude.to_radians().tan() * offset_radians.sin())
+                / edge_radians.sin();
+            if tan_latitude.atan().to_degrees() > coordinate.latitude {
+                crossings += 1;
+            }
+        }
+
+        let is_north_pole_inside = self.encircles_pole() && self.mean_latitude() > 0.0;
+        (crossings % 2 == 1) != is_north_pole_inside
+    }
+
+    /// Returns the shortest distance from the coordinate to an edge of the polygon, on a sphere with the
+    /// Earth's mean radius
+    pub fn distance_to_boundary(&self, coordinate: &Coordinate) -> Quantity<Distance> {
+        let meters = match self.vertices.len() {
+            0 => 0.0,
+            1 => haversine_distance(coordinate, &self.vertices[0]),
+            _ => self
+                .edge_indices()
+                .map(|(i, j)| {
+                    great_circle_distance_to_segment(
+                        coordinate,
+                        &self.vertices[i],
+                        &self.vertices[j],
+                    )
+                })
+                .fold(Float::INFINITY, Float::min),
+        };
+        Quantity::new(meters, Distance::Meters)
+    }
+
+    /// Returns the area covered by the vertices, which wraps around the antimeridian when the polygon
+    /// crosses it and reaches the pole when it goes around one. Returns None if there are no vertices.
+    pub fn bounds(&self) -> Option<CoordinateBounds> {
+        let first = self.vertices.first()?;
+        let mut north = first.latitude;
+        let mut south = first.latitude;
+        let mut longitude = first.longitude;
+        let mut east = longitude;
+        let mut west = longitude;
+        for (i, j) in self.edge_indices() {
+            north = north.max(self.vertices[j].latitude);
+            south = south.min(self.vertices[j].latitude);
+            longitude += self.longitude_delta(i, j);
+            east = east.max(longitude);
+            west = west.min(longitude);
+        }
+
+        if self.encircles_pole() {
+            if self.mean_latitude() > 0.0 {
+                north = 90.0;
+            } else {
+                south = -90.0;
+            }
+            return Some(CoordinateBounds::new(north, 180.0, south, -180.0));
+        }
+        if east - west >= 360.0 {
+            return Some(CoordinateBounds::new(north, 180.0, south, -180.0));
+        }
+        Some(CoordinateBounds::new(
+            north,
+            normalize_longitude(east),
+            south,
+            normalize_longitude(west),
+        ))
+    }
+
+    fn edge_indices(&self) -> impl Iterator<Item = (usize, usize)> {
+        let n = self.vertices.len();
+        (0..n).map(move |i| (i, (i + 1) % n))
+    }
+
+    /// The change in longitude along the edge, the short way around
+    fn longitude_delta(&self, start: usize, end: usize) -> Float {
+        normalize_longitude(self.vertices[end].longitude - self.vertices[start].longitude)
+    }
+
+    /// Returns true if the edges go all the way around the Earth's axis
+    fn encircles_pole(&self) -> bool {
+        let winding: Float = self
+            .edge_indices()
+            .map(|(i, j)| self.longitude_delta(i, j))
+            .sum();
+        winding.abs() > 180.0
+    }
+
+    fn mean_latitude(&self) -> Float {
+        self.vertices.iter().map(|v| v.latitude).sum::<Float>() / self.vertices.len() as Float
+    }
+}
+
+/// The q function of the authalic latitude (Snyder 3-12)
+fn authalic_q(latitude: Float, ellipsoid: &Ellipsoid) -> Float {
+    let e_sq = ellipsoid.eccentricity_squared();
+    let e = e_sq.sqrt();
+    let sin_phi = latitude.sin();
+    (1.0 - e_sq)
+        * (sin_phi / (1.0 - e_sq * sin_phi * sin_phi)
+            - 1.0 / (2.0 * e) * ((1.0 - e * sin_phi) / (1.0 + e * sin_phi)).ln())
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::{assert_approx_eq, units::quantity::Convertable};
+
+    fn polygon(vertices: &[(Float, Float)]) -> GeoPolygon {
+        GeoPolygon::new(
+            vertices
+                .iter()
+                .map(|(latitude, longitude)| Coordinate::new(*latitude, *longitude))
+                .collect(),
+        )
+    }
+
+    #[rstest]
+    #[case(&[(0.0, 0.0), (0.0, 90.0), (90.0, 0.0)], 63758202.716)]
+    #[case(&[(0.0, 0.0), (0.0, 1.0), (1.0, 1.0), (1.0, 0.0)], 12308.776)]
+    #[case(&[(1.0, 0.0), (1.0, 1.0), (0.0, 1.0), (0.0, 0.0)], 12308.776)]
+    #[case(&[(0.0, 179.0), (0.0, -179.0), (1.0, -179.0), (1.0, 179.0)], 24619.427)]
+    #[case(&[(80.0, 0.0), (80.0, 90.0), (80.0, 180.0), (80.0, -90.0)], 2507439.5)]
+    #[case(&[(-80.0, 0.0), (-80.0, -90.0), (-80.0, 180.0), (-80.0, 90.0)], 2507439.5)]
+    #[case(&[(0.0, 0.0), (1.0, 1.0)], 0.0)]
+    fn test_area(#[case] vertices: &[(Float, Float)], #[case] square_kilometers: Float) {
+        let area = polygon(vertices).area().convert(Area::SquareKilometers);
+        assert_approx_eq!(
+            area.amount,
+            square_kilometers,
+            square_kilometers * 1e-4 + 1e-3
+        );
+    }
+
+    #[rstest]
+    #[case(&[(0.0, 0.0), (0.0, 1.0), (1.0, 1.0), (1.0, 0.0)], 443.770)]
+    #[case(&[(0.0, 179.0), (0.0, -179.0), (1.0, -179.0), (1.0, 179.0)], 666.393)]
+    #[case(&[(0.0, 0.0)], 0.0)]
+    fn test_perimeter(#[case] vertices: &[(Float, Float)], #[case] kilometers: Float) {
+        let perimeter = polygon(vertices).perimeter().convert(Distance::Kilometers);
+        assert_approx_eq!(perimeter.amount, kilometers, 0.01);
+    }
+
+    #[rstest]
+    #[case(&[(0.0, 0.0), (0.0, 10.0), (10.0, 10.0), (10.0, 0.0)], 5.0, 5.0, true)]
+    #[case(&[(0.0, 0.0), (0.0, 10.0), (10.0, 10.0), (10.0, 0.0)], 5.0, 11.0, false)]
+    #[case(&[(0.0, 0.0), (0.0, 10.0), (10.0, 10.0), (10.0, 0.0)], -1.0, 5.0, false)]
+    #[case(&[(0.0, 0.0), (0.0, 10.0), (10.0, 10.0), (10.0, 0.0)], 5.0, -175.0, false)]
+    #[case(&[(0.0, 0.0), (10.0, 5.0), (0.0, 10.0), (5.0, 5.0)], 3.0, 5.0, false)]
+    #[case(&[(0.0, 0.0), (10.0, 5.0), (0.0, 10.0), (5.0, 5.0)], 3.0, 2.0, true)]
+    #[case(&[(0.0, 179.0), (0.0, -179.0), (1.0, -179.0), (1.0, 179.0)], 0.5, 180.0, true)]
+    #[case(&[(0.0, 179.0), (0.0, -179.0), (1.0, -179.0), (1.0, 179.0)], 0.5, -179.5, true)]
+    #[case(&[(0.0, 179.0), (0.0, -179.0), (1.0, -179.0), (1.0, 179.0)], 0.5, 178.0, false)]
+    #[case(&[(0.0, 179.0), (0.0, -179.0), (1.0, -179.0), (1.0, 179.0)], 0.5, 0.0, false)]
+    #[case(&[(80.0, 0.0), (80.0, 90.0), (80.0, 180.0), (80.0, -90.0)], 89.0, 45.0, true)]
+    #[case(&[(80.0, 0.0), (80.0, 90.0), (80.0, 180.0), (80.0, -90.0)], 70.0, 45.0, false)]
+    #[case(&[(80.0, 0.0), (80.0, 90.0), (80.0, 180.0), (80.0, -90.0)], -89.0, 45.0, false)]
+    #[case(&[(-80.0, 0.0), (-80.0, 90.0), (-80.0, 180.0), (-80.0, -90.0)], -89.0, 45.0, true)]
+    #[case(&[(-80.0, 0.0), (-80.0, 90.0), (-80.0, 180.0), (-80.0, -90.0)], 89.0, 45.0, false)]
+    #[case(&[(0.0, 0.0), (0.0, 10.0)], 0.0, 5.0, false)]
+    fn test_contains(
+        #[case] vertices: &[(Float, Float)],
+        #[case] latitude: Float,
+        #[case] longitude: Float,
+        #[case] expected: bool,
+    ) {
+        assert_eq!(
+            polygon(vertices).contains(&Coordinate::new(latitude, longitude)),
+            expected
+        );
+    }
+
+    #[rstest]
+    #[case(&[(0.0, 0.0), (0.0, 10.0), (10.0, 10.0), (10.0, 0.0)], 5.0, 9.0, 110.772)]
+    #[case(&[(0.0, 0.0), (0.0, 10.0), (10.0, 10.0), (10.0, 0.0)], -1.0, 5.0, 111.195)]
+    #[case(&[(0.0, 0.0), (0.0, 10.0), (10.0, 10.0), (10.0, 0.0)], -1.0, -1.0, 157.249)]
+    #[case(&[(0.0, 179.0), (0.0, -179.0), (1.0, -179.0), (1.0, 179.0)], 0.5, 180.0, 55.597)]
+    #[case(&[(0.0, 0.0), (0.0, 10.0)], 0.0, 5.0, 0.0)]
+    #[case(&[(0.0, 0.0)], 0.0, 1.0, 111.195)]
+    fn test_distance_to_boundary(
+        #[case] vertices: &[(Float, Float)],
+        #[case] latitude: Float,
+        #[case] longitude: Float,
+        #[case] kilometers: Float,
+    ) {
+        let distance = polygon(vertices)
+            .distance_to_boundary(&Coordinate::new(latitude, longitude))
+            .convert(Distance::Kilometers);
+        assert_approx_eq!(distance.amount, kilometers, 0.01);
+    }
+
+    #[rstest]
+    #[case(&[(0.0, 0.0), (0.0, 10.0), (10.0, 10.0), (10.0, 0.0)], CoordinateBounds::new(10.0, 10.0, 0.0, 0.0))]
+    #[case(&[(0.0, 179.0), (0.0, -179.0), (1.0, -179.0), (1.0, 179.0)], CoordinateBounds::new(1.0, -179.0, 0.0, 179.0))]
+    #[case(&[(80.0, 0.0), (80.0, 90.0), (80.0, 180.0), (80.0, -90.0)], CoordinateBounds::new(90.0, 180.0, 80.0, -180.0))]
+    #[case(&[(-80.0, 0.0), (-80.0, 90.0), (-80.0, 180.0), (-80.0, -90.0)], CoordinateBounds::new(-80.0, 180.0, -90.0, -180.0))]
+    fn test_bounds(#[case] vertices: &[(Float, Float)], #[case] expected: CoordinateBounds) {
+        assert_eq!(polygon(vertices).bounds(), Some(expected));
+    }
+
+    #[test]
+    fn test_bounds_empty() {
+        assert_eq!(GeoPolygon::new(vec![]).bounds(), None);
+    }
+}
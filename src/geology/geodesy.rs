@@ -0,0 +1,432 @@
+use crate::{
+    math::{Float, consts::PI, linear_algebra::Vector},
+    units::coordinate::Coordinate,
+};
+
+use super::{EARTH_MEAN_RADIUS, ellipsoid::Ellipsoid};
+
+const MAX_ITERATIONS: usize = 200;
+
+/// The result of solving the inverse geodesic problem between two coordinates
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Geodesic {
+    /// The length of the geodesic in meters
+    pub distance: Float,
+    /// The azimuth at the start, in degrees clockwise from north
+    pub initial_bearing: Float,
+    /// The azimuth at the end, in degrees clockwise from north
+    pub final_bearing: Float,
+}
+
+/// Returns the great-circle distance between two coordinates in meters, on a sphere with the Earth's
+/// mean radius
+pub fn haversine_distance(start: &Coordinate, end: &Coordinate) -> Float {
+    let lat1 = start.latitude.to_radians();
+    let lat2 = end.latitude.to_radians();
+    let delta_lat = lat2 - lat1;
+    let delta_lon = (end.longitude - start.longitude).to_radians();
+    let a =
+        (delta_lat / 2.0).sin().powi(2) + lat1.cos() * lat2.cos() * (delta_lon / 2.0).sin().powi(2);
+    2.0 * EARTH_MEAN_RADIUS * a.sqrt().min(1.0).asin()
+}
+
+/// Returns the initial great-circle bearing from the start to the end in degrees [0, 360)
+pub fn spherical_bearing(start: &Coordinate, end: &Coordinate) -> Float {
+    let lat1 = start.latitude.to_radians();
+    let lat2 = end.latitude.to_radians();
+    let delta_lon = (end.longitude - start.longitude).to_radians();
+    let y = delta_lon.sin() * lat2.cos();
+    let x = lat1.cos() * lat2.sin() - lat1.sin() * lat2.cos() * delta_lon.cos();
+    normalize_bearing(y.atan2(x).to_degrees())
+}
+
+/// Returns the point halfway along the great circle between two coordinates
+pub fn spherical_midpoint(start: &Coordinate, end: &Coordinate) -> Coordinate {
+    let lat1 = start.latitude.to_radians();
+    let lat2 = end.latitude.to_radians();
+    let delta_lon = (end.longitude - start.longitude).to_radians();
+    let bx = lat2.cos() * delta_lon.cos();
+    let by = lat2.cos() * delta_lon.sin();
+    let latitude = (lat1.sin() + lat2.sin()).atan2(((lat1.cos() + bx).powi(2) + by.powi(2)).sqrt());
+    let longitude = start.longitude.to_radians() + by.atan2(lat1.cos() + bx);
+    Coordinate::new(
+        latitude.to_degrees(),
+        normalize_longitude(longitude.to_degrees()),
+    )
+}
+
+/// Returns the distance in meters from the point to the closest point on the great circle segment
+/// between the start and end, on a sphere with the Earth's mean radius
+pub fn great_circle_distance_to_segment(
+    point: &Coordinate,
+    start: &Coordinate,
+    end: &Coordinate,
+) -> Float {
+    let point = unit_vector(point);
+    let start = unit_vector(start);
+    let end = unit_vector(end);
+    let normal = start.cross(&end);
+    let length = normal.magnitude();
+    if length == 0.0 {
+        return angle_between(&point, &start) * EARTH_MEAN_RADIUS;
+    }
+    let normal = normal.map(|v| v / length);
+
+    // Project the point onto the plane of the great circle and check if it lands between the ends
+    let offset = normal.dot(&point);
+    let projected = point.clone() - normal.map(|v| v * offset);
+    let is_between =
+        start.cross(&projected).dot(&normal) >= 0.0 && projected.cross(&end).dot(&normal) >= 0.0;
+    let angle = if is_between {
+        offset.clamp(-1.0, 1.0).asin().abs()
+    } else {
+        angle_between(&point, &start).min(angle_between(&point, &end))
+    };
+    angle * EARTH_MEAN_RADIUS
+}
+
+/// Solves the inverse geodesic problem on an ellipsoid using Vincenty's formula. Returns None if the
+/// formula does not converge, which happens for nearly antipodal points.
+pub fn vincenty_inverse(
+    start: &Coordinate,
+    end: &Coordinate,
+    ellipsoid: &Ellipsoid,
+) -> Option<Geodesic> {
+    let a = ellipsoid.semi_major_axis;
+    let f = ellipsoid.flattening;
+    let b = ellipsoid.semi_minor_axis();
+
+    let mut l = end.longitude - start.longitude;
+    if l.abs() > 180.0 {
+        // Take the short way across the antimeridian
+        l = normalize_longitude(l);
+    }
+    let l = l.to_radians();
+    let (sin_u1, cos_u1) = reduced_latitude(start.latitude, f).sin_cos();
+    let (sin_u2, cos_u2) = reduced_latitude(end.latitude, f).sin_cos();
+
+    let mut lambda = l;
+    let mut iterations = 0;
+    loop {
+        let (sin_lambda, cos_lambda) = lambda.sin_cos();
+        let sin_sigma = ((cos_u2 * sin_lambda).powi(2)
+            + (cos_u1 * sin_u2 - sin_u1 * cos_u2 * cos_lambda).powi(2))
+        .sqrt();
+        if sin_sigma == 0.0 {
+            // The points are the same
+            return Some(Geodesic {
+                distance: 0.0,
+                initial_bearing: 0.0,
+                final_bearing: 0.0,
+            });
+        }
+        let cos_sigma = sin_u1 * sin_u2 + cos_u1 * cos_u2 * cos_lambda;
+        let sigma = sin_sigma.atan2(cos_sigma);
+        let sin_alpha = cos_u1 * cos_u2 * sin_lambda / sin_sigma;
+        let cos_sq_alpha = 1.0 - sin_alpha.powi(2);
+        // The points are on the equator when cos²α is 0
+        let cos_2_sigma_m = if cos_sq_alpha == 0.0 {
+            0.0
+        } else {
+            cos_sigma - 2.0 * sin_u1 * sin_u2 / cos_sq_alpha
+        };
+        let c = f / 16.0 * cos_sq_alpha * (4.0 + f * (4.0 - 3.0 * cos_sq_alpha));
+        let previous_lambda = lambda;
+        lambda = l
+            + (1.0 - c)
+                * f
+                * sin_alpha
+                * (sigma
+                    + c * sin_sigma
+                        * (cos_2_sigma_m + c * cos_sigma * (-1.0 + 2.0 * cos_2_sigma_m.powi(2))));
+
+        iterations += 1;
+        if lambda.abs() > PI || iterations > MAX_ITERATIONS {
+            return None;
+        }
+
+        if (lambda - previous_lambda).abs() <= tolerance() {
+            let u_sq = cos_sq_alpha * (a.powi(2) - b.powi(2)) / b.powi(2);
+            let (big_a, big_b) = series_coefficients(u_sq);
+            let delta_sigma = delta_sigma(big_b, sin_sigma, cos_sigma, cos_2_sigma_m);
+            let distance = b * big_a * (sigma - delta_sigma);
+
+            let (sin_lambda, cos_lambda) = lambda.sin_cos();
+            let initial_bearing = (cos_u2 * sin_lambda)
+                .atan2(cos_u1 * sin_u2 - sin_u1 * cos_u2 * cos_lambda)
+                .to_degrees();
+            let final_bearing = (cos_u1 * sin_lambda)
+                .atan2(-sin_u1 * cos_u2 + cos_u1 * sin_u2 * cos_lambda)
+                .to_degrees();
+
+            return Some(Geodesic {
+                distance,
+                initial_bearing: normalize_bearing(initial_bearing),
+                final_bearing: normalize_bearing(final_bearing),
+            });
+        }
+    }
+}
+
+/// Solves the direct geodesic problem on an ellipsoid using Vincenty's formula, returning the
+/// destination and the final bearing in degrees. The distance is in meters and the bearing is in degrees.
+pub fn vincenty_direct(
+    start: &Coordinate,
+    distance: Float,
+    bearing: Float,
+    ellipsoid: &Ellipsoid,
+) -> (Coordinate, Float) {
+    let a = ellipsoid.semi_major_axis;
+    let f = ellipsoid.flattening;
+    let b = ellipsoid.semi_minor_axis();
+
+    let (sin_alpha1, cos_alpha1) = bearing.to_radians().sin_cos();
+    let (sin_u1, cos_u1) = reduced_latitude(start.latitude, f).sin_cos();
+    let sigma1 = sin_u1.atan2(cos_u1 * cos_alpha1);
+    let sin_alpha = cos_u1 * sin_alpha1;
+    let cos_sq_alpha = 1.0 - sin_alpha.powi(2);
+    let u_sq = cos_sq_alpha * (a.powi(2) - b.powi(2)) / b.powi(2);
+    let (big_a, big_b) = series_coefficients(u_sq);
+
+    let mut sigma = distance / (b * big_a);
+    let mut iterations = 0;
+    let (sin_sigma, cos_sigma, cos_2_sigma_m) = loop {
+        let cos_2_sigma_m = (2.0 * sigma1 + sigma).cos();
+        let (sin_sigma, cos_sigma) = sigma.sin_cos();
+        let previous_sigma = sigma;
+        sigma = distance / (b * big_a) + delta_sigma(big_b, sin_sigma, cos_sigma, cos_2_sigma_m);
+        iterations += 1;
+        if (sigma - previous_sigma).abs() <= tolerance() || iterations > MAX_ITERATIONS {
+            let (sin_sigma, cos_sigma) = sigma.sin_cos();
+            break (sin_sigma, cos_sigma, (2.0 * sigma1 + sigma).cos());
+        }
+    };
+
+    let x = sin_u1 * sin_sigma - cos_u1 * cos_sigma * cos_alpha1;
+    let latitude = (sin_u1 * cos_sigma + cos_u1 * sin_sigma * cos_alpha1)
+        .atan2((1.0 - f) * (sin_alpha.powi(2) + x.powi(2)).sqrt());
+    let lambda =
+        (sin_sigma * sin_alpha1).atan2(cos_u1 * cos_sigma - sin_u1 * sin_sigma * cos_alpha1);
+    let c = f / 16.0 * cos_sq_alpha * (4.0 + f * (4.0 - 3.0 * cos_sq_alpha));
+    let l = lambda
+        - (1.0 - c)
+            * f
+            * sin_alpha
+            * (sigma
+                + c * sin_sigma
+                    * (cos_2_sigma_m + c * cos_sigma * (-1.0 + 2.0 * cos_2_sigma_m.powi(2))));
+    let final_bearing = sin_alpha.atan2(-x).to_degrees();
+
+    let destination = Coordinate::new(
+        latitude.to_degrees(),
+        normalize_longitude(start.longitude + l.to_degrees()),
+    );
+    (destination, normalize_bearing(final_bearing))
+}
+
+fn unit_vector(coordinate: &Coordinate) -> Vector {
+    let (sin_phi, cos_phi) = coordinate.latitude.to_radians().sin_cos();
+    let (sin_lambda, cos_lambda) = coordinate.longitude.to_radians().sin_cos();
+    Vector::new_3d(cos_phi * cos_lambda, cos_phi * sin_lambda, sin_phi)
+}
+
+/// Returns the angle in radians between two unit vectors
+fn angle_between(a: &Vector, b: &Vector) -> Float {
+    a.cross(b).magnitude().atan2(a.dot(b))
+}
+
+/// Returns the reduced (parametric) latitude in radians. This avoids tan so it stays accurate at the poles.
+fn reduced_latitude(latitude: Float, flattening: Float) -> Float {
+    let (sin, cos) = latitude.to_radians().sin_cos();
+    ((1.0 - flattening) * sin).atan2(cos)
+}
+
+/// Returns Vincenty's A and B series coefficients
+fn series_coefficients(u_sq: Float) -> (Float, Float) {
+    let a = 1.0 + u_sq / 16384.0 * (4096.0 + u_sq * (-768.0 + u_sq * (320.0 - 175.0 * u_sq)));
+    let b = u_sq / 1024.0 * (256.0 + u_sq * (-128.0 + u_sq * (74.0 - 47.0 * u_sq)));
+    (a, b)
+}
+
+fn delta_sigma(b: Float, sin_sigma: Float, cos_sigma: Float, cos_2_sigma_m: Float) -> Float {
+    b * sin_sigma
+        * (cos_2_sigma_m
+            + b / 4.0
+                * (cos_sigma * (-1.0 + 2.0 * cos_2_sigma_m.powi(2))
+                    - b / 6.0
+                        * cos_2_sigma_m
+                        * (-3.0 + 4.0 * sin_sigma.powi(2))
+                        * (-3.0 + 4.0 * cos_2_sigma_m.powi(2))))
+}
+
+/// The change between iterations where Vincenty's formulas are considered converged
+fn tolerance() -> Float {
+    (Float::EPSILON * 4.0).max(1e-12)
+}
+
+fn normalize_bearing(degrees: Float) -> Float {
+    ((degrees % 360.0) + 360.0) % 360.0
+}
+
+pub(super) fn normalize_longitude(degrees: Float) -> Float {
+    let normalized = normalize_bearing(degrees + 180.0) - 180.0;
+    // Keep 180° instead of wrapping it to -180°
+    if normalized == -180.0 && degrees > 0.0 {
+        180.0
+    } else {
+        normalized
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::excessive_precision)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::assert_approx_eq;
+
+    fn flinders_peak() -> Coordinate {
+        Coordinate::new(-37.951033417, 144.424867889)
+    }
+
+    fn buninyong() -> Coordinate {
+        Coordinate::new(-37.652821139, 143.926495528)
+    }
+
+    #[rstest]
+    #[case(Coordinate::new(51.5007, -0.1246), Coordinate::new(40.6892, -74.0445), 5574840.0)]
+    #[case(Coordinate::new(0.0, 0.0), Coordinate::new(0.0, 180.0), 20015115.0)]
+    #[case(Coordinate::new(10.0, 20.0), Coordinate::new(10.0, 20.0), 0.0)]
+    fn test_haversine_distance(
+        #[case] start: Coordinate,
+        #[case] end: Coordinate,
+        #[case] expected: Float,
+    ) {
+        assert_approx_eq!(haversine_distance(&start, &end), expected, 100.0);
+    }
+
+    #[rstest]
+    #[case(Coordinate::new(1.0, 5.0), 111195.08)]
+    #[case(Coordinate::new(0.0, 5.0), 0.0)]
+    #[case(Coordinate::new(0.0, 12.0), 222390.16)]
+    #[case(Coordinate::new(-1.0, -1.0), 157249.6)]
+    fn test_great_circle_distance_to_segment(#[case] point: Coordinate, #[case] expected: Float) {
+        let start = Coordinate::new(0.0, 0.0);
+        let end = Coordinate::new(0.0, 10.0);
+        assert_approx_eq!(
+            great_circle_distance_to_segment(&point, &start, &end),
+            expected,
+            10.0
+        );
+    }
+
+    #[test]
+    fn test_vincenty_inverse() {
+        let actual = vincenty_inverse(&flinders_peak(), &buninyong(), &Ellipsoid::WGS84).unwrap();
+        assert_approx_eq!(actual.distance, 54972.271, 1.0);
+        assert_approx_eq!(actual.initial_bearing, 306.868159, 0.001);
+        assert_approx_eq!(actual.final_bearing, 307.173631, 0.001);
+    }
+
+    #[rstest]
+    #[case(Coordinate::new(0.0, 0.0), Coordinate::new(0.0, 1.0), 111319.49, 90.0)]
+    #[case(Coordinate::new(0.0, 0.0), Coordinate::new(1.0, 0.0), 110574.39, 0.0)]
+    #[case(Coordinate::new(0.0, 0.0), Coordinate::new(45.0, 0.0), 4984944.38, 0.0)]
+    #[case(
+        Coordinate::new(0.0, 0.0),
+        Coordinate::new(90.0, 0.0),
+        10001965.73,
+        0.0
+    )]
+    #[case(Coordinate::new(0.0, 0.0), Coordinate::new(-90.0, 0.0), 10001965.73, 180.0)]
+    #[case(Coordinate::new(45.0, 10.0), Coordinate::new(45.0, 10.0), 0.0, 0.0)]
+    fn test_vincenty_inverse_reference_distances(
+        #[case] start: Coordinate,
+        #[case] end: Coordinate,
+        #[case] expected_distance: Float,
+        #[case] expected_bearing: Float,
+    ) {
+        let actual = vincenty_inverse(&start, &end, &Ellipsoid::WGS84).unwrap();
+        assert_approx_eq!(actual.distance, expected_distance, 2.0);
+        assert_approx_eq!(actual.initial_bearing, expected_bearing, 0.001);
+    }
+
+    #[test]
+    fn test_vincenty_inverse_across_antimeridian() {
+        let start = Coordinate::new(1.0, 179.0);
+        let end = Coordinate::new(1.0, -179.0);
+        let actual = vincenty_inverse(&start, &end, &Ellipsoid::WGS84).unwrap();
+        assert_approx_eq!(actual.distance, 222605.30, 5.0);
+        assert_approx_eq!(actual.initial_bearing, 89.98255, 0.001);
+    }
+
+    #[test]
+    fn test_vincenty_inverse_fails_for_antipodal_points() {
+        let start = Coordinate::new(0.0, 0.0);
+        let end = Coordinate::new(0.5, 179.7);
+        assert_eq!(vincenty_inverse(&start, &end, &Ellipsoid::WGS84), None);
+    }
+
+    #[test]
+    fn test_vincenty_direct() {
+        let (destination, final_bearing) =
+            vincenty_direct(&flinders_peak(), 54972.271, 306.868159, &Ellipsoid::WGS84);
+        assert_approx_eq!(destination.latitude, buninyong().latitude, 0.0001);
+        assert_approx_eq!(destination.longitude, buninyong().longitude, 0.0001);
+        assert_approx_eq!(final_bearing, 307.173631, 0.001);
+    }
+
+    #[rstest]
+    #[case(Coordinate::new(0.0, 170.0), 90.0, Coordinate::new(0.0, -170.0))]
+    #[case(Coordinate::new(0.0, -170.0), 270.0, Coordinate::new(0.0, 170.0))]
+    fn test_vincenty_direct_wraps_longitude(
+        #[case] start: Coordinate,
+        #[case] bearing: Float,
+        #[case] expected: Coordinate,
+    ) {
+        let distance = 20.0 * 111319.49;
+        let (destination, _) = vincenty_direct(&start, distance, bearing, &Ellipsoid::WGS84);
+        assert_approx_eq!(destination.latitude, expected.latitude, 0.0001);
+        assert_approx_eq!(destination.longitude, expected.longitude, 0.0001);
+    }
+
+    #[rstest]
+    #[case(Coordinate::new(0.0, 0.0), Coordinate::new(0.0, 1.0), 90.0)]
+    #[case(Coordinate::new(0.0, 0.0), Coordinate::new(-1.0, 0.0), 180.0)]
+    #[case(Coordinate::new(35.0, 45.0), Coordinate::new(35.0, 135.0), 60.16)]
+    fn test_spherical_bearing(
+        #[case] start: Coordinate,
+        #[case] end: Coordinate,
+        #[case] expected: Float,
+    ) {
+        assert_approx_eq!(spherical_bearing(&start, &end), expected, 0.01);
+    }
+
+    #[rstest]
+    #[case(
+        Coordinate::new(0.0, 0.0),
+        Coordinate::new(0.0, 90.0),
+        Coordinate::new(0.0, 45.0)
+    )]
+    #[case(
+        Coordinate::new(0.0, 0.0),
+        Coordinate::new(60.0, 0.0),
+        Coordinate::new(30.0, 0.0)
+    )]
+    #[case(Coordinate::new(0.0, 170.0), Coordinate::new(0.0, -170.0), Coordinate::new(0.0, 180.0))]
+    #[case(
+        Coordinate::new(35.0, 45.0),
+        Coordinate::new(35.0, 135.0),
+        Coordinate::new(44.719, 90.0)
+    )]
+    fn test_spherical_midpoint(
+        #[case] start: Coordinate,
+        #[case] end: Coordinate,
+        #[case] expected: Coordinate,
+    ) {
+        let actual = spherical_midpoint(&start, &end);
+        assert_approx_eq!(actual.latitude, expected.latitude, 0.01);
+        assert_approx_eq!(actual.longitude, expected.longitude, 0.01);
+    }
+}
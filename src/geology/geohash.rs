@@ -0,0 +1,337 @@
+use crate::{
+    math::Float,
+    units::{bearing::CompassDirection, coordinate::Coordinate},
+};
+
+use super::bounds::CoordinateBounds;
+
+const BASE32: &[u8; 32] = b"0123456789bcdefghjkmnpqrstuvwxyz";
+
+/// The longest geohash which is encoded (about 4 cm)
+pub const MAX_GEOHASH_PRECISION: usize = 12;
+
+/// Encodes the coordinate as a geohash with the number of characters (1 to 12). Each character
+/// narrows the cell, from about 5000 km with 1 character to 5 m with 9 and 4 cm with 12.
+pub fn encode_geohash(coordinate: &Coordinate, precision: usize) -> String {
+    let precision = precision.clamp(1, MAX_GEOHASH_PRECISION);
+    let mut latitude_range = (-90.0, 90.0);
+    let mut longitude_range = (-180.0, 180.0);
+    let latitude = coordinate.latitude.clamp(-90.0, 90.0);
+    let longitude = coordinate.longitude.clamp(-180.0, 180.0);
+
+    let mut hash = String::with_capacity(precision);
+    let mut is_longitude = true;
+    for _ in 0..precision {
+        let mut index = 0;
+        for _ in 0..5 {
+            let (value, range) = if is_longitude {
+                (longitude, &mut longitude_range)
+            } else {
+                (latitude, &mut latitude_range)
+            };
+            let middle = (range.0 + range.1) / 2.0;
+            index <<= 1;
+            if value >= middle {
+                index |= 1;
+                range.0 = middle;
+            } else {
+                range.1 = middle;
+            }
+            is_longitude = !is_longitude;
+        }
+        hash.push(BASE32[index] as char);
+    }
+    hash
+}
+
+/// Returns the center of the geohash cell
+pub fn decode_geohash(hash: &str) -> Result<Coordinate, InvalidGeohash> {
+    geohash_bounds(hash).map(|bounds| bounds.center())
+}
+
+/// Returns the area covered by the geohash
+pub fn geohash_bounds(hash: &str) -> Result<CoordinateBounds, InvalidGeohash> {
+    let indices = parse(hash)?;
+    let mut latitude_range: (Float, Float) = (-90.0, 90.0);
+    let mut longitude_range: (Float, Float) = (-180.0, 180.0);
+    let mut is_longitude = true;
+    for index in indices {
+        for bit in (0..5).rev() {
+            let range = if is_longitude {
+                &mut longitude_range
+            } else {
+                &mut latitude_range
+            };
+            let middle = (range.0 + range.1) / 2.0;
+            if (index >> bit) & 1 == 1 {
+                range.0 = middle;
+            } else {
+                range.1 = middle;
+            }
+            is_longitude = !is_longitude;
+        }
+    }
+    Ok(CoordinateBounds::new(
+        latitude_range.1,
+        longitude_range.1,
+        latitude_range.0,
+        longitude_range.0,
+    ))
+}
+
+/// Returns the geohash of the same length next to this one in the direction. Neighbors wrap around
+/// the antimeridian. Returns None past the poles.
+pub fn geohash_neighbor(
+    hash: &str,
+    direction: CompassDirection,
+) -> Result<Option<String>, InvalidGeohash> {
+    let hash = hash.trim().to_lowercase();
+    let bounds = geohash_bounds(&hash)?;
+    let (north_south, east_west) = match direction {
+        CompassDirection::North => (Some(Side::Top), None),
+        CompassDirection::NorthEast => (Some(Side::Top), Some(Side::Right)),
+        CompassDirection::East => (None, Some(Side::Right)),
+        CompassDirection::SouthEast => (Some(Side::Bottom), Some(Side::Right)),
+        CompassDirection::South => (Some(Side::Bottom), None),
+        CompassDirection::SouthWest => (Some(Side::Bottom), Some(Side::Left)),
+        CompassDirection::West => (None, Some(Side::Left)),
+        CompassDirection::NorthWest => (Some(Side::Top), Some(Side::Left)),
+    };
+
+    let mut neighbor = hash;
+    if let Some(side) = north_south {
+        let is_past_pole = match side {
+            Side::Top => bounds.north >= 90.0,
+            _ => bounds.south <= -90.0,
+        };
+        if is_past_pole {
+            return Ok(None);
+        }
+        neighbor = adjacent(&neighbor, side);
+    }
+    if let Some(side) = east_west {
+        neighbor = adjacent(&neighbor, side);
+    }
+    Ok(Some(neighbor))
+}
+
+/// Returns the geohashes surrounding this one, starting to the north and going clockwise. Directions
+/// past the poles are skipped.
+pub fn geohash_neighbors(hash: &str) -> Result<Vec<(CompassDirection, String)>, InvalidGeohash> {
+    let mut neighbors = Vec::with_capacity(8);
+    for direction in [
+        CompassDirection::North,
+        CompassDirection::NorthEast,
+        CompassDirection::East,
+        CompassDirection::SouthEast,
+        CompassDirection::South,
+        CompassDirection::SouthWest,
+        CompassDirection::West,
+        CompassDirection::NorthWest,
+    ] {
+        if let Some(neighbor) = geohash_neighbor(hash, direction)? {
+            neighbors.push((direction, neighbor));
+        }
+    }
+    Ok(neighbors)
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct InvalidGeohash(pub String);
+
+impl std::fmt::Display for InvalidGeohash {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "invalid geohash: '{}'", self.0)
+    }
+}
+
+impl std::error::Error for InvalidGeohash {}
+
+#[derive(Clone, Copy)]
+enum Side {
+    Top,
+    Right,
+    Bottom,
+    Left,
+}
+
+/// Returns the base 32 values of the characters in the geohash
+fn parse(hash: &str) -> Result<Vec<usize>, InvalidGeohash> {
+    let trimmed = hash.trim();
+    if trimmed.is_empty() {
+        return Err(InvalidGeohash(hash.to_string()));
+    }
+    trimmed
+        .chars()
+        .map(|c| {
+            BASE32
+                .iter()
+                .position(|&b| b as char == c.to_ascii_lowercase())
+                .ok_or_else(|| InvalidGeohash(hash.to_string()))
+        })
+        .collect()
+}
+
+/// Returns the adjacent geohash on the side, using the lookup tables for even and odd length hashes.
+/// The lowercase hash must be valid and not on the edge of the map in that direction (except east and west).
+fn adjacent(hash: &str, side: Side) -> String {
+    // Even and odd length tables
+    let (neighbors, borders) = match side {
+        Side::Top => (
+            [
+                "p0r21436x8zb9dcf5h7kjnmqesgutwvy",
+                "bc01fg45238967deuvhjyznpkmstqrwx",
+            ],
+            ["prxz", "bcfguvyz"],
+        ),
+        Side::Right => (
+            [
+                "bc01fg45238967deuvhjyznpkmstqrwx",
+                "p0r21436x8zb9dcf5h7kjnmqesgutwvy",
+            ],
+            ["bcfguvyz", "prxz"],
+        ),
+        Side::Bottom => (
+            [
+                "14365h7k9dcfesgujnmqp0r2twvyx8zb",
+                "238967debc01fg45kmstqrwxuvhjyznp",
+            ],
+            ["028b", "0145hjnp"],
+        ),
+        Side::Left => (
+            [
+                "238967debc01fg45kmstqrwxuvhjyznp",
+                "14365h7k9dcfesgujnmqp0r2twvyx8zb",
+            ],
+            ["0145hjnp", "028b"],
+        ),
+    };
+
+    let Some(last) = hash.chars().last() else {
+        return String::new();
+    };
+    let parity = hash.len() % 2;
+    let mut parent = hash[..hash.len() - 1].to_string();
+    if borders[parity].contains(last) && !parent.is_empty() {
+        parent = adjacent(&parent, side);
+    }
+    let index = neighbors[parity].find(last).unwrap_or(0);
+    parent.push(BASE32[index] as char);
+    parent
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::assert_approx_eq;
+
+    #[rstest]
+    #[case(57.64911, 10.40744, 8, "u4pruydq")]
+    #[case(42.605, -5.603, 5, "ezs42")]
+    #[case(-33.8568, 151.2153, 6, "r3gx2u")]
+    #[case(0.0, 0.0, 1, "s")]
+    #[case(90.0, 180.0, 4, "zzzz")]
+    #[case(-90.0, -180.0, 4, "0000")]
+    #[case(42.605, -5.603, 0, "e")]
+    fn test_encode_geohash(
+        #[case] latitude: Float,
+        #[case] longitude: Float,
+        #[case] precision: usize,
+        #[case] expected: &str,
+    ) {
+        let hash = encode_geohash(&Coordinate::new(latitude, longitude), precision);
+        assert_eq!(hash, expected);
+    }
+
+    #[cfg(feature = "f64")]
+    #[test]
+    fn test_encode_geohash_high_precision() {
+        let hash = encode_geohash(&Coordinate::new(57.64911, 10.40744), 11);
+        assert_eq!(hash, "u4pruydqqvj");
+    }
+
+    #[rstest]
+    #[case("ezs42", 42.60498, -5.60303, 0.04395, 0.04395)]
+    #[case("EZS42", 42.60498, -5.60303, 0.04395, 0.04395)]
+    #[case("u4pruydq", 57.64913, 10.40731, 0.00017, 0.00034)]
+    #[case("s", 22.5, 22.5, 45.0, 45.0)]
+    fn test_decode_geohash(
+        #[case] hash: &str,
+        #[case] latitude: Float,
+        #[case] longitude: Float,
+        #[case] height: Float,
+        #[case] width: Float,
+    ) {
+        let center = decode_geohash(hash).unwrap();
+        assert_approx_eq!(center.latitude, latitude, 1e-5);
+        assert_approx_eq!(center.longitude, longitude, 1e-5);
+
+        let bounds = geohash_bounds(hash).unwrap();
+        assert_approx_eq!(bounds.height(), height, 1e-5);
+        assert_approx_eq!(bounds.width(), width, 1e-5);
+    }
+
+    #[rstest]
+    #[case("")]
+    #[case("ezs4a")]
+    #[case("ezs 42")]
+    fn test_decode_geohash_invalid(#[case] hash: &str) {
+        assert_eq!(decode_geohash(hash), Err(InvalidGeohash(hash.to_string())));
+    }
+
+    #[test]
+    fn test_geohash_neighbors() {
+        let neighbors = geohash_neighbors("dqcjq").unwrap();
+        assert_eq!(
+            neighbors,
+            vec![
+                (CompassDirection::North, "dqcjw".to_string()),
+                (CompassDirection::NorthEast, "dqcjx".to_string()),
+                (CompassDirection::East, "dqcjr".to_string()),
+                (CompassDirection::SouthEast, "dqcjp".to_string()),
+                (CompassDirection::South, "dqcjn".to_string()),
+                (CompassDirection::SouthWest, "dqcjj".to_string()),
+                (CompassDirection::West, "dqcjm".to_string()),
+                (CompassDirection::NorthWest, "dqcjt".to_string()),
+            ]
+        );
+    }
+
+    #[rstest]
+    #[case("ezs42", CompassDirection::North, Some("ezs48"))]
+    #[case("ezs42", CompassDirection::SouthWest, Some("ezefp"))]
+    #[case("u000", CompassDirection::West, Some("gbpb"))]
+    #[case("9zzz", CompassDirection::East, Some("dpbp"))]
+    #[case("xbpb", CompassDirection::East, Some("8000"))]
+    #[case("8000", CompassDirection::West, Some("xbpb"))]
+    #[case("zzzz", CompassDirection::North, None)]
+    #[case("zzzz", CompassDirection::NorthWest, None)]
+    #[case("0000", CompassDirection::South, None)]
+    #[case("0000", CompassDirection::North, Some("0001"))]
+    fn test_geohash_neighbor(
+        #[case] hash: &str,
+        #[case] direction: CompassDirection,
+        #[case] expected: Option<&str>,
+    ) {
+        let neighbor = geohash_neighbor(hash, direction).unwrap();
+        assert_eq!(neighbor.as_deref(), expected);
+    }
+
+    #[test]
+    fn test_geohash_neighbors_at_pole() {
+        let neighbors = geohash_neighbors("zzzz").unwrap();
+        let directions: Vec<CompassDirection> = neighbors.iter().map(|(d, _)| *d).collect();
+        assert_eq!(
+            directions,
+            vec![
+                CompassDirection::East,
+                CompassDirection::SouthEast,
+                CompassDirection::South,
+                CompassDirection::SouthWest,
+                CompassDirection::West,
+            ]
+        );
+    }
+}
@@ -0,0 +1,400 @@
+use crate::{
+    math::Float,
+    units::{
+        bearing::Bearing,
+        coordinate::Coordinate,
+        distance::Distance,
+        quantity::{Convertable, Quantity},
+    },
+};
+
+use super::ellipsoid::Ellipsoid;
+
+/// The geomagnetic reference radius of the World Magnetic Model in meters
+const REFERENCE_RADIUS: Float = 6371200.0;
+
+/// The epoch of the embedded World Magnetic Model coefficients, as a decimal year
+pub const WMM_EPOCH: Float = 2025.0;
+
+/// The decimal year after which the embedded World Magnetic Model is no longer valid. It still
+/// extrapolates past this, but the error grows each year.
+pub const WMM_VALID_UNTIL: Float = 2030.0;
+
+const MAX_DEGREE: usize = 12;
+
+/// The strength of the Earth's magnetic field in nanoteslas, in the local north, east and down
+/// directions
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MagneticField {
+    pub north: Float,
+    pub east: Float,
+    pub down: Float,
+}
+
+impl MagneticField {
+    pub fn new(north: Float, east: Float, down: Float) -> Self {
+        MagneticField { north, east, down }
+    }
+
+    /// The strength of the horizontal component of the field in nanoteslas
+    pub fn horizontal_intensity(&self) -> Float {
+        self.north.hypot(self.east)
+    }
+
+    /// The strength of the field in nanoteslas
+    pub fn total_intensity(&self) -> Float {
+        self.horizontal_intensity().hypot(self.down)
+    }
+
+    /// The angle in degrees between true north and magnetic north, positive when magnetic north is
+    /// east of true north
+    pub fn declination(&self) -> Float {
+        self.east.atan2(self.north).to_degrees()
+    }
+
+    /// The angle in degrees between the field and the horizontal plane, positive when the field points
+    /// down
+    pub fn inclination(&self) -> Float {
+        self.down.atan2(self.horizontal_intensity()).to_degrees()
+    }
+}
+
+/// Returns the Earth's main magnetic field from the World Magnetic Model (WMM2025) at the coordinate,
+/// altitude above the WGS84 ellipsoid and decimal year (see `decimal_year`)
+pub fn magnetic_field(
+    coordinate: &Coordinate,
+    altitude: &Quantity<Distance>,
+    decimal_year: Float,
+) -> MagneticField {
+    let ellipsoid = Ellipsoid::WGS84;
+    let altitude = altitude.convert(Distance::Meters).amount;
+    let latitude = coordinate.latitude.clamp(-90.0, 90.0).to_radians();
+    let longitude = coordinate.longitude.to_radians();
+
+    // Geodetic to geocentric spherical coordinates
+    let e2 = ellipsoid.eccentricity_squared();
+    let n = ellipsoid.semi_major_axis / (1.0 - e2 * latitude.sin().powi(2)).sqrt();
+    let p = (n + altitude) * latitude.cos();
+    let z = (n * (1.0 - e2) + altitude) * latitude.sin();
+    let radius = p.hypot(z);
+    let geocentric_latitude = z.atan2(p);
+
+    // The east component divides by the cosine of the latitude, which only cancels out away from the poles
+    let sin_latitude = geocentric_latitude.sin();
+    let cos_latitude = geocentric_latitude.cos().max(Float::EPSILON);
+    let (legendre, derivative) = schmidt_legendre(sin_latitude, cos_latitude);
+
+    let years = decimal_year - WMM_EPOCH;
+    let mut north = 0.0;
+    let mut east = 0.0;
+    let mut down = 0.0;
+    for &(degree, order, g, h, g_rate, h_rate) in WMM2025.iter() {
+        let g = g + years * g_rate;
+        let h = h + years * h_rate;
+        let scale = (REFERENCE_RADIUS / radius).powi(degree as i32 + 2);
+        let (sin_m, cos_m) = (order as Float * longitude).sin_cos();
+        let harmonic = g * cos_m + h * sin_m;
+        north += scale * harmonic * derivative[degree][order];
+        east += scale * order as Float * (g * sin_m - h * cos_m) * legendre[degree][order]
+            / cos_latitude;
+        down -= scale * (degree as Float + 1.0) * harmonic * legendre[degree][order];
+    }
+
+    // Rotate from geocentric to geodetic
+    let (sin_psi, cos_psi) = (geocentric_latitude - latitude).sin_cos();
+    MagneticField::new(
+        north * cos_psi - down * sin_psi,
+        east,
+        north * sin_psi + down * cos_psi,
+    )
+}
+
+/// Returns the magnetic declination in degrees from the World Magnetic Model. Add it to a magnetic
+/// bearing to get the true bearing (see `Bearing::with_declination`).
+pub fn magnetic_declination(
+    coordinate: &Coordinate,
+    altitude: &Quantity<Distance>,
+    decimal_year: Float,
+) -> Float {
+    magnetic_field(coordinate, altitude, decimal_year).declination()
+}
+
+/// Converts a magnetic bearing to a true bearing using the declination from the World Magnetic Model
+/// at the coordinate, altitude and decimal year
+pub fn true_bearing(
+    magnetic_bearing: &Bearing,
+    coordinate: &Coordinate,
+    altitude: &Quantity<Distance>,
+    decimal_year: Float,
+) -> Bearing {
+    magnetic_bearing.with_declination(magnetic_declination(coordinate, altitude, decimal_year))
+}
+
+/// Returns the date as a decimal year, which is how the World Magnetic Model measures time. The month
+/// is clamped to 1 to 12 and the day to the days in the month.
+pub fn decimal_year(year: i32, month: u32, day: u32) -> Float {
+    const DAYS_BEFORE_MONTH: [u32; 12] = [0, 31, 59, 90, 120, 151, 181, 212, 243, 273, 304, 334];
+    const DAYS_IN_MONTH: [u32; 12] = [31, 28, 31, 30, 31, 30, 31, 31, 30, 31, 30, 31];
+    let is_leap_year =
+        (year.rem_euclid(4) == 0 && year.rem_euclid(100) != 0) || year.rem_euclid(400) == 0;
+    let month = month.clamp(1, 12) as usize;
+    let days_in_month = DAYS_IN_MONTH[month - 1] + if is_leap_year && month == 2 { 1 } else { 0 };
+    let mut day_of_year = DAYS_BEFORE_MONTH[month - 1] + day.clamp(1, days_in_month) - 1;
+    if is_leap_year && month > 2 {
+        day_of_year += 1;
+    }
+    let days_in_year = if is_leap_year { 366.0 } else { 365.0 };
+    year as Float + day_of_year as Float / days_in_year
+}
+
+/// Returns the Schmidt semi-normalized associated Legendre functions and their derivatives with
+/// respect to colatitude, indexed by degree and order
+fn schmidt_legendre(
+    sin_latitude: Float,
+    cos_latitude: Float,
+) -> (
+    [[Float; MAX_DEGREE + 1]; MAX_DEGREE + 1],
+    [[Float; MAX_DEGREE + 1]; MAX_DEGREE + 1],
+) {
+    let mut p = [[0.0; MAX_DEGREE + 1]; MAX_DEGREE + 1];
+    let mut dp = [[0.0; MAX_DEGREE + 1]; MAX_DEGREE + 1];
+    p[0][0] = 1.0;
+    for n in 1..=MAX_DEGREE {
+        let nf = n as Float;
+        for m in 0..=n {
+            let mf = m as Float;
+            if n == m {
+                let k = if n == 1 {
+                    1.0
+                } else {
+                    ((2.0 * nf - 1.0) / (2.0 * nf)).sqrt()
+                };
+                p[n][n] = k * cos_latitude * p[n - 1][n - 1];
+                dp[n][n] = k * (cos_latitude * dp[n - 1][n - 1] + sin_latitude * p[n - 1][n - 1]);
+            } else {
+                let k = if n > m + 1 {
+                    ((nf - 1.0).powi(2) - mf * mf).sqrt()
+                } else {
+                    0.0
+                };
+                let (p2, dp2) = if n >= 2 {
+                    (p[n - 2][m], dp[n - 2][m])
+                } else {
+                    (0.0, 0.0)
+                };
+                let norm = (nf * nf - mf * mf).sqrt();
+                p[n][m] = ((2.0 * nf - 1.0) * sin_latitude * p[n - 1][m] - k * p2) / norm;
+                dp[n][m] = ((2.0 * nf - 1.0)
+                    * (sin_latitude * dp[n - 1][m] - cos_latitude * p[n - 1][m])
+                    - k * dp2)
+                    / norm;
+            }
+        }
+    }
+    (p, dp)
+}
+
+/// The WMM2025 Gauss coefficients in nanoteslas: degree, order, g, h and their yearly rates of change
+#[rustfmt::skip]
+const WMM2025: [(usize, usize, Float, Float, Float, Float); 90] = [
+    (1, 0, -29351.8, 0.0, 12.0, 0.0),
+    (1, 1, -1410.8, 4545.4, 9.7, -21.5),
+    (2, 0, -2556.6, 0.0, -11.6, 0.0),
+    (2, 1, 2951.1, -3133.6, -5.2, -27.7),
+    (2, 2, 1649.3, -815.1, -8.0, -12.1),
+    (3, 0, 1361.0, 0.0, -1.3, 0.0),
+    (3, 1, -2404.1, -56.6, -4.2, 4.0),
+    (3, 2, 1243.8, 237.5, 0.4, -0.3),
+    (3, 3, 453.6, -549.5, -15.6, -4.1),
+    (4, 0, 895.0, 0.0, -1.6, 0.0),
+    (4, 1, 799.5, 278.6, -2.4, -1.1),
+    (4, 2, 55.7, -133.9, -6.0, 4.1),
+    (4, 3, -281.1, 212.0, 5.6, 1.6),
+    (4, 4, 12.1, -375.6, -7.0, -4.4),
+    (5, 0, -233.2, 0.0, 0.6, 0.0),
+    (5, 1, 368.9, 45.4, 1.4, -0.5),
+    (5, 2, 187.2, 220.2, 0.0, 2.2),
+    (5, 3, -138.7, -122.9, 0.6, 0.4),
+    (5, 4, -142.0, 43.0, 2.2, 1.7),
+    (5, 5, 20.9, 106.1, 0.9, 1.9),
+    (6, 0, 64.4, 0.0, -0.2, 0.0),
+    (6, 1, 63.8, -18.4, -0.4, 0.3),
+    (6, 2, 76.9, 16.8, 0.9, -1.6),
+    (6, 3, -115.7, 48.8, 1.2, -0.4),
+    (6, 4, -40.9, -59.8, -0.9, 0.9),
+    (6, 5, 14.9, 10.9, 0.3, 0.7),
+    (6, 6, -60.7, 72.7, 0.9, 0.9),
+    (7, 0, 79.5, 0.0, 0.0, 0.0),
+    (7, 1, -77.0, -48.9, -0.1, 0.6),
+    (7, 2, -8.8, -14.4, -0.1, 0.5),
+    (7, 3, 59.3, -1.0, 0.5, -0.8),
+    (7, 4, 15.8, 23.4, -0.1, 0.0),
+    (7, 5, 2.5, -7.4, -0.8, -1.0),
+    (7, 6, -11.1, -25.1, -0.8, 0.6),
+    (7, 7, 14.2, -2.3, 0.8, -0.2),
+    (8, 0, 23.2, 0.0, -0.1, 0.0),
+    (8, 1, 10.8, 7.1, 0.2, -0.2),
+    (8, 2, -17.5, -12.6, 0.0, 0.5),
+    (8, 3, 2.0, 11.4, 0.5, -0.4),
+    (8, 4, -21.7, -9.7, -0.1, 0.4),
+    (8, 5, 16.9, 12.7, 0.3, -0.5),
+    (8, 6, 15.0, 0.7, 0.2, -0.6),
+    (8, 7, -16.8, -5.2, 0.0, 0.3),
+    (8, 8, 0.9, 3.9, 0.2, 0.2),
+    (9, 0, 4.6, 0.0, 0.0, 0.0),
+    (9, 1, 7.8, -24.8, -0.1, -0.3),
+    (9, 2, 3.0, 12.2, 0.1, 0.3),
+    (9, 3, -0.2, 8.3, 0.3, -0.3),
+    (9, 4, -2.5, -3.3, -0.3, 0.3),
+    (9, 5, -13.1, -5.2, 0.0, 0.2),
+    (9, 6, 2.4, 7.2, 0.3, -0.1),
+    (9, 7, 8.6, -0.6, -0.1, -0.2),
+    (9, 8, -8.7, 0.8, 0.1, 0.4),
+    (9, 9, -12.9, 10.0, -0.1, 0.1),
+    (10, 0, -1.3, 0.0, 0.1, 0.0),
+    (10, 1, -6.4, 3.3, 0.0, 0.0),
+    (10, 2, 0.2, 0.0, 0.1, 0.0),
+    (10, 3, 2.0, 2.4, 0.1, -0.2),
+    (10, 4, -1.0, 5.3, 0.0, 0.1),
+    (10, 5, -0.6, -9.1, -0.3, -0.1),
+    (10, 6, -0.9, 0.4, 0.0, 0.1),
+    (10, 7, 1.5, -4.2, -0.1, 0.0),
+    (10, 8, 0.9, -3.8, -0.1, -0.1),
+    (10, 9, -2.7, 0.9, 0.0, 0.2),
+    (10, 10, -3.9, -9.1, 0.0, 0.0),
+    (11, 0, 2.9, 0.0, 0.0, 0.0),
+    (11, 1, -1.5, 0.0, 0.0, 0.0),
+    (11, 2, -2.5, 2.9, 0.0, 0.1),
+    (11, 3, 2.4, -0.6, 0.0, 0.0),
+    (11, 4, -0.6, 0.2, 0.0, 0.1),
+    (11, 5, -0.1, 0.5, -0.1, 0.0),
+    (11, 6, -0.6, -0.3, 0.0, 0.0),
+    (11, 7, -0.1, -1.2, 0.0, 0.1),
+    (11, 8, 1.1, -1.7, -0.1, 0.0),
+    (11, 9, -1.0, -2.9, -0.1, 0.0),
+    (11, 10, -0.2, -1.8, -0.1, 0.0),
+    (11, 11, 2.6, -2.3, -0.1, 0.0),
+    (12, 0, -2.0, 0.0, 0.0, 0.0),
+    (12, 1, -0.2, -1.3, 0.0, 0.0),
+    (12, 2, 0.3, 0.7, 0.0, 0.0),
+    (12, 3, 1.2, 1.0, 0.0, -0.1),
+    (12, 4, -1.3, -1.4, 0.0, 0.1),
+    (12, 5, 0.6, 0.0, 0.0, 0.0),
+    (12, 6, 0.6, 0.6, 0.1, 0.0),
+    (12, 7, 0.5, -0.1, 0.0, 0.0),
+    (12, 8, -0.1, 0.8, 0.0, 0.0),
+    (12, 9, -0.4, 0.1, 0.0, 0.0),
+    (12, 10, -0.2, -1.0, -0.1, 0.0),
+    (12, 11, -1.3, 0.1, 0.0, 0.0),
+    (12, 12, -0.7, 0.2, -0.1, -0.1),
+];
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::assert_approx_eq;
+
+    // Test points from the WMM2025 technical report
+    #[rstest]
+    #[case(2025.0, 0.0, 80.0, 0.0, (6521.6, 145.9, 54791.5), 83.21, 1.28)]
+    #[case(2025.0, 0.0, 0.0, 120.0, (39677.8, -109.6, -10580.2), -14.93, -0.16)]
+    #[case(2025.0, 0.0, -80.0, 240.0, (6117.5, 15751.9, -52022.5), -72.00, 68.78)]
+    #[case(2025.0, 100.0, 80.0, 0.0, (6216.0, 92.4, 52598.8), 83.26, 0.85)]
+    #[case(2025.0, 100.0, 0.0, 120.0, (37688.6, -96.2, -10152.1), -15.08, -0.15)]
+    #[case(2025.0, 100.0, -80.0, 240.0, (5907.6, 14780.3, -49540.7), -72.19, 68.21)]
+    #[case(2027.5, 0.0, 80.0, 0.0, (6500.8, 294.5, 54869.4), 83.24, 2.59)]
+    #[case(2027.5, 0.0, 0.0, 120.0, (39701.6, -167.4, -10381.8), -14.65, -0.24)]
+    #[case(2027.5, 0.0, -80.0, 240.0, (6200.7, 15730.3, -51783.7), -71.92, 68.49)]
+    #[case(2027.5, 100.0, 80.0, 0.0, (6196.7, 233.8, 52670.5), 83.29, 2.16)]
+    #[case(2027.5, 100.0, 0.0, 120.0, (37711.5, -148.7, -9969.8), -14.81, -0.23)]
+    #[case(2027.5, 100.0, -80.0, 240.0, (5984.0, 14760.1, -49317.7), -72.10, 67.93)]
+    fn test_magnetic_field(
+        #[case] year: Float,
+        #[case] altitude_km: Float,
+        #[case] latitude: Float,
+        #[case] longitude: Float,
+        #[case] expected: (Float, Float, Float),
+        #[case] inclination: Float,
+        #[case] declination: Float,
+    ) {
+        let field = magnetic_field(
+            &Coordinate::new(latitude, longitude),
+            &Quantity::new(altitude_km, Distance::Kilometers),
+            year,
+        );
+        assert_approx_eq!(field.north, expected.0, 2.0);
+        assert_approx_eq!(field.east, expected.1, 2.0);
+        assert_approx_eq!(field.down, expected.2, 2.0);
+        assert_approx_eq!(field.inclination(), inclination, 0.015);
+        assert_approx_eq!(field.declination(), declination, 0.015);
+    }
+
+    #[test]
+    fn test_field_strength() {
+        let field = MagneticField::new(6570.4, -146.3, 54606.0);
+        assert_approx_eq!(field.horizontal_intensity(), 6572.0, 0.1);
+        assert_approx_eq!(field.total_intensity(), 55000.1, 0.1);
+    }
+
+    #[rstest]
+    #[case(90.0, 56888.5, 88.20)]
+    #[case(-90.0, 54381.2, -71.99)]
+    fn test_magnetic_field_at_poles(
+        #[case] latitude: Float,
+        #[case] total_intensity: Float,
+        #[case] inclination: Float,
+    ) {
+        let field = magnetic_field(
+            &Coordinate::new(latitude, 0.0),
+            &Quantity::new(0.0, Distance::Meters),
+            2025.0,
+        );
+        assert_approx_eq!(field.total_intensity(), total_intensity, 2.0);
+        assert_approx_eq!(field.inclination(), inclination, 0.05);
+    }
+
+    #[test]
+    fn test_magnetic_declination() {
+        let declination = magnetic_declination(
+            &Coordinate::new(0.0, 120.0),
+            &Quantity::new(0.0, Distance::Meters),
+            2025.0,
+        );
+        assert_approx_eq!(declination, -0.16, 0.01);
+    }
+
+    #[test]
+    fn test_true_bearing() {
+        let bearing = true_bearing(
+            &Bearing::new(45.0),
+            &Coordinate::new(-80.0, 240.0),
+            &Quantity::new(0.0, Distance::Meters),
+            2025.0,
+        );
+        assert_approx_eq!(bearing.degrees, 113.78, 0.01);
+    }
+
+    #[rstest]
+    #[case(2020, 1, 1, 2020.0)]
+    #[case(2022, 7, 2, 2022.4986)]
+    #[case(2024, 3, 1, 2024.164)]
+    #[case(2023, 3, 1, 2023.1616)]
+    #[case(2000, 12, 31, 2000.9973)]
+    #[case(2100, 3, 1, 2100.1616)]
+    #[case(2023, 2, 31, 2023.1589)]
+    #[case(2024, 2, 30, 2024.1612)]
+    #[case(2023, 4, 0, 2023.2466)]
+    #[case(2023, 0, 1, 2023.0)]
+    #[case(2023, 13, 31, 2023.9973)]
+    fn test_decimal_year(
+        #[case] year: i32,
+        #[case] month: u32,
+        #[case] day: u32,
+        #[case] expected: Float,
+    ) {
+        assert_approx_eq!(decimal_year(year, month, day), expected, 2e-4);
+    }
+}
@@ -0,0 +1,398 @@
+use crate::{
+    math::{
+        Float,
+        geometry::Point2D,
+        linear_algebra::{Matrix, RawTensor, Vector, least_squares},
+    },
+    units::coordinate::Coordinate,
+};
+
+use super::{
+    ellipsoid::Ellipsoid, projection::Projection, transverse_mercator::TransverseMercator,
+};
+
+/// A pixel on the image of a map and the coordinate it shows. Pixels start at the top left corner of the
+/// image, with y increasing downward.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ControlPoint {
+    pub pixel: Point2D,
+    pub coordinate: Coordinate,
+}
+
+impl ControlPoint {
+    pub fn new(pixel: Point2D, coordinate: Coordinate) -> Self {
+        ControlPoint { pixel, coordinate }
+    }
+}
+
+/// The transform from pixels to the ground
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum CalibrationModel {
+    /// Scale, rotation and translation, for scans and screenshots of maps (2+ points)
+    Similarity,
+    /// Adds a different scale on each axis and shear, for stretched scans (3+ points)
+    Affine,
+    /// Adds perspective, for photos of a map taken at an angle (4+ points)
+    Projective,
+}
+
+impl CalibrationModel {
+    /// The number of control points needed to fit the model
+    pub fn minimum_points(&self) -> usize {
+        match self {
+            CalibrationModel::Similarity => 2,
+            CalibrationModel::Affine => 3,
+            CalibrationModel::Projective => 4,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum CalibrationError {
+    /// The model needs more control points
+    NotEnoughPoints { required: usize, actual: usize },
+    /// The control points do not determine a transform (ex. they are on top of each other or in a line)
+    Degenerate,
+}
+
+impl std::fmt::Display for CalibrationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CalibrationError::NotEnoughPoints { required, actual } => write!(
+                f,
+                "at least {} control points are required, but {} were provided",
+                required, actual
+            ),
+            CalibrationError::Degenerate => {
+                write!(f, "the control points do not determine a transform")
+            }
+        }
+    }
+}
+
+impl std::error::Error for CalibrationError {}
+
+/// A fitted transform between the pixels of a map image and coordinates. The transform is fit on a
+/// transverse Mercator plane centered on the control points, so it works for any map in a conformal
+/// projection covering up to a few hundred kilometers.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MapCalibration {
+    pub model: CalibrationModel,
+    projection: TransverseMercator,
+    /// The homogeneous transform from pixels to the projected plane
+    transform: Matrix,
+    inverse: Matrix,
+    residuals: Vec<Float>,
+}
+
+impl MapCalibration {
+    /// Fits the model to the control points with least squares
+    pub fn fit(points: &[ControlPoint], model: CalibrationModel) -> Result<Self, CalibrationError> {
+        let required = model.minimum_points();
+        if points.len() < required {
+            return Err(CalibrationError::NotEnoughPoints {
+                required,
+                actual: points.len(),
+            });
+        }
+
+        let projection = local_projection(points);
+        // Image rows increase downward, so flip them to match the handedness of the plane
+        let flip = Matrix::from(vec![
+            vec![1.0, 0.0, 0.0],
+            vec![0.0, -1.0, 0.0],
+            vec![0.0, 0.0, 1.0],
+        ]);
+        let pixels: Vec<Point2D> = points
+            .iter()
+            .map(|point| apply(&flip, &point.pixel))
+            .collect();
+        let planes: Vec<Point2D> = points
+            .iter()
+            .map(|point| projection.project(&point.coordinate))
+            .collect();
+
+        // Centering and scaling the points keeps the least squares problem well conditioned
+        let (pixel_normalization, _) =
+            normalization(&pixels).ok_or(CalibrationError::Degenerate)?;
+        let (plane_normalization, plane_denormalization) =
+            normalization(&planes).ok_or(CalibrationError::Degenerate)?;
+        let normalized_pixels: Vec<Point2D> = pixels
+            .iter()
+            .map(|pixel| apply(&pixel_normalization, pixel))
+            .collect();
+        let normalized_planes: Vec<Point2D> = planes
+            .iter()
+            .map(|plane| apply(&plane_normalization, plane))
+            .collect();
+
+        let fitted = Matrix::from(fit_model(model, &normalized_pixels, &normalized_planes));
+        let is_valid = fitted.data.iter().flatten().all(|value| value.is_finite())
+            && fitted.determinant().abs() > 1e-4;
+        if !is_valid {
+            return Err(CalibrationError::Degenerate);
+        }
+
+        let transform = plane_denormalization
+            .dot(&fitted)
+            .dot(&pixel_normalization)
+            .dot(&flip);
+        let inverse = transform.inverse();
+        let residuals = points
+            .iter()
+            .zip(planes.iter())
+            .map(|(point, plane)| apply(&transform, &point.pixel).distance(plane))
+            .collect();
+
+        Ok(MapCalibration {
+            model,
+            projection,
+            transform,
+            inverse,
+            residuals,
+        })
+    }
+
+    /// Returns the coordinate shown at the pixel
+    pub fn to_coordinate(&self, pixel: &Point2D) -> Coordinate {
+        self.projection.unproject(&apply(&self.transform, pixel))
+    }
+
+    /// Returns the pixel which shows the coordinate
+    pub fn to_pixel(&self, coordinate: &Coordinate) -> Point2D {
+        apply(&self.inverse, &self.projection.project(coordinate))
+    }
+
+    /// The distance in meters between where each control point is and where the calibration puts it, in
+    /// the order the points were given
+    pub fn residuals(&self) -> &[Float] {
+        &self.residuals
+    }
+
+    /// The root mean square of the residuals in meters
+    pub fn rms_error(&self) -> Float {
+        let sum: Float = self.residuals.iter().map(|r| r * r).sum();
+        (sum / self.residuals.len() as Float).sqrt()
+    }
+}
+
+/// A transverse Mercator projection centered on the control points, which is conformal and has almost
+/// no scale error nearby
+fn local_projection(points: &[ControlPoint]) -> TransverseMercator {
+    let count = points.len() as Float;
+    let latitude = points.iter().map(|p| p.coordinate.latitude).sum::<Float>() / count;
+    let longitude = points.iter().map(|p| p.coordinate.longitude).sum::<Float>() / count;
+    TransverseMercator {
+        ellipsoid: Ellipsoid::WGS84,
+        central_meridian: longitude,
+        latitude_of_origin: latitude,
+        scale_factor: 1.0,
+        false_easting: 0.0,
+        false_northing: 0.0,
+    }
+}
+
+/// Returns the transforms which move the points to be centered on the origin with an average distance
+/// of √2 from it, and back. Returns None if the points are all the same.
+fn normalization(points: &[Point2D]) -> Option<(Matrix, Matrix)> {
+    let count = points.len() as Float;
+    let cx = points.iter().map(|p| p.x).sum::<Float>() / count;
+    let cy = points.iter().map(|p| p.y).sum::<Float>() / count;
+    let center = Point2D::new(cx, cy);
+    let mean_distance = points.iter().map(|p| p.distance(&center)).sum::<Float>() / count;
+    if mean_distance == 0.0 || !mean_distance.is_finite() {
+        return None;
+    }
+    let scale = Float::sqrt(2.0) / mean_distance;
+    Some((
+        Matrix::from(vec![
+            vec![scale, 0.0, -scale * cx],
+            vec![0.0, scale, -scale * cy],
+            vec![0.0, 0.0, 1.0],
+        ]),
+        Matrix::from(vec![
+            vec![1.0 / scale, 0.0, cx],
+            vec![0.0, 1.0 / scale, cy],
+            vec![0.0, 0.0, 1.0],
+        ]),
+    ))
+}
+
+/// Fits the homogeneous transform from the source to the destination points
+fn fit_model(model: CalibrationModel, source: &[Point2D], destination: &[Point2D]) -> RawTensor {
+    let mut rows: RawTensor = Vec::with_capacity(source.len() * 2);
+    let mut values = Vec::with_capacity(source.len() * 2);
+    for (s, d) in source.iter().zip(destination.iter()) {
+        match model {
+            CalibrationModel::Similarity => {
+                rows.push(vec![s.x, -s.y, 1.0, 0.0]);
+                rows.push(vec![s.y, s.x, 0.0, 1.0]);
+            }
+            CalibrationModel::Affine => {
+                rows.push(vec![s.x, s.y, 1.0, 0.0, 0.0, 0.0]);
+                rows.push(vec![0.0, 0.0, 0.0, s.x, s.y, 1.0]);
+            }
+            CalibrationModel::Projective => {
+                rows.push(vec![s.x, s.y, 1.0, 0.0, 0.0, 0.0, -s.x * d.x, -s.y * d.x]);
+                rows.push(vec![0.0, 0.0, 0.0, s.x, s.y, 1.0, -s.x * d.y, -s.y * d.y]);
+            }
+        }
+        values.push(d.x);
+        values.push(d.y);
+    }
+
+    let h = least_squares(&rows, &values);
+    match model {
+        CalibrationModel::Similarity => vec![
+            vec![h[0], -h[1], h[2]],
+            vec![h[1], h[0], h[3]],
+            vec![0.0, 0.0, 1.0],
+        ],
+        CalibrationModel::Affine => vec![
+            vec![h[0], h[1], h[2]],
+            vec![h[3], h[4], h[5]],
+            vec![0.0, 0.0, 1.0],
+        ],
+        CalibrationModel::Projective => vec![
+            vec![h[0], h[1], h[2]],
+            vec![h[3], h[4], h[5]],
+            vec![h[6], h[7], 1.0],
+        ],
+    }
+}
+
+/// Applies a homogeneous transform to the point
+fn apply(transform: &Matrix, point: &Point2D) -> Point2D {
+    let result = transform
+        .dot(&Vector::new_3d(point.x, point.y, 1.0))
+        .to_vector();
+    Point2D::new(result.x / result.z, result.y / result.z)
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::{assert_approx_eq, geology::web_mercator::coordinate_to_pixel};
+
+    /// Returns the pixel of the coordinate on a screenshot of a web map at zoom 14
+    fn screenshot_pixel(coordinate: &Coordinate) -> Point2D {
+        let origin = coordinate_to_pixel(&Coordinate::new(42.1, -71.1), 14.0, 256);
+        let pixel = coordinate_to_pixel(coordinate, 14.0, 256);
+        Point2D::new(pixel.x - origin.x, pixel.y - origin.y)
+    }
+
+    /// Returns the pixel of the coordinate on a photo of the screenshot taken at an angle
+    fn photo_pixel(coordinate: &Coordinate) -> Point2D {
+        let pixel = screenshot_pixel(coordinate);
+        let w = 0.0002 * pixel.x + 0.0001 * pixel.y + 1.0;
+        Point2D::new(
+            (pixel.x + 0.2 * pixel.y + 10.0) / w,
+            (0.1 * pixel.x + 0.9 * pixel.y + 20.0) / w,
+        )
+    }
+
+    /// Returns the pixel of the coordinate on a scan of a map which was stretched vertically
+    fn scan_pixel(coordinate: &Coordinate) -> Point2D {
+        let pixel = screenshot_pixel(coordinate);
+        Point2D::new(pixel.x + 0.1 * pixel.y, 1.5 * pixel.y)
+    }
+
+    fn control_points(pixel: fn(&Coordinate) -> Point2D, count: usize) -> Vec<ControlPoint> {
+        [
+            Coordinate::new(42.08, -71.08),
+            Coordinate::new(42.01, -70.92),
+            Coordinate::new(42.07, -70.93),
+            Coordinate::new(42.02, -71.06),
+            Coordinate::new(42.05, -71.0),
+        ]
+        .iter()
+        .take(count)
+        .map(|coordinate| ControlPoint::new(pixel(coordinate), *coordinate))
+        .collect()
+    }
+
+    #[rstest]
+    #[case(CalibrationModel::Similarity, screenshot_pixel, 2)]
+    #[case(CalibrationModel::Similarity, screenshot_pixel, 5)]
+    #[case(CalibrationModel::Affine, scan_pixel, 3)]
+    #[case(CalibrationModel::Affine, scan_pixel, 5)]
+    #[case(CalibrationModel::Projective, photo_pixel, 4)]
+    #[case(CalibrationModel::Projective, photo_pixel, 5)]
+    fn test_fit(
+        #[case] model: CalibrationModel,
+        #[case] pixel: fn(&Coordinate) -> Point2D,
+        #[case] count: usize,
+    ) {
+        let calibration = MapCalibration::fit(&control_points(pixel, count), model).unwrap();
+        assert_eq!(calibration.residuals().len(), count);
+        assert!(calibration.rms_error() < 10.0);
+
+        let coordinate = Coordinate::new(42.03, -70.97);
+        let actual = calibration.to_coordinate(&pixel(&coordinate));
+        assert_approx_eq!(actual.latitude, coordinate.latitude, 1e-4);
+        assert_approx_eq!(actual.longitude, coordinate.longitude, 1e-4);
+
+        let expected = pixel(&coordinate);
+        let actual = calibration.to_pixel(&coordinate);
+        assert_approx_eq!(actual.x, expected.x, 1.0);
+        assert_approx_eq!(actual.y, expected.y, 1.0);
+    }
+
+    #[test]
+    fn test_residuals() {
+        // The last point is in the middle, so the fit can't bend to hide its error
+        let mut points = control_points(screenshot_pixel, 5);
+        points[4].pixel = Point2D::new(points[4].pixel.x + 50.0, points[4].pixel.y);
+        let calibration = MapCalibration::fit(&points, CalibrationModel::Similarity).unwrap();
+        let residuals = calibration.residuals();
+        let worst = residuals
+            .iter()
+            .enumerate()
+            .max_by(|a, b| a.1.total_cmp(b.1))
+            .unwrap();
+        assert_eq!(worst.0, 4);
+        assert!(*worst.1 > 100.0);
+        assert!(calibration.rms_error() > 50.0);
+    }
+
+    #[rstest]
+    #[case(CalibrationModel::Similarity, 1, CalibrationError::NotEnoughPoints { required: 2, actual: 1 })]
+    #[case(CalibrationModel::Affine, 2, CalibrationError::NotEnoughPoints { required: 3, actual: 2 })]
+    #[case(CalibrationModel::Projective, 3, CalibrationError::NotEnoughPoints { required: 4, actual: 3 })]
+    fn test_not_enough_points(
+        #[case] model: CalibrationModel,
+        #[case] count: usize,
+        #[case] expected: CalibrationError,
+    ) {
+        let points = control_points(screenshot_pixel, count);
+        assert_eq!(MapCalibration::fit(&points, model), Err(expected));
+    }
+
+    #[test]
+    fn test_degenerate() {
+        let coordinate = Coordinate::new(42.0, -71.0);
+        let same = vec![
+            ControlPoint::new(Point2D::new(10.0, 10.0), coordinate),
+            ControlPoint::new(Point2D::new(10.0, 10.0), coordinate),
+        ];
+        assert_eq!(
+            MapCalibration::fit(&same, CalibrationModel::Similarity),
+            Err(CalibrationError::Degenerate)
+        );
+
+        let in_a_line = vec![
+            ControlPoint::new(Point2D::new(0.0, 0.0), Coordinate::new(42.0, -71.0)),
+            ControlPoint::new(Point2D::new(100.0, 0.0), Coordinate::new(42.0, -70.9)),
+            ControlPoint::new(Point2D::new(200.0, 0.0), Coordinate::new(42.0, -70.8)),
+        ];
+        assert_eq!(
+            MapCalibration::fit(&in_a_line, CalibrationModel::Affine),
+            Err(CalibrationError::Degenerate)
+        );
+    }
+}
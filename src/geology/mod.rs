@@ -0,0 +1,19 @@
+pub mod bounds;
+pub mod datum;
+pub mod ecef;
+pub mod ellipsoid;
+pub mod geo_polygon;
+pub mod geodesy;
+pub mod geohash;
+pub mod geomagnetism;
+pub mod map_calibration;
+pub mod osgb;
+pub mod plus_code;
+pub mod polyline;
+pub mod projection;
+pub mod transverse_mercator;
+pub mod utm;
+pub mod web_mercator;
+
+/// The mean radius of the Earth in meters (IUGG)
+pub const EARTH_MEAN_RADIUS: crate::math::Float = 6371008.8;
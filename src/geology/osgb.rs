@@ -0,0 +1,262 @@
+use std::str::FromStr;
+
+use crate::{
+    math::{Float, geometry::Point2D},
+    units::{coordinate::Coordinate, coordinate_format::ParseCoordinateError},
+};
+
+use super::{
+    datum::{Datum, transform_datum},
+    ellipsoid::Ellipsoid,
+    projection::Projection,
+    transverse_mercator::TransverseMercator,
+};
+
+const MAX_EASTING: Float = 700000.0;
+const MAX_NORTHING: Float = 1300000.0;
+
+/// The letters of the grid squares, skipping I
+const SQUARE_LETTERS: &str = "ABCDEFGHJKLMNOPQRSTUVWXYZ";
+
+/// A location in the Ordnance Survey National Grid of Great Britain (OSGB36 datum)
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct OsgbCoordinate {
+    /// Meters east of the false origin, south west of the Isles of Scilly
+    pub easting: Float,
+    /// Meters north of the false origin
+    pub northing: Float,
+}
+
+impl OsgbCoordinate {
+    /// Converts a WGS84 coordinate to the National Grid. The datum shift is a Helmert transformation,
+    /// which is accurate to about 5 m. Returns None if the location is outside of the grid.
+    pub fn from_coordinate(coordinate: &Coordinate) -> Option<Self> {
+        let (osgb36, _) = transform_datum(coordinate, 0.0, &Datum::WGS84, &Datum::OSGB36);
+        let point = projection().project(&osgb36);
+        let grid = OsgbCoordinate {
+            easting: point.x,
+            northing: point.y,
+        };
+        grid.is_on_grid().then_some(grid)
+    }
+
+    /// Returns the WGS84 coordinate of the location
+    pub fn to_coordinate(&self) -> Coordinate {
+        let osgb36 = projection().unproject(&Point2D::new(self.easting, self.northing));
+        transform_datum(&osgb36, 0.0, &Datum::OSGB36, &Datum::WGS84).0
+    }
+
+    /// Formats the location as a grid reference (ex. "TG 51409 13177"). The digits are the total number
+    /// of digits in the easting and northing, from 0 (100 km) to 10 (1 m). Positions are truncated.
+    pub fn format(&self, digits: usize) -> String {
+        let digits = (digits / 2).min(5);
+        let column = (self.easting / 100000.0).floor() as usize;
+        let row = (self.northing / 100000.0).floor() as usize;
+
+        // The first letter is the 500 km square and the second is the 100 km square within it
+        let first = (19 - row) / 5 * 5 + (column + 10) / 5;
+        let second = (19 - row) * 5 % 25 + column % 5;
+        let letters = format!("{}{}", letter_at(first.min(24)), letter_at(second.min(24)));
+        if digits == 0 {
+            return letters;
+        }
+
+        let step = (10.0 as Float).powi(5 - digits as i32);
+        let format = |meters: Float| {
+            format!(
+                "{:0width$}",
+                ((meters % 100000.0) / step).floor() as u64,
+                width = digits
+            )
+        };
+        format!(
+            "{} {} {}",
+            letters,
+            format(self.easting),
+            format(self.northing)
+        )
+    }
+
+    fn is_on_grid(&self) -> bool {
+        (0.0..MAX_EASTING).contains(&self.easting) && (0.0..MAX_NORTHING).contains(&self.northing)
+    }
+}
+
+impl std::fmt::Display for OsgbCoordinate {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.format(10))
+    }
+}
+
+impl FromStr for OsgbCoordinate {
+    type Err = ParseCoordinateError;
+
+    /// Parses a grid reference (ex. "TG 51409 13177", "TG5140913177" or "TG 514 131") or a numeric
+    /// easting and northing in meters (ex. "651409, 313177")
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let malformed = || ParseCoordinateError::Malformed(s.to_string());
+        let text = s.trim().to_uppercase();
+        let starts_with_letter = text.chars().next().is_some_and(|c| c.is_ascii_alphabetic());
+
+        let grid = if starts_with_letter {
+            let mut chars = text.chars();
+            let (Some(first), Some(second)) = (chars.next(), chars.next()) else {
+                return Err(malformed());
+            };
+            let (column, row) = square_offset(first, second)
+                .ok_or_else(|| ParseCoordinateError::InvalidGridSquare(s.to_string()))?;
+
+            let digits: String = chars.filter(|c| !c.is_whitespace()).collect();
+            if digits.len() > 10
+                || !digits.len().is_multiple_of(2)
+                || !digits.chars().all(|c| c.is_ascii_digit())
+            {
+                return Err(malformed());
+            }
+            let half = digits.len() / 2;
+            let scale = (10.0 as Float).powi(5 - half as i32);
+            let read = |text: &str| -> Float {
+                if text.is_empty() {
+                    0.0
+                } else {
+                    text.parse::<u32>().unwrap_or(0) as Float * scale
+                }
+            };
+            OsgbCoordinate {
+                easting: column as Float * 100000.0 + read(&digits[..half]),
+                northing: row as Float * 100000.0 + read(&digits[half..]),
+            }
+        } else {
+            let numbers: Vec<&str> = text
+                .split(|c: char| c.is_whitespace() || c == ',')
+                .filter(|part| !part.is_empty())
+                .collect();
+            let [easting, northing] = numbers.as_slice() else {
+                return Err(malformed());
+            };
+            OsgbCoordinate {
+                easting: easting.parse().map_err(|_| malformed())?,
+                northing: northing.parse().map_err(|_| malformed())?,
+            }
+        };
+
+        if !grid.is_on_grid() {
+            return Err(ParseCoordinateError::OutsideGrid(s.to_string()));
+        }
+        Ok(grid)
+    }
+}
+
+/// Returns the 100 km column and row of the grid square letters
+fn square_offset(first: char, second: char) -> Option<(usize, usize)> {
+    let first = SQUARE_LETTERS.find(first)?;
+    let second = SQUARE_LETTERS.find(second)?;
+    let column = (first + 3) % 5 * 5 + second % 5;
+    let row = 19usize
+        .checked_sub(first / 5 * 5)?
+        .checked_sub(second / 5)?;
+    (column < 7 && row < 13).then_some((column, row))
+}
+
+fn letter_at(index: usize) -> char {
+    SQUARE_LETTERS.as_bytes()[index] as char
+}
+
+#[allow(clippy::excessive_precision)]
+fn projection() -> TransverseMercator {
+    TransverseMercator {
+        ellipsoid: Ellipsoid::AIRY_1830,
+        central_meridian: -2.0,
+        latitude_of_origin: 49.0,
+        scale_factor: 0.9996012717,
+        false_easting: 400000.0,
+        false_northing: -100000.0,
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::excessive_precision)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::assert_approx_eq;
+
+    #[test]
+    fn test_from_coordinate() {
+        // Ordnance Survey worked example, shifted from OSGB36 to WGS84
+        let grid = OsgbCoordinate::from_coordinate(&Coordinate::new(52.657977, 1.716038)).unwrap();
+        assert_approx_eq!(grid.easting, 651409.903, 2.0);
+        assert_approx_eq!(grid.northing, 313177.270, 2.0);
+    }
+
+    #[rstest]
+    #[case(40.0, -2.0)]
+    #[case(52.0, -20.0)]
+    #[case(62.0, -2.0)]
+    fn test_from_coordinate_outside_grid(#[case] latitude: Float, #[case] longitude: Float) {
+        assert_eq!(
+            OsgbCoordinate::from_coordinate(&Coordinate::new(latitude, longitude)),
+            None
+        );
+    }
+
+    #[rstest]
+    #[case(651409.903, 313177.270, 52.657977, 1.716038)]
+    #[case(216600.0, 771200.0, 56.796088, -5.004712)]
+    #[case(538876.0, 177344.0, 51.478015, -0.001594)]
+    fn test_to_coordinate(
+        #[case] easting: Float,
+        #[case] northing: Float,
+        #[case] latitude: Float,
+        #[case] longitude: Float,
+    ) {
+        let coordinate = OsgbCoordinate { easting, northing }.to_coordinate();
+        assert_approx_eq!(coordinate.latitude, latitude, 1e-4);
+        assert_approx_eq!(coordinate.longitude, longitude, 1e-4);
+    }
+
+    #[rstest]
+    #[case(651409.903, 313177.270, 10, "TG 51409 13177")]
+    #[case(651409.903, 313177.270, 6, "TG 514 131")]
+    #[case(651409.903, 313177.270, 0, "TG")]
+    #[case(0.0, 0.0, 4, "SV 00 00")]
+    #[case(412345.0, 1267890.0, 8, "HP 1234 6789")]
+    #[case(216600.0, 771200.0, 10, "NN 16600 71200")]
+    fn test_format(
+        #[case] easting: Float,
+        #[case] northing: Float,
+        #[case] digits: usize,
+        #[case] expected: &str,
+    ) {
+        assert_eq!(
+            OsgbCoordinate { easting, northing }.format(digits),
+            expected
+        );
+    }
+
+    #[rstest]
+    #[case("TG 51409 13177", 651409.0, 313177.0)]
+    #[case("tg5140913177", 651409.0, 313177.0)]
+    #[case("NN 166 712", 216600.0, 771200.0)]
+    #[case("SV", 0.0, 0.0)]
+    #[case("651409, 313177", 651409.0, 313177.0)]
+    #[case("651409.9 313177.3", 651409.9, 313177.3)]
+    fn test_parse(#[case] text: &str, #[case] easting: Float, #[case] northing: Float) {
+        let grid: OsgbCoordinate = text.parse().unwrap();
+        assert_approx_eq!(grid.easting, easting, 0.01);
+        assert_approx_eq!(grid.northing, northing, 0.01);
+    }
+
+    #[rstest]
+    #[case("TG 5140 131", ParseCoordinateError::Malformed("TG 5140 131".to_string()))]
+    #[case("TG 51409 1317X", ParseCoordinateError::Malformed("TG 51409 1317X".to_string()))]
+    #[case("651409", ParseCoordinateError::Malformed("651409".to_string()))]
+    #[case("AA 12345 12345", ParseCoordinateError::InvalidGridSquare("AA 12345 12345".to_string()))]
+    #[case("TI 12345 12345", ParseCoordinateError::InvalidGridSquare("TI 12345 12345".to_string()))]
+    #[case("800000, 100000", ParseCoordinateError::OutsideGrid("800000, 100000".to_string()))]
+    fn test_parse_errors(#[case] text: &str, #[case] expected: ParseCoordinateError) {
+        assert_eq!(text.parse::<OsgbCoordinate>(), Err(expected));
+    }
+}
@@ -0,0 +1,459 @@
+use crate::{math::Float, units::coordinate::Coordinate};
+
+use super::bounds::CoordinateBounds;
+
+const ALPHABET: &[u8; 20] = b"23456789CFGHJMPQRVWX";
+const SEPARATOR: char = '+';
+const SEPARATOR_POSITION: usize = 8;
+const PADDING: char = '0';
+
+/// The number of digits encoded as latitude and longitude pairs. Digits after this refine a 4 x 5 grid.
+const PAIR_CODE_LENGTH: usize = 10;
+const MAX_CODE_LENGTH: usize = 15;
+const GRID_CODE_LENGTH: usize = MAX_CODE_LENGTH - PAIR_CODE_LENGTH;
+const GRID_COLUMNS: i64 = 4;
+const GRID_ROWS: i64 = 5;
+
+/// The pair resolution of the last pair digit, in units per degree
+const PAIR_PRECISION: i64 = 8000;
+/// The value of the first pair digit in units of the pair precision
+const PAIR_FIRST_PLACE_VALUE: i64 = 160000;
+/// The grid precision of the last digit, in units per degree
+const FINAL_LATITUDE_PRECISION: i64 = PAIR_PRECISION * 3125;
+const FINAL_LONGITUDE_PRECISION: i64 = PAIR_PRECISION * 1024;
+/// The pair resolutions in degrees, from the first pair to the last
+const PAIR_RESOLUTIONS: [Float; 5] = [20.0, 1.0, 0.05, 0.0025, 0.000125];
+/// The shortest full code which can be shortened
+const MIN_TRIMMABLE_CODE_LENGTH: usize = 6;
+
+/// The default code length, which is about 14 m by 14 m
+pub const DEFAULT_PLUS_CODE_LENGTH: usize = 10;
+
+/// Encodes the coordinate as a full Open Location Code (Plus Code), such as "849VCWC8+R9". The code
+/// length is the number of digits from 2 to 15, where 10 is about 14 m and 11 is about 3 m. Odd lengths
+/// below 10 are rounded up to the next even length.
+pub fn encode_plus_code(coordinate: &Coordinate, code_length: usize) -> String {
+    let mut code_length = code_length.clamp(2, MAX_CODE_LENGTH);
+    if code_length < PAIR_CODE_LENGTH && code_length % 2 == 1 {
+        code_length += 1;
+    }
+
+    let latitude = coordinate.latitude.clamp(-90.0, 90.0);
+    let longitude = normalize_longitude(coordinate.longitude);
+    let max_latitude = 180 * FINAL_LATITUDE_PRECISION;
+    let mut latitude_value = (((latitude + 90.0) * FINAL_LATITUDE_PRECISION as Float).floor()
+        as i64)
+        .clamp(0, max_latitude - 1);
+    let mut longitude_value = (((longitude + 180.0) * FINAL_LONGITUDE_PRECISION as Float).floor()
+        as i64)
+        .clamp(0, 360 * FINAL_LONGITUDE_PRECISION - 1);
+
+    // Built in reverse, from the last digit
+    let mut digits: Vec<u8> = Vec::with_capacity(MAX_CODE_LENGTH);
+    if code_length > PAIR_CODE_LENGTH {
+        for _ in 0..GRID_CODE_LENGTH {
+            let row = latitude_value % GRID_ROWS;
+            let column = longitude_value % GRID_COLUMNS;
+            digits.push(ALPHABET[(row * GRID_COLUMNS + column) as usize]);
+            latitude_value /= GRID_ROWS;
+            longitude_value /= GRID_COLUMNS;
+        }
+    } else {
+        latitude_value /= GRID_ROWS.pow(GRID_CODE_LENGTH as u32);
+        longitude_value /= GRID_COLUMNS.pow(GRID_CODE_LENGTH as u32);
+    }
+    for _ in 0..PAIR_CODE_LENGTH / 2 {
+        digits.push(ALPHABET[(longitude_value % 20) as usize]);
+        digits.push(ALPHABET[(latitude_value % 20) as usize]);
+        latitude_value /= 20;
+        longitude_value /= 20;
+    }
+    digits.reverse();
+
+    let digits: String = digits.iter().map(|&d| d as char).collect();
+    let mut code = String::with_capacity(MAX_CODE_LENGTH + 1);
+    if code_length < SEPARATOR_POSITION {
+        code.push_str(&digits[..code_length]);
+        code.extend(std::iter::repeat_n(
+            PADDING,
+            SEPARATOR_POSITION - code_length,
+        ));
+        code.push(SEPARATOR);
+    } else {
+        code.push_str(&digits[..SEPARATOR_POSITION]);
+        code.push(SEPARATOR);
+        code.push_str(&digits[SEPARATOR_POSITION..code_length]);
+    }
+    code
+}
+
+/// Returns the area of a full Plus Code. Short codes need to be recovered first with `recover_plus_code`.
+pub fn decode_plus_code(code: &str) -> Result<CoordinateBounds, InvalidPlusCode> {
+    if !is_full_plus_code(code) {
+        return Err(InvalidPlusCode(code.to_string()));
+    }
+
+    let values: Vec<i64> = code
+        .trim()
+        .to_uppercase()
+        .chars()
+        .filter(|c| *c != SEPARATOR && *c != PADDING)
+        .take(MAX_CODE_LENGTH)
+        .filter_map(digit_value)
+        .collect();
+
+    let mut latitude = -90 * PAIR_PRECISION;
+    let mut longitude = -180 * PAIR_PRECISION;
+    let pair_length = values.len().min(PAIR_CODE_LENGTH);
+    let mut place_value = PAIR_FIRST_PLACE_VALUE;
+    for i in (0..pair_length).step_by(2) {
+        latitude += values[i] * place_value;
+        longitude += values[i + 1] * place_value;
+        if i + 2 < pair_length {
+            place_value /= 20;
+        }
+    }
+    let mut latitude_size = place_value * FINAL_LATITUDE_PRECISION / PAIR_PRECISION;
+    let mut longitude_size = place_value * FINAL_LONGITUDE_PRECISION / PAIR_PRECISION;
+
+    let mut grid_latitude = 0;
+    let mut grid_longitude = 0;
+    if values.len() > PAIR_CODE_LENGTH {
+        let mut row_value = GRID_ROWS.pow(GRID_CODE_LENGTH as u32 - 1);
+        let mut column_value = GRID_COLUMNS.pow(GRID_CODE_LENGTH as u32 - 1);
+        for i in PAIR_CODE_LENGTH..values.len() {
+            grid_latitude += values[i] / GRID_COLUMNS * row_value;
+            grid_longitude += values[i] % GRID_COLUMNS * column_value;
+            if i + 1 < values.len() {
+                row_value /= GRID_ROWS;
+                column_value /= GRID_COLUMNS;
+            }
+        }
+        latitude_size = row_value;
+        longitude_size = column_value;
+    }
+
+    // Work in the final precision so the edges are exact before converting to degrees
+    let south = latitude * (FINAL_LATITUDE_PRECISION / PAIR_PRECISION) + grid_latitude;
+    let west = longitude * (FINAL_LONGITUDE_PRECISION / PAIR_PRECISION) + grid_longitude;
+    let to_latitude = |value: i64| value as Float / FINAL_LATITUDE_PRECISION as Float;
+    let to_longitude = |value: i64| value as Float / FINAL_LONGITUDE_PRECISION as Float;
+    Ok(CoordinateBounds::new(
+        to_latitude(south + latitude_size).min(90.0),
+        to_longitude(west + longitude_size).min(180.0),
+        to_latitude(south),
+        to_longitude(west),
+    ))
+}
+
+/// Removes as many leading digits from the full code as can be recovered from a reference location
+/// within about a quarter of the code's area (ex. "CWC8+R9" near Mountain View). Codes shorter than
+/// 6 digits and padded codes can't be shortened.
+pub fn shorten_plus_code(code: &str, reference: &Coordinate) -> Result<String, InvalidPlusCode> {
+    let invalid = || InvalidPlusCode(code.to_string());
+    if !is_full_plus_code(code) || code.contains(PADDING) {
+        return Err(invalid());
+    }
+    let code = code.trim().to_uppercase();
+    if code.len() - 1 < MIN_TRIMMABLE_CODE_LENGTH {
+        return Err(invalid());
+    }
+
+    let center = decode_plus_code(&code)?.center();
+    let latitude = reference.latitude.clamp(-90.0, 90.0);
+    let longitude = normalize_longitude(reference.longitude);
+    let range = (center.latitude - latitude)
+        .abs()
+        .max((center.longitude - longitude).abs());
+
+    // Remove the most pairs possible while keeping the reference well inside the recovered area
+    for i in (1..PAIR_RESOLUTIONS.len() - 1).rev() {
+        if range < PAIR_RESOLUTIONS[i] * 0.3 {
+            return Ok(code[(i + 1) * 2..].to_string());
+        }
+    }
+    Ok(code)
+}
+
+/// Recovers the full code of a short code from the nearest matching location to the reference. Full
+/// codes are returned unchanged.
+pub fn recover_plus_code(code: &str, reference: &Coordinate) -> Result<String, InvalidPlusCode> {
+    if is_full_plus_code(code) {
+        return Ok(code.trim().to_uppercase());
+    }
+    if !is_short_plus_code(code) {
+        return Err(InvalidPlusCode(code.to_string()));
+    }
+
+    let code = code.trim().to_uppercase();
+    let latitude = reference.latitude.clamp(-90.0, 90.0);
+    let longitude = normalize_longitude(reference.longitude);
+    let separator = code.find(SEPARATOR).unwrap_or(0);
+    let padding_length = SEPARATOR_POSITION - separator;
+    let resolution = (20.0 as Float).powi(2 - padding_length as i32 / 2);
+    let half_resolution = resolution / 2.0;
+
+    // Use the reference's leading digits, then move to the neighboring cell if that is closer
+    let prefix = encode_plus_code(&Coordinate::new(latitude, longitude), MAX_CODE_LENGTH);
+    let full = format!("{}{}", &prefix[..padding_length], code);
+    let area = decode_plus_code(&full)?;
+    let mut center = area.center();
+    if latitude + half_resolution < center.latitude && center.latitude - resolution >= -90.0 {
+        center.latitude -= resolution;
+    } else if latitude - half_resolution > center.latitude && center.latitude + resolution <= 90.0 {
+        center.latitude += resolution;
+    }
+    if longitude + half_resolution < center.longitude {
+        center.longitude -= resolution;
+    } else if longitude - half_resolution > center.longitude {
+        center.longitude += resolution;
+    }
+
+    let code_length = full.len() - 1;
+    Ok(encode_plus_code(&center, code_length))
+}
+
+/// Returns true if the text is a valid full or short Plus Code
+pub fn is_valid_plus_code(code: &str) -> bool {
+    let code = code.trim().to_uppercase();
+    let Some(separator) = code.find(SEPARATOR) else {
+        return false;
+    };
+    if code.len() < 2
+        || code.matches(SEPARATOR).count() != 1
+        || separator > SEPARATOR_POSITION
+        || separator % 2 == 1
+        || code.len() - separator == 2
+    {
+        return false;
+    }
+
+    if let Some(padding) = code.find(PADDING) {
+        // Padding is only allowed in full codes, in pairs, and directly before the separator
+        let padding_length = code[padding..]
+            .chars()
+            .take_while(|c| *c == PADDING)
+            .count();
+        if padding == 0
+            || padding % 2 == 1
+            || padding_length % 2 == 1
+            || padding + padding_length != separator
+            || separator != SEPARATOR_POSITION
+            || !code.ends_with(SEPARATOR)
+        {
+            return false;
+        }
+    }
+
+    code.chars()
+        .all(|c| c == SEPARATOR || c == PADDING || digit_value(c).is_some())
+}
+
+/// Returns true if the text is a valid short Plus Code, which is missing its leading digits
+pub fn is_short_plus_code(code: &str) -> bool {
+    is_valid_plus_code(code)
+        && code
+            .trim()
+            .find(SEPARATOR)
+            .is_some_and(|separator| separator < SEPARATOR_POSITION)
+}
+
+/// Returns true if the text is a valid full Plus Code
+pub fn is_full_plus_code(code: &str) -> bool {
+    if !is_valid_plus_code(code) || is_short_plus_code(code) {
+        return false;
+    }
+    let code = code.trim().to_uppercase();
+    let mut values = code.chars().filter_map(digit_value);
+    let latitude = values.next().unwrap_or(0) * 20;
+    let longitude = values.next().unwrap_or(0) * 20;
+    latitude < 180 && longitude < 360
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct InvalidPlusCode(pub String);
+
+impl std::fmt::Display for InvalidPlusCode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "invalid plus code: '{}'", self.0)
+    }
+}
+
+impl std::error::Error for InvalidPlusCode {}
+
+fn digit_value(c: char) -> Option<i64> {
+    ALPHABET
+        .iter()
+        .position(|&d| d as char == c.to_ascii_uppercase())
+        .map(|position| position as i64)
+}
+
+/// Wraps the longitude into [-180, 180)
+fn normalize_longitude(longitude: Float) -> Float {
+    (longitude + 180.0).rem_euclid(360.0) - 180.0
+}
+
+#[cfg(test)]
+#[allow(clippy::excessive_precision)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::assert_approx_eq;
+
+    #[rstest]
+    #[case(20.375, 2.775, 6, "7FG49Q00+")]
+    #[case(20.3700625, 2.7821875, 10, "7FG49QCJ+2V")]
+    #[case(47.0000625, 8.0000625, 10, "8FVC2222+22")]
+    #[case(-41.2730625, 174.7859375, 10, "4VCPPQGP+Q9")]
+    #[case(0.5, -179.5, 4, "62G20000+")]
+    #[case(-89.5, -179.5, 4, "22220000+")]
+    #[case(20.5, 2.5, 4, "7FG40000+")]
+    #[case(-89.9999375, -179.9999375, 10, "22222222+22")]
+    #[case(0.5, 179.5, 4, "6VGX0000+")]
+    #[case(90.0, 1.0, 4, "CFX30000+")]
+    #[case(92.0, 1.0, 4, "CFX30000+")]
+    #[case(1.0, 180.0, 4, "62H20000+")]
+    #[case(1.0, 181.0, 4, "62H30000+")]
+    #[case(20.375, 2.775, 5, "7FG49Q00+")]
+    #[case(20.375, 2.775, 1, "7F000000+")]
+    fn test_encode_plus_code(
+        #[case] latitude: Float,
+        #[case] longitude: Float,
+        #[case] code_length: usize,
+        #[case] expected: &str,
+    ) {
+        let code = encode_plus_code(&Coordinate::new(latitude, longitude), code_length);
+        assert_eq!(code, expected);
+    }
+
+    #[cfg(feature = "f64")]
+    #[rstest]
+    #[case(20.3701125, 2.782234375, 11, "7FG49QCJ+2VX")]
+    #[case(20.3701135, 2.78223535156, 13, "7FG49QCJ+2VXGJ")]
+    #[case(1.0, 1.0, 11, "6FH32222+222")]
+    fn test_encode_plus_code_grid(
+        #[case] latitude: Float,
+        #[case] longitude: Float,
+        #[case] code_length: usize,
+        #[case] expected: &str,
+    ) {
+        let code = encode_plus_code(&Coordinate::new(latitude, longitude), code_length);
+        assert_eq!(code, expected);
+    }
+
+    #[rstest]
+    #[case("7FG49Q00+", 20.35, 2.75, 20.4, 2.8)]
+    #[case("7fg49qcj+2v", 20.37, 2.782125, 20.370125, 2.78225)]
+    #[case("7FG49QCJ+2VX", 20.3701, 2.78221875, 20.370125, 2.78225)]
+    #[case("CFX30000+", 89.0, 1.0, 90.0, 2.0)]
+    #[case("62G20000+", 0.0, -180.0, 1.0, -179.0)]
+    fn test_decode_plus_code(
+        #[case] code: &str,
+        #[case] south: Float,
+        #[case] west: Float,
+        #[case] north: Float,
+        #[case] east: Float,
+    ) {
+        let area = decode_plus_code(code).unwrap();
+        assert_approx_eq!(area.south, south, 1e-5);
+        assert_approx_eq!(area.west, west, 1e-5);
+        assert_approx_eq!(area.north, north, 1e-5);
+        assert_approx_eq!(area.east, east, 1e-5);
+    }
+
+    #[rstest]
+    #[case("")]
+    #[case("7FG49QCJ2V")]
+    #[case("7FG49QCJ+2")]
+    #[case("7FG4+9QCJ+2V")]
+    #[case("7FG49QCJ+2A")]
+    #[case("7FG4900+")]
+    #[case("7FG49Q00+2V")]
+    #[case("WC2345G6+")]
+    #[case("CWC8+R9")]
+    fn test_decode_plus_code_invalid(#[case] code: &str) {
+        assert_eq!(
+            decode_plus_code(code),
+            Err(InvalidPlusCode(code.to_string()))
+        );
+    }
+
+    #[rstest]
+    #[case("849VCWC8+R9", true, true, false)]
+    #[case("CWC8+R9", true, false, true)]
+    #[case("+2VX", true, false, true)]
+    #[case("7FG40000+", true, true, false)]
+    #[case("7FG40000+2V", false, false, false)]
+    #[case("7FG40+", false, false, false)]
+    #[case("0FG40000+", false, false, false)]
+    #[case("X2G40000+", true, false, false)]
+    #[case("+", false, false, false)]
+    fn test_plus_code_validity(
+        #[case] code: &str,
+        #[case] is_valid: bool,
+        #[case] is_full: bool,
+        #[case] is_short: bool,
+    ) {
+        assert_eq!(is_valid_plus_code(code), is_valid);
+        assert_eq!(is_full_plus_code(code), is_full);
+        assert_eq!(is_short_plus_code(code), is_short);
+    }
+
+    #[rstest]
+    #[case("9C3W9QCJ+2VX", 51.3701125, -1.217765625, "+2VX")]
+    #[case("9C3W9QCJ+2VX", 51.3708675, -1.217765625, "CJ+2VX")]
+    #[case("9C3W9QCJ+2VX", 51.3701125, -1.2, "9QCJ+2VX")]
+    #[case("9C3W9QCJ+2VX", 51.45, -1.217765625, "9QCJ+2VX")]
+    #[case("9C3W9QCJ+2VX", 55.0, -1.217765625, "9C3W9QCJ+2VX")]
+    #[case("849VCWC8+R9", 37.4, -122.1, "CWC8+R9")]
+    fn test_shorten_plus_code(
+        #[case] code: &str,
+        #[case] latitude: Float,
+        #[case] longitude: Float,
+        #[case] expected: &str,
+    ) {
+        let short = shorten_plus_code(code, &Coordinate::new(latitude, longitude)).unwrap();
+        assert_eq!(short, expected);
+    }
+
+    #[rstest]
+    #[case("7FG40000+")]
+    #[case("7FG4+")]
+    #[case("CWC8+R9")]
+    fn test_shorten_plus_code_invalid(#[case] code: &str) {
+        assert_eq!(
+            shorten_plus_code(code, &Coordinate::new(0.0, 0.0)),
+            Err(InvalidPlusCode(code.to_string()))
+        );
+    }
+
+    #[rstest]
+    #[case("CWC8+R9", 37.4, -122.1, "849VCWC8+R9")]
+    #[case("cwc8+r9", 37.4, -122.1, "849VCWC8+R9")]
+    #[case("9QCJ+2VX", 51.45, -1.217765625, "9C3W9QCJ+2VX")]
+    #[case("849VCWC8+R9", 0.0, 0.0, "849VCWC8+R9")]
+    // The nearest match is in the next cell to the north or across the antimeridian
+    #[case("2222+22", 20.9999, 2.9, "7FH52222+22")]
+    #[case("XXXX+XX", 21.0001, 3.1, "7FG4XXXX+XX")]
+    #[case("2222+22", 0.5, 179.9, "62G22222+22")]
+    fn test_recover_plus_code(
+        #[case] code: &str,
+        #[case] latitude: Float,
+        #[case] longitude: Float,
+        #[case] expected: &str,
+    ) {
+        let full = recover_plus_code(code, &Coordinate::new(latitude, longitude)).unwrap();
+        assert_eq!(full, expected);
+    }
+
+    #[rstest]
+    #[case("CWC8+R")]
+    #[case("7FG40+")]
+    fn test_recover_plus_code_invalid(#[case] code: &str) {
+        assert_eq!(
+            recover_plus_code(code, &Coordinate::new(37.4, -122.1)),
+            Err(InvalidPlusCode(code.to_string()))
+        );
+    }
+}
@@ -0,0 +1,162 @@
+use crate::{
+    math::{
+        Float,
+        geometry::{douglas_peucker_mask, visvalingam_whyatt_mask},
+    },
+    units::{
+        area::Area,
+        coordinate::Coordinate,
+        distance::Distance,
+        quantity::{Convertable, Quantity},
+    },
+};
+
+use super::{
+    EARTH_MEAN_RADIUS,
+    geodesy::{great_circle_distance_to_segment, normalize_longitude},
+};
+
+/// Simplifies the path with the Douglas-Peucker algorithm, keeping the points needed so no removed
+/// point is further than the tolerance from the simplified path. The first and last points are always
+/// kept.
+pub fn douglas_peucker(
+    coordinates: &[Coordinate],
+    tolerance: &Quantity<Distance>,
+) -> Vec<Coordinate> {
+    let meters = tolerance.convert(Distance::Meters).amount;
+    let keep = douglas_peucker_mask(coordinates.len(), meters, |i, start, end| {
+        great_circle_distance_to_segment(&coordinates[i], &coordinates[start], &coordinates[end])
+    });
+    filter(coordinates, &keep)
+}
+
+/// Simplifies the path with the Visvalingam-Whyatt algorithm, repeatedly removing the point which forms
+/// the smallest triangle with its neighbors until every remaining triangle is at least the minimum
+/// area. The first and last points are always kept.
+pub fn visvalingam_whyatt(
+    coordinates: &[Coordinate],
+    min_area: &Quantity<Area>,
+) -> Vec<Coordinate> {
+    let square_meters = min_area.convert(Area::SquareMeters).amount;
+    let keep = visvalingam_whyatt_mask(coordinates.len(), square_meters, |a, b, c| {
+        triangle_area(&coordinates[a], &coordinates[b], &coordinates[c])
+    });
+    filter(coordinates, &keep)
+}
+
+/// Returns points evenly spaced along the path (following geodesics between the points), starting at
+/// the first point. The last point is always included, so the final gap may be shorter than the spacing.
+pub fn resample(coordinates: &[Coordinate], spacing: &Quantity<Distance>) -> Vec<Coordinate> {
+    let spacing = spacing.convert(Distance::Meters).amount;
+    if coordinates.len() < 2 || spacing <= 0.0 {
+        return coordinates.to_vec();
+    }
+    let mut resampled = vec![coordinates[0]];
+    // The distance along the current segment where the next point goes
+    let mut next = spacing;
+    for pair in coordinates.windows(2) {
+        let length = pair[0].distance_to(&pair[1]).amount;
+        let bearing = pair[0].bearing_to(&pair[1]);
+        while next <= length {
+            resampled.push(pair[0].destination(&Quantity::new(next, Distance::Meters), &bearing));
+            next += spacing;
+        }
+        next -= length;
+    }
+    let last = coordinates[coordinates.len() - 1];
+    if resampled.last() != Some(&last) {
+        resampled.push(last);
+    }
+    resampled
+}
+
+fn filter(coordinates: &[Coordinate], keep: &[bool]) -> Vec<Coordinate> {
+    coordinates
+        .iter()
+        .zip(keep)
+        .filter(|(_, keep)| **keep)
+        .map(|(coordinate, _)| *coordinate)
+        .collect()
+}
+
+/// Returns the area of the triangle in square meters, using a flat approximation around the middle
+/// point (fine for the small triangles between neighboring points on a path)
+fn triangle_area(a: &Coordinate, b: &Coordinate, c: &Coordinate) -> Float {
+    let scale = b.latitude.to_radians().cos();
+    let offset = |point: &Coordinate| {
+        (
+            normalize_longitude(point.longitude - b.longitude).to_radians()
+                * scale
+                * EARTH_MEAN_RADIUS,
+            (point.latitude - b.latitude).to_radians() * EARTH_MEAN_RADIUS,
+        )
+    };
+    let (ax, ay) = offset(a);
+    let (cx, cy) = offset(c);
+    (ax * cy - cx * ay).abs() / 2.0
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::assert_approx_eq;
+
+    /// A path which goes east along the equator with a bump, then turns north
+    fn path() -> Vec<Coordinate> {
+        vec![
+            Coordinate::new(0.0, 0.0),
+            Coordinate::new(0.00001, 0.001),
+            Coordinate::new(0.0, 0.002),
+            Coordinate::new(0.0003, 0.003),
+            Coordinate::new(0.0, 0.004),
+            Coordinate::new(0.01, 0.004),
+            Coordinate::new(0.02, 0.004),
+        ]
+    }
+
+    #[rstest]
+    #[case(5.0, vec![0, 2, 3, 4, 6])]
+    #[case(50.0, vec![0, 4, 6])]
+    #[case(0.1, vec![0, 1, 2, 3, 4, 6])]
+    fn test_douglas_peucker(#[case] tolerance: Float, #[case] expected: Vec<usize>) {
+        let path = path();
+        let simplified = douglas_peucker(&path, &Quantity::new(tolerance, Distance::Meters));
+        let expected: Vec<Coordinate> = expected.iter().map(|i| path[*i]).collect();
+        assert_eq!(simplified, expected);
+    }
+
+    #[rstest]
+    #[case(1000.0, vec![0, 2, 3, 4, 6])]
+    #[case(50000.0, vec![0, 4, 6])]
+    #[case(1.0, vec![0, 1, 2, 3, 4, 6])]
+    fn test_visvalingam_whyatt(#[case] min_area: Float, #[case] expected: Vec<usize>) {
+        let path = path();
+        let simplified = visvalingam_whyatt(&path, &Quantity::new(min_area, Area::SquareMeters));
+        let expected: Vec<Coordinate> = expected.iter().map(|i| path[*i]).collect();
+        assert_eq!(simplified, expected);
+    }
+
+    #[test]
+    fn test_resample() {
+        let path = vec![
+            Coordinate::new(0.0, 179.99),
+            Coordinate::new(0.0, -179.99),
+            Coordinate::new(0.01, -179.99),
+        ];
+        let resampled = resample(&path, &Quantity::new(1000.0, Distance::Meters));
+        // 2226 m east then 1106 m north
+        assert_eq!(resampled.len(), 5);
+        assert_approx_eq!(resampled[1].longitude, 179.99 + 1000.0 / 111319.49, 1e-4);
+        assert_approx_eq!(resampled[2].longitude, -179.99 - 226.39 / 111319.49, 1e-4);
+        assert_approx_eq!(resampled[3].latitude, (1000.0 - 226.39) / 110574.39, 1e-4);
+        assert_approx_eq!(resampled[3].longitude, -179.99, 1e-4);
+        assert_eq!(resampled[4], path[2]);
+
+        assert_eq!(
+            resample(&path[..1], &Quantity::new(1.0, Distance::Meters)),
+            path[..1].to_vec()
+        );
+    }
+}
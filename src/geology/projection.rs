@@ -0,0 +1,248 @@
+use crate::{
+    math::{Float, geometry::Point2D},
+    units::{bearing::Bearing, coordinate::Coordinate, distance::Distance, quantity::Quantity},
+};
+
+use super::{
+    EARTH_MEAN_RADIUS,
+    ellipsoid::Ellipsoid,
+    geodesy::normalize_longitude,
+    transverse_mercator::{conformal_tau, geodetic_tau},
+};
+
+/// A map projection between coordinates and points on a plane. Points are in meters unless the
+/// projection says otherwise.
+pub trait Projection {
+    fn project(&self, coordinate: &Coordinate) -> Point2D;
+    fn unproject(&self, point: &Point2D) -> Coordinate;
+}
+
+/// The Mercator projection on an ellipsoid. It is conformal, but the scale grows toward the poles, which
+/// are at infinity.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Mercator {
+    pub ellipsoid: Ellipsoid,
+    /// The longitude of the central meridian in degrees
+    pub central_meridian: Float,
+    /// The scale factor on the equator
+    pub scale_factor: Float,
+    /// The easting of the origin in meters
+    pub false_easting: Float,
+    /// The northing of the origin in meters
+    pub false_northing: Float,
+}
+
+impl Mercator {
+    /// World Mercator on WGS84 (EPSG:3395)
+    pub const WORLD: Mercator = Mercator {
+        ellipsoid: Ellipsoid::WGS84,
+        central_meridian: 0.0,
+        scale_factor: 1.0,
+        false_easting: 0.0,
+        false_northing: 0.0,
+    };
+
+    fn radius(&self) -> Float {
+        self.ellipsoid.semi_major_axis * self.scale_factor
+    }
+}
+
+impl Projection for Mercator {
+    fn project(&self, coordinate: &Coordinate) -> Point2D {
+        let e = self.ellipsoid.eccentricity_squared().sqrt();
+        let lambda = normalize_longitude(coordinate.longitude - self.central_meridian).to_radians();
+        let tau = conformal_tau(coordinate.latitude.to_radians().tan(), e);
+        Point2D::new(
+            self.false_easting + self.radius() * lambda,
+            self.false_northing + self.radius() * tau.asinh(),
+        )
+    }
+
+    fn unproject(&self, point: &Point2D) -> Coordinate {
+        let e = self.ellipsoid.eccentricity_squared().sqrt();
+        let lambda = (point.x - self.false_easting) / self.radius();
+        let tau_prime = ((point.y - self.false_northing) / self.radius()).sinh();
+        Coordinate::new(
+            geodetic_tau(tau_prime, e).atan().to_degrees(),
+            normalize_longitude(self.central_meridian + lambda.to_degrees()),
+        )
+    }
+}
+
+/// The equirectangular (plate carrée) projection on a sphere, which maps longitude and latitude
+/// linearly. Distances are true along the meridians and the standard parallel.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Equirectangular {
+    /// The longitude of the central meridian in degrees
+    pub central_meridian: Float,
+    /// The latitude in degrees where the scale is true
+    pub standard_parallel: Float,
+    /// The radius of the sphere in meters
+    pub radius: Float,
+}
+
+impl Equirectangular {
+    /// Creates the projection on a sphere with the Earth's mean radius
+    pub fn new(central_meridian: Float, standard_parallel: Float) -> Self {
+        Equirectangular {
+            central_meridian,
+            standard_parallel,
+            radius: EARTH_MEAN_RADIUS,
+        }
+    }
+}
+
+impl Projection for Equirectangular {
+    fn project(&self, coordinate: &Coordinate) -> Point2D {
+        let lambda = normalize_longitude(coordinate.longitude - self.central_meridian).to_radians();
+        Point2D::new(
+            self.radius * lambda * self.standard_parallel.to_radians().cos(),
+            self.radius * coordinate.latitude.to_radians(),
+        )
+    }
+
+    fn unproject(&self, point: &Point2D) -> Coordinate {
+        let lambda = point.x / (self.radius * self.standard_parallel.to_radians().cos());
+        Coordinate::new(
+            (point.y / self.radius).to_degrees(),
+            normalize_longitude(self.central_meridian + lambda.to_degrees()),
+        )
+    }
+}
+
+/// The azimuthal equidistant projection on the WGS84 ellipsoid. Distances and bearings from the center
+/// are true, which makes it useful for range rings.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AzimuthalEquidistant {
+    pub center: Coordinate,
+}
+
+impl AzimuthalEquidistant {
+    pub fn new(center: Coordinate) -> Self {
+        AzimuthalEquidistant { center }
+    }
+}
+
+impl Projection for AzimuthalEquidistant {
+    fn project(&self, coordinate: &Coordinate) -> Point2D {
+        let distance = self.center.distance_to(coordinate).amount;
+        if distance == 0.0 {
+            return Point2D::new(0.0, 0.0);
+        }
+        let bearing = self.center.bearing_to(coordinate).degrees.to_radians();
+        Point2D::new(distance * bearing.sin(), distance * bearing.cos())
+    }
+
+    fn unproject(&self, point: &Point2D) -> Coordinate {
+        let distance = point.x.hypot(point.y);
+        let bearing = Bearing::new(point.x.atan2(point.y).to_degrees());
+        self.center
+            .destination(&Quantity::new(distance, Distance::Meters), &bearing)
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::excessive_precision)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::assert_approx_eq;
+
+    #[rstest]
+    #[case(0.0, 0.0, 0.0, 0.0)]
+    #[case(45.0, 45.0, 5009377.086, 5591295.919)]
+    #[case(-60.0, -120.0, -13358338.895, -8362698.549)]
+    fn test_mercator(
+        #[case] latitude: Float,
+        #[case] longitude: Float,
+        #[case] x: Float,
+        #[case] y: Float,
+    ) {
+        let point = Mercator::WORLD.project(&Coordinate::new(latitude, longitude));
+        assert_approx_eq!(point.x, x, 1.0);
+        assert_approx_eq!(point.y, y, 1.0);
+
+        let coordinate = Mercator::WORLD.unproject(&point);
+        assert_approx_eq!(coordinate.latitude, latitude, 1e-5);
+        assert_approx_eq!(coordinate.longitude, longitude, 1e-5);
+    }
+
+    #[test]
+    fn test_mercator_wraps_around_antimeridian() {
+        let projection = Mercator {
+            central_meridian: 150.0,
+            ..Mercator::WORLD
+        };
+        let point = projection.project(&Coordinate::new(0.0, -170.0));
+        assert_approx_eq!(point.x, 4452779.632, 1.0);
+
+        let coordinate = projection.unproject(&point);
+        assert_approx_eq!(coordinate.longitude, -170.0, 1e-5);
+    }
+
+    #[rstest]
+    #[case(0.0, 45.0, 45.0, 5003778.611, 5003778.611)]
+    #[case(30.0, 45.0, 45.0, 4333399.392, 5003778.611)]
+    #[case(0.0, -90.0, 90.0, 10007557.221, -10007557.221)]
+    fn test_equirectangular(
+        #[case] standard_parallel: Float,
+        #[case] latitude: Float,
+        #[case] longitude: Float,
+        #[case] x: Float,
+        #[case] y: Float,
+    ) {
+        let projection = Equirectangular::new(0.0, standard_parallel);
+        let point = projection.project(&Coordinate::new(latitude, longitude));
+        assert_approx_eq!(point.x, x, 2.0);
+        assert_approx_eq!(point.y, y, 2.0);
+
+        let coordinate = projection.unproject(&point);
+        assert_approx_eq!(coordinate.latitude, latitude, 1e-5);
+        assert_approx_eq!(coordinate.longitude, longitude, 1e-5);
+    }
+
+    #[rstest]
+    #[case(
+        Coordinate::new(0.0, 0.0),
+        Coordinate::new(0.0, 10.0),
+        1113194.908,
+        0.0
+    )]
+    #[case(
+        Coordinate::new(0.0, 0.0),
+        Coordinate::new(10.0, 0.0),
+        0.0,
+        1105854.833
+    )]
+    #[case(Coordinate::new(0.0, 0.0), Coordinate::new(-10.0, 0.0), 0.0, -1105854.833)]
+    #[case(Coordinate::new(45.0, 10.0), Coordinate::new(45.0, 10.0), 0.0, 0.0)]
+    fn test_azimuthal_equidistant(
+        #[case] center: Coordinate,
+        #[case] coordinate: Coordinate,
+        #[case] x: Float,
+        #[case] y: Float,
+    ) {
+        let projection = AzimuthalEquidistant::new(center);
+        let point = projection.project(&coordinate);
+        assert_approx_eq!(point.x, x, 1.0);
+        assert_approx_eq!(point.y, y, 1.0);
+
+        let actual = projection.unproject(&point);
+        assert_approx_eq!(actual.latitude, coordinate.latitude, 1e-5);
+        assert_approx_eq!(actual.longitude, coordinate.longitude, 1e-5);
+    }
+
+    #[test]
+    fn test_azimuthal_equidistant_preserves_distance() {
+        let center = Coordinate::new(42.0, -71.0);
+        let coordinate = Coordinate::new(44.5, -68.25);
+        let projection = AzimuthalEquidistant::new(center);
+        let point = projection.project(&coordinate);
+        let distance = center.distance_to(&coordinate).amount;
+        assert_approx_eq!(point.x.hypot(point.y), distance, 1.0);
+    }
+}
@@ -0,0 +1,228 @@
+use crate::{
+    math::{Float, geometry::Point2D},
+    units::coordinate::Coordinate,
+};
+
+use super::{ellipsoid::Ellipsoid, projection::Projection};
+
+/// A transverse Mercator projection, computed with Krüger's series to the sixth order in n (Karney
+/// 2011). This is accurate to well under a millimeter within a few thousand kilometers of the central
+/// meridian.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TransverseMercator {
+    pub ellipsoid: Ellipsoid,
+    /// The longitude of the central meridian in degrees
+    pub central_meridian: Float,
+    /// The latitude of the origin in degrees
+    pub latitude_of_origin: Float,
+    /// The scale factor on the central meridian
+    pub scale_factor: Float,
+    /// The easting of the origin in meters
+    pub false_easting: Float,
+    /// The northing of the origin in meters
+    pub false_northing: Float,
+}
+
+impl Projection for TransverseMercator {
+    /// Projects the coordinate, returning the easting (x) and northing (y) in meters
+    fn project(&self, coordinate: &Coordinate) -> Point2D {
+        let (x, y) = self.plane_coordinates(coordinate.latitude, coordinate.longitude);
+        let (_, origin_y) = self.plane_coordinates(self.latitude_of_origin, self.central_meridian);
+        Point2D::new(x + self.false_easting, y - origin_y + self.false_northing)
+    }
+
+    fn unproject(&self, point: &Point2D) -> Coordinate {
+        let (_, origin_y) = self.plane_coordinates(self.latitude_of_origin, self.central_meridian);
+        let scale = self.scale_factor * self.rectifying_radius();
+        let xi = (point.y - self.false_northing + origin_y) / scale;
+        let eta = (point.x - self.false_easting) / scale;
+
+        let beta = inverse_coefficients(self.third_flattening());
+        let mut xi_prime = xi;
+        let mut eta_prime = eta;
+        for (j, b) in beta.iter().enumerate() {
+            let k = 2.0 * (j + 1) as Float;
+            xi_prime -= b * (k * xi).sin() * (k * eta).cosh();
+            eta_prime -= b * (k * xi).cos() * (k * eta).sinh();
+        }
+
+        let (sin_xi, cos_xi) = xi_prime.sin_cos();
+        let sinh_eta = eta_prime.sinh();
+        let tau_prime = sin_xi / (sinh_eta.powi(2) + cos_xi.powi(2)).sqrt();
+        let longitude = sinh_eta.atan2(cos_xi);
+
+        let e = self.ellipsoid.eccentricity_squared().sqrt();
+        let tau = geodetic_tau(tau_prime, e);
+
+        Coordinate::new(
+            tau.atan().to_degrees(),
+            self.central_meridian + longitude.to_degrees(),
+        )
+    }
+}
+
+impl TransverseMercator {
+    /// Returns the unscaled plane coordinates (x, y) relative to the central meridian and equator
+    fn plane_coordinates(&self, latitude: Float, longitude: Float) -> (Float, Float) {
+        let e = self.ellipsoid.eccentricity_squared().sqrt();
+        let (sin_phi, cos_phi) = latitude.to_radians().sin_cos();
+        let lambda = (longitude - self.central_meridian).to_radians();
+        let (sin_lambda, cos_lambda) = lambda.sin_cos();
+
+        let tau_prime = conformal_tau(sin_phi / cos_phi, e);
+        let xi_prime = tau_prime.atan2(cos_lambda);
+        let eta_prime = (sin_lambda / (tau_prime.powi(2) + cos_lambda.powi(2)).sqrt()).asinh();
+
+        let alpha = forward_coefficients(self.third_flattening());
+        let mut xi = xi_prime;
+        let mut eta = eta_prime;
+        for (j, a) in alpha.iter().enumerate() {
+            let k = 2.0 * (j + 1) as Float;
+            xi += a * (k * xi_prime).sin() * (k * eta_prime).cosh();
+            eta += a * (k * xi_prime).cos() * (k * eta_prime).sinh();
+        }
+
+        let scale = self.scale_factor * self.rectifying_radius();
+        (scale * eta, scale * xi)
+    }
+
+    fn third_flattening(&self) -> Float {
+        let f = self.ellipsoid.flattening;
+        f / (2.0 - f)
+    }
+
+    /// The radius of the sphere with the same meridian length as the ellipsoid
+    fn rectifying_radius(&self) -> Float {
+        let n = self.third_flattening();
+        let n2 = n * n;
+        self.ellipsoid.semi_major_axis / (1.0 + n) * (1.0 + n2 / 4.0 + n2 * n2 / 64.0)
+    }
+}
+
+/// Returns the tangent of the conformal latitude for the tangent of the geodetic latitude
+pub(super) fn conformal_tau(tau: Float, eccentricity: Float) -> Float {
+    let sigma = (eccentricity * (eccentricity * tau / (1.0 + tau * tau).sqrt()).atanh()).sinh();
+    tau * (1.0 + sigma * sigma).sqrt() - sigma * (1.0 + tau * tau).sqrt()
+}
+
+/// Returns the tangent of the geodetic latitude for the tangent of the conformal latitude, solved with
+/// Newton's method
+pub(super) fn geodetic_tau(tau_prime: Float, eccentricity: Float) -> Float {
+    let e_sq = eccentricity * eccentricity;
+    let mut tau = tau_prime;
+    for _ in 0..10 {
+        let tau_i = conformal_tau(tau, eccentricity);
+        let delta = (tau_prime - tau_i) / (1.0 + tau_i.powi(2)).sqrt()
+            * (1.0 + (1.0 - e_sq) * tau.powi(2))
+            / ((1.0 - e_sq) * (1.0 + tau.powi(2)).sqrt());
+        tau += delta;
+        if delta.abs() <= Float::EPSILON * 4.0 {
+            break;
+        }
+    }
+    tau
+}
+
+fn forward_coefficients(n: Float) -> [Float; 6] {
+    let n2 = n * n;
+    let n3 = n2 * n;
+    let n4 = n3 * n;
+    let n5 = n4 * n;
+    let n6 = n5 * n;
+    [
+        n / 2.0 - 2.0 * n2 / 3.0 + 5.0 * n3 / 16.0 + 41.0 * n4 / 180.0 - 127.0 * n5 / 288.0
+            + 7891.0 * n6 / 37800.0,
+        13.0 * n2 / 48.0 - 3.0 * n3 / 5.0 + 557.0 * n4 / 1440.0 + 281.0 * n5 / 630.0
+            - 1983433.0 * n6 / 1935360.0,
+        61.0 * n3 / 240.0 - 103.0 * n4 / 140.0 + 15061.0 * n5 / 26880.0 + 167603.0 * n6 / 181440.0,
+        49561.0 * n4 / 161280.0 - 179.0 * n5 / 168.0 + 6601661.0 * n6 / 7257600.0,
+        34729.0 * n5 / 80640.0 - 3418889.0 * n6 / 1995840.0,
+        212378941.0 * n6 / 319334400.0,
+    ]
+}
+
+fn inverse_coefficients(n: Float) -> [Float; 6] {
+    let n2 = n * n;
+    let n3 = n2 * n;
+    let n4 = n3 * n;
+    let n5 = n4 * n;
+    let n6 = n5 * n;
+    [
+        n / 2.0 - 2.0 * n2 / 3.0 + 37.0 * n3 / 96.0 - n4 / 360.0 - 81.0 * n5 / 512.0
+            + 96199.0 * n6 / 604800.0,
+        n2 / 48.0 + n3 / 15.0 - 437.0 * n4 / 1440.0 + 46.0 * n5 / 105.0
+            - 1118711.0 * n6 / 3870720.0,
+        17.0 * n3 / 480.0 - 37.0 * n4 / 840.0 - 209.0 * n5 / 4480.0 + 5569.0 * n6 / 90720.0,
+        4397.0 * n4 / 161280.0 - 11.0 * n5 / 504.0 - 830251.0 * n6 / 7257600.0,
+        4583.0 * n5 / 161280.0 - 108847.0 * n6 / 3991680.0,
+        20648693.0 * n6 / 638668800.0,
+    ]
+}
+
+#[cfg(test)]
+#[allow(clippy::excessive_precision)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::assert_approx_eq;
+
+    fn national_grid() -> TransverseMercator {
+        TransverseMercator {
+            ellipsoid: Ellipsoid::AIRY_1830,
+            central_meridian: -2.0,
+            latitude_of_origin: 49.0,
+            scale_factor: 0.9996012717,
+            false_easting: 400000.0,
+            false_northing: -100000.0,
+        }
+    }
+
+    fn utm_zone_32() -> TransverseMercator {
+        TransverseMercator {
+            ellipsoid: Ellipsoid::WGS84,
+            central_meridian: 9.0,
+            latitude_of_origin: 0.0,
+            scale_factor: 0.9996,
+            false_easting: 500000.0,
+            false_northing: 0.0,
+        }
+    }
+
+    #[test]
+    fn test_project_national_grid() {
+        // Ordnance Survey worked example (OSGB36 datum)
+        let coordinate = Coordinate::new(52.657570301, 1.717921528);
+        let point = national_grid().project(&coordinate);
+        assert_approx_eq!(point.x, 651409.903, 1.0);
+        assert_approx_eq!(point.y, 313177.270, 1.0);
+    }
+
+    #[test]
+    fn test_unproject_national_grid() {
+        let coordinate = national_grid().unproject(&Point2D::new(651409.903, 313177.270));
+        assert_approx_eq!(coordinate.latitude, 52.657570301, 1e-5);
+        assert_approx_eq!(coordinate.longitude, 1.717921528, 1e-5);
+    }
+
+    #[rstest]
+    #[case(51.2, 7.5, 395201.31, 5673135.24)]
+    #[case(0.0, 9.0, 500000.0, 0.0)]
+    #[case(-10.0, 12.0, 828928.74, -1106908.85)]
+    fn test_project_utm(
+        #[case] latitude: Float,
+        #[case] longitude: Float,
+        #[case] easting: Float,
+        #[case] northing: Float,
+    ) {
+        let projection = utm_zone_32();
+        let point = projection.project(&Coordinate::new(latitude, longitude));
+        assert_approx_eq!(point.x, easting, 1.0);
+        assert_approx_eq!(point.y, northing, 1.0);
+
+        let coordinate = projection.unproject(&Point2D::new(easting, northing));
+        assert_approx_eq!(coordinate.latitude, latitude, 1e-5);
+        assert_approx_eq!(coordinate.longitude, longitude, 1e-5);
+    }
+}
@@ -0,0 +1,598 @@
+use std::str::FromStr;
+
+use crate::{
+    math::{Float, geometry::Point2D},
+    units::{coordinate::Coordinate, coordinate_format::ParseCoordinateError},
+};
+
+use super::{
+    ellipsoid::Ellipsoid, projection::Projection, transverse_mercator::TransverseMercator,
+};
+
+/// The latitude bands from 80° S to 84° N. Each is 8° tall except for X, which is 12°.
+const BANDS: &str = "CDEFGHJKLMNPQRSTUVWX";
+const MIN_LATITUDE: Float = -80.0;
+const MAX_LATITUDE: Float = 84.0;
+
+/// The column letters of the 100 km squares, which repeat every three zones
+const SQUARE_COLUMNS: [&str; 3] = ["ABCDEFGH", "JKLMNPQR", "STUVWXYZ"];
+/// The row letters of the 100 km squares, which repeat every 2,000 km
+const SQUARE_ROWS: &str = "ABCDEFGHJKLMNPQRSTUV";
+
+/// A location in the Universal Transverse Mercator grid on WGS84
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct UtmCoordinate {
+    /// The longitude zone, from 1 to 60
+    pub zone: u8,
+    /// The latitude band letter, from C to X (excluding I and O). N and above are in the northern hemisphere.
+    pub band: char,
+    /// Meters east, where the zone's central meridian is 500,000 m
+    pub easting: Float,
+    /// Meters north of the equator, or of 10,000 km south of the equator in the southern hemisphere
+    pub northing: Float,
+}
+
+impl UtmCoordinate {
+    /// Converts the coordinate to UTM, including the Norway and Svalbard zone exceptions. Returns None
+    /// outside of the UTM latitudes (80° S to 84° N).
+    pub fn from_coordinate(coordinate: &Coordinate) -> Option<Self> {
+        let mut band = band_for(coordinate.latitude)?;
+        let zone = zone_for(coordinate);
+        let mut point = utm_projection(zone, is_northern(band)).project(coordinate);
+
+        // Latitudes just south of the equator can round onto it, so pick the hemisphere from the northing
+        if point.y < 0.0 && is_northern(band) {
+            band = 'M';
+            point = utm_projection(zone, false).project(coordinate);
+        }
+
+        Some(UtmCoordinate {
+            zone,
+            band,
+            easting: point.x,
+            northing: point.y,
+        })
+    }
+
+    pub fn to_coordinate(&self) -> Coordinate {
+        utm_projection(self.zone, self.is_northern())
+            .unproject(&Point2D::new(self.easting, self.northing))
+    }
+
+    pub fn is_northern(&self) -> bool {
+        is_northern(self.band)
+    }
+}
+
+impl std::fmt::Display for UtmCoordinate {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}{} {} {}",
+            self.zone,
+            self.band,
+            self.easting.floor(),
+            self.northing.floor()
+        )
+    }
+}
+
+impl FromStr for UtmCoordinate {
+    type Err = ParseCoordinateError;
+
+    /// Parses a UTM coordinate (ex. "17T 589090 4477606" or "17 T 589090mE 4477606mN")
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let malformed = || ParseCoordinateError::Malformed(s.to_string());
+        let text = s.trim().to_uppercase();
+        let (zone, band, rest) = read_zone_and_band(&text, s)?;
+
+        let numbers: Vec<&str> = rest
+            .split(|c: char| c.is_whitespace() || c == ',')
+            .filter(|part| !part.is_empty())
+            .map(|part| {
+                part.trim_end_matches(['E', 'N'])
+                    .trim_end_matches('M')
+                    .trim()
+            })
+            .collect();
+        let [easting, northing] = numbers.as_slice() else {
+            return Err(malformed());
+        };
+        let easting: Float = easting.parse().map_err(|_| malformed())?;
+        let northing: Float = northing.parse().map_err(|_| malformed())?;
+
+        let utm = UtmCoordinate {
+            zone,
+            band,
+            easting,
+            northing,
+        };
+        validate(&utm, s)?;
+        Ok(utm)
+    }
+}
+
+/// A location in the Military Grid Reference System, which names the 100 km squares of the UTM grid
+/// with letters. The US National Grid (USNG) is the same system written with spaces.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MgrsCoordinate {
+    pub zone: u8,
+    pub band: char,
+    /// The column letter of the 100 km square
+    pub column: char,
+    /// The row letter of the 100 km square
+    pub row: char,
+    /// Meters east within the 100 km square
+    pub easting: Float,
+    /// Meters north within the 100 km square
+    pub northing: Float,
+}
+
+impl MgrsCoordinate {
+    /// Converts the coordinate to MGRS. Returns None outside of the UTM latitudes (80° S to 84° N).
+    pub fn from_coordinate(coordinate: &Coordinate) -> Option<Self> {
+        UtmCoordinate::from_coordinate(coordinate).map(|utm| MgrsCoordinate::from_utm(&utm))
+    }
+
+    pub fn from_utm(utm: &UtmCoordinate) -> Self {
+        let column_index = (utm.easting / 100000.0).floor() as usize;
+        let row_index = (utm.northing / 100000.0).floor() as usize;
+        let columns = square_columns(utm.zone);
+        let row_offset = if utm.zone.is_multiple_of(2) { 5 } else { 0 };
+        MgrsCoordinate {
+            zone: utm.zone,
+            band: utm.band,
+            column: letter_at(columns, column_index.clamp(1, 8) - 1),
+            row: letter_at(SQUARE_ROWS, (row_index + row_offset) % 20),
+            easting: utm.easting % 100000.0,
+            northing: utm.northing % 100000.0,
+        }
+    }
+
+    /// Returns the UTM coordinate of the location. Returns None if the zone is invalid or the square
+    /// letters don't exist in the zone.
+    pub fn to_utm(&self) -> Option<UtmCoordinate> {
+        if !(1..=60).contains(&self.zone) {
+            return None;
+        }
+        let columns = square_columns(self.zone);
+        let column_index = columns.find(self.column)? + 1;
+        let row_offset = if self.zone.is_multiple_of(2) { 5 } else { 0 };
+        let row_index = (SQUARE_ROWS.find(self.row)? + 20 - row_offset) % 20;
+
+        // The row letters repeat every 2,000 km, so use the bottom of the latitude band to pick the cycle.
+        // The band is at most 1,332 km tall, which leaves room for the grid lines curving across the zone.
+        let band_latitude = band_bottom(self.band)?;
+        let central_meridian = Coordinate::new(band_latitude, central_meridian(self.zone));
+        let band_northing = utm_projection(self.zone, is_northern(self.band))
+            .project(&central_meridian)
+            .y;
+        let minimum_northing = (band_northing / 100000.0).floor() * 100000.0 - 100000.0;
+
+        let mut northing = row_index as Float * 100000.0 + self.northing;
+        while northing < minimum_northing {
+            northing += 2000000.0;
+        }
+
+        Some(UtmCoordinate {
+            zone: self.zone,
+            band: self.band,
+            easting: column_index as Float * 100000.0 + self.easting,
+            northing,
+        })
+    }
+
+    /// Returns the location. Returns None if the square letters don't exist in the zone.
+    pub fn to_coordinate(&self) -> Option<Coordinate> {
+        self.to_utm().map(|utm| utm.to_coordinate())
+    }
+
+    /// Formats the location without spaces (ex. "18SUJ2348706483"). The digits are the number of digits
+    /// for each of the easting and northing, from 0 (100 km) to 5 (1 m). Positions are truncated, so they
+    /// refer to the south west corner of the square.
+    pub fn format(&self, digits: usize) -> String {
+        let (easting, northing) = self.digits(digits);
+        format!(
+            "{}{}{}{}{}{}",
+            self.zone, self.band, self.column, self.row, easting, northing
+        )
+    }
+
+    /// Formats the location in the USNG style, with spaces (ex. "18S UJ 23487 06483")
+    pub fn format_usng(&self, digits: usize) -> String {
+        let (easting, northing) = self.digits(digits);
+        let square = format!("{}{} {}{}", self.zone, self.band, self.column, self.row);
+        if digits == 0 {
+            square
+        } else {
+            format!("{} {} {}", square, easting, northing)
+        }
+    }
+
+    fn digits(&self, digits: usize) -> (String, String) {
+        let digits = digits.min(5);
+        if digits == 0 {
+            return (String::new(), String::new());
+        }
+        let step = (10.0 as Float).powi(5 - digits as i32);
+        let format =
+            |meters: Float| format!("{:0width$}", (meters / step).floor() as u64, width = digits);
+        (format(self.easting), format(self.northing))
+    }
+}
+
+impl std::fmt::Display for MgrsCoordinate {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.format(5))
+    }
+}
+
+impl FromStr for MgrsCoordinate {
+    type Err = ParseCoordinateError;
+
+    /// Parses an MGRS or USNG location (ex. "18SUJ2348706483" or "18S UJ 234 064"). Spaces are
+    /// ignored and the easting and northing can have 0 to 5 digits each.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let malformed = || ParseCoordinateError::Malformed(s.to_string());
+        let text: String = s
+            .chars()
+            .filter(|c| !c.is_whitespace())
+            .collect::<String>()
+            .to_uppercase();
+        let (zone, band, rest) = read_zone_and_band(&text, s)?;
+
+        let mut chars = rest.chars();
+        let (Some(column), Some(row)) = (chars.next(), chars.next()) else {
+            return Err(malformed());
+        };
+        if !column.is_ascii_alphabetic() || !row.is_ascii_alphabetic() {
+            return Err(malformed());
+        }
+
+        let digits = chars.as_str();
+        if digits.len() > 10
+            || !digits.len().is_multiple_of(2)
+            || !digits.chars().all(|c| c.is_ascii_digit())
+        {
+            return Err(malformed());
+        }
+        let half = digits.len() / 2;
+        let scale = (10.0 as Float).powi(5 - half as i32);
+        let read = |text: &str| -> Float {
+            if text.is_empty() {
+                0.0
+            } else {
+                text.parse::<u32>().unwrap_or(0) as Float * scale
+            }
+        };
+
+        let mgrs = MgrsCoordinate {
+            zone,
+            band,
+            column,
+            row,
+            easting: read(&digits[..half]),
+            northing: read(&digits[half..]),
+        };
+        let utm = mgrs
+            .to_utm()
+            .ok_or_else(|| ParseCoordinateError::InvalidGridSquare(s.to_string()))?;
+        validate(&utm, s)?;
+        Ok(mgrs)
+    }
+}
+
+/// Returns the transverse Mercator projection of the UTM zone (1 to 60) in the northern or southern
+/// hemisphere
+pub fn utm_projection(zone: u8, is_northern: bool) -> TransverseMercator {
+    TransverseMercator {
+        ellipsoid: Ellipsoid::WGS84,
+        central_meridian: central_meridian(zone),
+        latitude_of_origin: 0.0,
+        scale_factor: 0.9996,
+        false_easting: 500000.0,
+        false_northing: if is_northern { 0.0 } else { 10000000.0 },
+    }
+}
+
+fn central_meridian(zone: u8) -> Float {
+    zone as Float * 6.0 - 183.0
+}
+
+fn zone_for(coordinate: &Coordinate) -> u8 {
+    let latitude = coordinate.latitude;
+    let longitude = coordinate.longitude;
+
+    // Norway
+    if (56.0..64.0).contains(&latitude) && (3.0..12.0).contains(&longitude) {
+        return 32;
+    }
+
+    // Svalbard
+    if latitude >= 72.0 && (0.0..42.0).contains(&longitude) {
+        return match longitude {
+            l if l < 9.0 => 31,
+            l if l < 21.0 => 33,
+            l if l < 33.0 => 35,
+            _ => 37,
+        };
+    }
+
+    (((longitude + 180.0) / 6.0).floor() as i32 + 1).clamp(1, 60) as u8
+}
+
+fn band_for(latitude: Float) -> Option<char> {
+    if !(MIN_LATITUDE..=MAX_LATITUDE).contains(&latitude) {
+        return None;
+    }
+    let index = ((latitude - MIN_LATITUDE) / 8.0).floor() as usize;
+    Some(letter_at(BANDS, index.min(BANDS.len() - 1)))
+}
+
+/// Returns the southern latitude of the band in degrees
+fn band_bottom(band: char) -> Option<Float> {
+    BANDS
+        .find(band)
+        .map(|index| MIN_LATITUDE + index as Float * 8.0)
+}
+
+fn is_northern(band: char) -> bool {
+    band >= 'N'
+}
+
+/// Returns the column letters of the zone
+fn square_columns(zone: u8) -> &'static str {
+    SQUARE_COLUMNS[(zone as usize + 2) % 3]
+}
+
+fn letter_at(letters: &str, index: usize) -> char {
+    letters.as_bytes()[index] as char
+}
+
+/// Reads the zone number and band letter from the start of the uppercase text
+fn read_zone_and_band<'a>(
+    text: &'a str,
+    original: &str,
+) -> Result<(u8, char, &'a str), ParseCoordinateError> {
+    let digits = text.chars().take_while(|c| c.is_ascii_digit()).count();
+    if digits == 0 {
+        return Err(ParseCoordinateError::Malformed(original.to_string()));
+    }
+    let zone = text[..digits]
+        .parse::<u8>()
+        .ok()
+        .filter(|zone| (1..=60).contains(zone))
+        .ok_or_else(|| ParseCoordinateError::InvalidZone(original.to_string()))?;
+
+    let rest = text[digits..].trim_start();
+    let band = rest
+        .chars()
+        .next()
+        .ok_or_else(|| ParseCoordinateError::Malformed(original.to_string()))?;
+    if !BANDS.contains(band) {
+        return Err(ParseCoordinateError::InvalidBand(original.to_string()));
+    }
+    Ok((zone, band, &rest[band.len_utf8()..]))
+}
+
+/// Checks that the location is on the grid and within its latitude band
+fn validate(utm: &UtmCoordinate, original: &str) -> Result<(), ParseCoordinateError> {
+    let is_on_grid =
+        (100000.0..=900000.0).contains(&utm.easting) && (0.0..=10000000.0).contains(&utm.northing);
+    if !is_on_grid {
+        return Err(ParseCoordinateError::OutsideGrid(original.to_string()));
+    }
+
+    // The widest zones (Norway and Svalbard) reach 6° from their central meridian. Near the poles an
+    // easting can be far outside of the zone, so check the longitude as well.
+    let coordinate = utm.to_coordinate();
+    let longitude = coordinate.longitude;
+    let is_in_zone = (-180.001..=180.001).contains(&longitude)
+        && (longitude - central_meridian(utm.zone)).abs() <= 6.5;
+    if !is_in_zone {
+        return Err(ParseCoordinateError::OutsideGrid(original.to_string()));
+    }
+
+    // Allow a little room for positions rounded onto the edge of the band
+    let latitude = coordinate.latitude;
+    let bottom = band_bottom(utm.band).unwrap_or(MIN_LATITUDE);
+    let top = if utm.band == 'X' {
+        MAX_LATITUDE
+    } else {
+        bottom + 8.0
+    };
+    if latitude < bottom - 0.5 || latitude > top + 0.5 {
+        return Err(ParseCoordinateError::InvalidBand(original.to_string()));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+#[allow(clippy::excessive_precision)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::assert_approx_eq;
+
+    #[rstest]
+    #[case(51.2, 7.5, 32, 'U', 395201.31, 5673135.24)]
+    #[case(38.88949, -77.03523, 18, 'S', 323484.11, 4306482.0)]
+    #[case(-10.0, 12.0, 33, 'L', 171071.26, 8893091.15)]
+    #[case(0.0, -180.0, 1, 'N', 166021.44, 0.0)]
+    #[case(0.0, 180.0, 60, 'N', 833978.56, 0.0)]
+    #[case(60.0, 5.0, 32, 'V', 276979.93, 6658157.20)]
+    #[case(78.0, 10.0, 33, 'X', 384085.48, 8663320.20)]
+    fn test_from_coordinate(
+        #[case] latitude: Float,
+        #[case] longitude: Float,
+        #[case] zone: u8,
+        #[case] band: char,
+        #[case] easting: Float,
+        #[case] northing: Float,
+    ) {
+        let utm = UtmCoordinate::from_coordinate(&Coordinate::new(latitude, longitude)).unwrap();
+        assert_eq!(utm.zone, zone);
+        assert_eq!(utm.band, band);
+        assert_approx_eq!(utm.easting, easting, 1.0);
+        assert_approx_eq!(utm.northing, northing, 1.0);
+
+        let coordinate = utm.to_coordinate();
+        assert_approx_eq!(coordinate.latitude, latitude, 1e-4);
+        assert_approx_eq!(coordinate.longitude, longitude, 1e-4);
+    }
+
+    #[test]
+    fn test_from_coordinate_just_south_of_equator() {
+        let utm = UtmCoordinate::from_coordinate(&Coordinate::new(-1e-8, -1e-7)).unwrap();
+        assert_eq!(utm.band, 'M');
+        assert!(utm.northing > 9999999.0);
+        assert!(utm.to_string().parse::<UtmCoordinate>().is_ok());
+    }
+
+    #[rstest]
+    #[case(84.5)]
+    #[case(-80.5)]
+    fn test_from_coordinate_outside_utm(#[case] latitude: Float) {
+        assert_eq!(
+            UtmCoordinate::from_coordinate(&Coordinate::new(latitude, 0.0)),
+            None
+        );
+    }
+
+    #[rstest]
+    #[case("17T 630084 4833439", 17, 'T', 630084.0, 4833439.0)]
+    #[case("17t 630084mE 4833439mN", 17, 'T', 630084.0, 4833439.0)]
+    #[case(" 32 U 395201, 5673135 ", 32, 'U', 395201.0, 5673135.0)]
+    #[case("56H 334786.6 6252080.5", 56, 'H', 334786.6, 6252080.5)]
+    fn test_parse_utm(
+        #[case] text: &str,
+        #[case] zone: u8,
+        #[case] band: char,
+        #[case] easting: Float,
+        #[case] northing: Float,
+    ) {
+        let utm: UtmCoordinate = text.parse().unwrap();
+        assert_eq!(utm.zone, zone);
+        assert_eq!(utm.band, band);
+        assert_approx_eq!(utm.easting, easting, 0.01);
+        assert_approx_eq!(utm.northing, northing, 0.5);
+    }
+
+    #[rstest]
+    #[case("17T 630084", ParseCoordinateError::Malformed("17T 630084".to_string()))]
+    #[case("T 630084 4833439", ParseCoordinateError::Malformed("T 630084 4833439".to_string()))]
+    #[case("61T 630084 4833439", ParseCoordinateError::InvalidZone("61T 630084 4833439".to_string()))]
+    #[case("17I 630084 4833439", ParseCoordinateError::InvalidBand("17I 630084 4833439".to_string()))]
+    #[case("17C 630084 4833439", ParseCoordinateError::InvalidBand("17C 630084 4833439".to_string()))]
+    #[case("17T 1630084 4833439", ParseCoordinateError::OutsideGrid("17T 1630084 4833439".to_string()))]
+    #[case("1N 2E 3", ParseCoordinateError::OutsideGrid("1N 2E 3".to_string()))]
+    #[case("17T 950000 4833439", ParseCoordinateError::OutsideGrid("17T 950000 4833439".to_string()))]
+    #[case("31X 880000 9300000", ParseCoordinateError::OutsideGrid("31X 880000 9300000".to_string()))]
+    #[case("17T 630084 abc", ParseCoordinateError::Malformed("17T 630084 abc".to_string()))]
+    fn test_parse_utm_errors(#[case] text: &str, #[case] expected: ParseCoordinateError) {
+        assert_eq!(text.parse::<UtmCoordinate>(), Err(expected));
+    }
+
+    #[test]
+    fn test_format_utm() {
+        let utm = UtmCoordinate {
+            zone: 17,
+            band: 'T',
+            easting: 630084.9,
+            northing: 4833439.2,
+        };
+        assert_eq!(utm.to_string(), "17T 630084 4833439");
+    }
+
+    #[rstest]
+    #[case(38.88949, -77.03523, "18SUJ23480648", "18S UJ 2348 0648")]
+    #[case(51.2, 7.5, "32ULB95207313", "32U LB 9520 7313")]
+    #[case(-33.8568, 151.2153, "56HLH34905228", "56H LH 3490 5228")]
+    #[case(-10.0, 12.0, "33LSJ71079309", "33L SJ 7107 9309")]
+    #[case(0.0, 180.0, "60NZF33970000", "60N ZF 3397 0000")]
+    #[case(21.4097, -157.9161, "4QFJ12346787", "4Q FJ 1234 6787")]
+    fn test_mgrs_from_coordinate(
+        #[case] latitude: Float,
+        #[case] longitude: Float,
+        #[case] mgrs: &str,
+        #[case] usng: &str,
+    ) {
+        let coordinate =
+            MgrsCoordinate::from_coordinate(&Coordinate::new(latitude, longitude)).unwrap();
+        assert_eq!(coordinate.format(4), mgrs);
+        assert_eq!(coordinate.format_usng(4), usng);
+    }
+
+    #[rstest]
+    #[case("18SUJ2348406481", 38.88949, -77.03523)]
+    #[case("18S UJ 23484 06481", 38.88949, -77.03523)]
+    #[case("18suj2348406481", 38.88949, -77.03523)]
+    #[case("32ULB9520173135", 51.2, 7.5)]
+    #[case("56HLH3490052288", -33.8568, 151.2153)]
+    #[case("33LSJ7107193091", -10.0, 12.0)]
+    #[case("4QFJ1234367879", 21.4097, -157.9161)]
+    fn test_parse_mgrs(#[case] text: &str, #[case] latitude: Float, #[case] longitude: Float) {
+        let coordinate = text
+            .parse::<MgrsCoordinate>()
+            .unwrap()
+            .to_coordinate()
+            .unwrap();
+        assert_approx_eq!(coordinate.latitude, latitude, 1e-4);
+        assert_approx_eq!(coordinate.longitude, longitude, 1e-4);
+    }
+
+    #[rstest]
+    #[case("4QFJ123456", 21.20844, -157.91799)]
+    #[case("4QFJ", 20.79719, -158.03913)]
+    fn test_parse_mgrs_precision(
+        #[case] text: &str,
+        #[case] latitude: Float,
+        #[case] longitude: Float,
+    ) {
+        let coordinate = text
+            .parse::<MgrsCoordinate>()
+            .unwrap()
+            .to_coordinate()
+            .unwrap();
+        assert_approx_eq!(coordinate.latitude, latitude, 1e-4);
+        assert_approx_eq!(coordinate.longitude, longitude, 1e-4);
+    }
+
+    #[rstest]
+    #[case("18SUJ234064", "18SUJ234064")]
+    #[case("18S UJ 2348 0648", "18SUJ23480648")]
+    fn test_format_mgrs_round_trip(#[case] text: &str, #[case] expected: &str) {
+        let mgrs: MgrsCoordinate = text.parse().unwrap();
+        let digits = (expected.len() - 5) / 2;
+        assert_eq!(mgrs.format(digits), expected);
+    }
+
+    #[rstest]
+    #[case("18SUJ23480648X", ParseCoordinateError::Malformed("18SUJ23480648X".to_string()))]
+    #[case("18SUJ2348064", ParseCoordinateError::Malformed("18SUJ2348064".to_string()))]
+    #[case("18SU", ParseCoordinateError::Malformed("18SU".to_string()))]
+    #[case("18SIJ2348706483", ParseCoordinateError::InvalidGridSquare("18SIJ2348706483".to_string()))]
+    #[case("18SAJ2348706483", ParseCoordinateError::InvalidGridSquare("18SAJ2348706483".to_string()))]
+    #[case("0SUJ2348706483", ParseCoordinateError::InvalidZone("0SUJ2348706483".to_string()))]
+    #[case("18OUJ2348706483", ParseCoordinateError::InvalidBand("18OUJ2348706483".to_string()))]
+    fn test_parse_mgrs_errors(#[case] text: &str, #[case] expected: ParseCoordinateError) {
+        assert_eq!(text.parse::<MgrsCoordinate>(), Err(expected));
+    }
+
+    #[rstest]
+    #[case(0)]
+    #[case(61)]
+    fn test_mgrs_invalid_zone(#[case] zone: u8) {
+        let utm = UtmCoordinate {
+            zone,
+            band: 'T',
+            easting: 630084.0,
+            northing: 4833439.0,
+        };
+        let mgrs = MgrsCoordinate::from_utm(&utm);
+        assert_eq!(mgrs.to_utm(), None);
+    }
+}
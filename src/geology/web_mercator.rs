@@ -0,0 +1,296 @@
+use crate::{
+    math::{Float, consts::PI, geometry::Point2D},
+    units::coordinate::Coordinate,
+};
+
+use super::{bounds::CoordinateBounds, geodesy::normalize_longitude, projection::Projection};
+
+/// The radius of the sphere used by Web Mercator, which is the WGS84 semi-major axis
+const RADIUS: Float = 6378137.0;
+
+/// The latitude where Web Mercator becomes square, which is the limit of tiled maps
+#[allow(clippy::excessive_precision)]
+pub const MAX_LATITUDE: Float = 85.051_128_78;
+
+/// The width and height of a standard map tile in pixels
+pub const DEFAULT_TILE_SIZE: u32 = 256;
+
+/// The spherical Mercator projection used by web maps (EPSG:3857). Latitudes are clamped to
+/// `MAX_LATITUDE`.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct WebMercator;
+
+impl Projection for WebMercator {
+    fn project(&self, coordinate: &Coordinate) -> Point2D {
+        let latitude = coordinate.latitude.clamp(-MAX_LATITUDE, MAX_LATITUDE);
+        Point2D::new(
+            RADIUS * normalize_longitude(coordinate.longitude).to_radians(),
+            RADIUS * latitude.to_radians().tan().asinh(),
+        )
+    }
+
+    fn unproject(&self, point: &Point2D) -> Coordinate {
+        Coordinate::new(
+            (point.y / RADIUS).sinh().atan().to_degrees(),
+            normalize_longitude((point.x / RADIUS).to_degrees()),
+        )
+    }
+}
+
+/// A slippy map tile. At each zoom level the world is split into 2^zoom by 2^zoom tiles, starting at
+/// the north west corner.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Tile {
+    pub x: u32,
+    pub y: u32,
+    pub zoom: u8,
+}
+
+impl Tile {
+    pub fn new(x: u32, y: u32, zoom: u8) -> Self {
+        Tile { x, y, zoom }
+    }
+
+    /// Returns the tile containing the coordinate at the zoom level (0 to 31)
+    pub fn from_coordinate(coordinate: &Coordinate, zoom: u8) -> Self {
+        let zoom = zoom.min(31);
+        let (x, y) = world_fraction(coordinate);
+        let count = tile_count(zoom);
+        let max_index = (count - 1.0) as u32;
+        Tile {
+            x: ((x * count).floor() as u32).min(max_index),
+            y: ((y * count).floor() as u32).min(max_index),
+            zoom,
+        }
+    }
+
+    /// Returns the area covered by the tile
+    pub fn bounds(&self) -> CoordinateBounds {
+        let count = tile_count(self.zoom);
+        let north_west = from_world_fraction(self.x as Float / count, self.y as Float / count);
+        let south_east =
+            from_world_fraction((self.x + 1) as Float / count, (self.y + 1) as Float / count);
+        CoordinateBounds::new(
+            north_west.latitude,
+            south_east.longitude,
+            south_east.latitude,
+            north_west.longitude,
+        )
+    }
+
+    /// Returns the Bing Maps quadkey of the tile, which has one digit per zoom level
+    pub fn quadkey(&self) -> String {
+        (1..=self.zoom)
+            .rev()
+            .map(|level| {
+                let mask = 1 << (level - 1);
+                let mut digit = 0;
+                if self.x & mask != 0 {
+                    digit += 1;
+                }
+                if self.y & mask != 0 {
+                    digit += 2;
+                }
+                char::from(b'0' + digit)
+            })
+            .collect()
+    }
+
+    /// Returns the tile of a Bing Maps quadkey
+    pub fn from_quadkey(quadkey: &str) -> Result<Self, InvalidQuadkey> {
+        let quadkey = quadkey.trim();
+        if quadkey.len() > 31 {
+            return Err(InvalidQuadkey(quadkey.to_string()));
+        }
+        let mut tile = Tile::new(0, 0, quadkey.len() as u8);
+        for c in quadkey.chars() {
+            let digit = c
+                .to_digit(4)
+                .ok_or_else(|| InvalidQuadkey(quadkey.to_string()))?;
+            tile.x = (tile.x << 1) | (digit & 1);
+            tile.y = (tile.y << 1) | (digit >> 1);
+        }
+        Ok(tile)
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct InvalidQuadkey(pub String);
+
+impl std::fmt::Display for InvalidQuadkey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "invalid quadkey: '{}'", self.0)
+    }
+}
+
+impl std::error::Error for InvalidQuadkey {}
+
+/// Returns the pixel of the coordinate on the whole map at the zoom level, where the map is
+/// tile_size * 2^zoom pixels wide. The origin is the north west corner.
+pub fn coordinate_to_pixel(coordinate: &Coordinate, zoom: Float, tile_size: u32) -> Point2D {
+    let size = map_size(zoom, tile_size);
+    let (x, y) = world_fraction(coordinate);
+    Point2D::new(x * size, y * size)
+}
+
+/// Returns the coordinate at a pixel on the whole map at the zoom level
+pub fn pixel_to_coordinate(pixel: &Point2D, zoom: Float, tile_size: u32) -> Coordinate {
+    let size = map_size(zoom, tile_size);
+    from_world_fraction(pixel.x / size, pixel.y / size)
+}
+
+/// Returns the meters on the ground covered by a pixel at the latitude and zoom level
+pub fn ground_resolution(latitude: Float, zoom: Float, tile_size: u32) -> Float {
+    let latitude = latitude.clamp(-MAX_LATITUDE, MAX_LATITUDE);
+    latitude.to_radians().cos() * 2.0 * PI * RADIUS / map_size(zoom, tile_size)
+}
+
+fn tile_count(zoom: u8) -> Float {
+    (1u64 << zoom) as Float
+}
+
+fn map_size(zoom: Float, tile_size: u32) -> Float {
+    tile_size as Float * zoom.exp2()
+}
+
+/// Returns the position of the coordinate on the map from 0 to 1, from the north west corner
+fn world_fraction(coordinate: &Coordinate) -> (Float, Float) {
+    let point = WebMercator.project(coordinate);
+    let half_width = PI * RADIUS;
+    (
+        (point.x / half_width + 1.0) / 2.0,
+        (1.0 - point.y / half_width) / 2.0,
+    )
+}
+
+fn from_world_fraction(x: Float, y: Float) -> Coordinate {
+    let half_width = PI * RADIUS;
+    let point = Point2D::new((2.0 * x - 1.0) * half_width, (1.0 - 2.0 * y) * half_width);
+    let coordinate = WebMercator.unproject(&point);
+    // Keep the east edge of the map at 180° instead of wrapping it
+    let longitude = if x >= 1.0 {
+        180.0
+    } else {
+        coordinate.longitude
+    };
+    Coordinate::new(coordinate.latitude, longitude)
+}
+
+#[cfg(test)]
+#[allow(clippy::excessive_precision)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::assert_approx_eq;
+
+    #[rstest]
+    #[case(0.0, 0.0, 0.0, 0.0)]
+    #[case(45.0, 45.0, 5009377.086, 5621521.486)]
+    #[case(85.0511287798, 180.0, 20037508.343, 20037508.343)]
+    #[case(89.0, -180.0, -20037508.343, 20037508.343)]
+    fn test_project(
+        #[case] latitude: Float,
+        #[case] longitude: Float,
+        #[case] x: Float,
+        #[case] y: Float,
+    ) {
+        let point = WebMercator.project(&Coordinate::new(latitude, longitude));
+        assert_approx_eq!(point.x, x, 10.0);
+        assert_approx_eq!(point.y, y, 10.0);
+
+        let coordinate = WebMercator.unproject(&point);
+        assert_approx_eq!(
+            coordinate.latitude,
+            latitude.clamp(-MAX_LATITUDE, MAX_LATITUDE),
+            1e-5
+        );
+        assert_approx_eq!(coordinate.longitude.abs(), longitude.abs(), 1e-5);
+    }
+
+    #[rstest]
+    #[case(41.85, -87.65, 10, 262, 380)]
+    #[case(51.5074, -0.1278, 15, 16372, 10896)]
+    #[case(-33.8568, 151.2153, 12, 3768, 2457)]
+    #[case(0.0, 0.0, 0, 0, 0)]
+    #[case(90.0, 180.0, 2, 3, 0)]
+    #[case(-90.0, -180.0, 2, 0, 3)]
+    fn test_tile_from_coordinate(
+        #[case] latitude: Float,
+        #[case] longitude: Float,
+        #[case] zoom: u8,
+        #[case] x: u32,
+        #[case] y: u32,
+    ) {
+        let tile = Tile::from_coordinate(&Coordinate::new(latitude, longitude), zoom);
+        assert_eq!(tile, Tile::new(x, y, zoom));
+    }
+
+    #[rstest]
+    #[case(Tile::new(262, 380, 10), 42.032974, -87.539062, 41.771312, -87.890625)]
+    #[case(Tile::new(0, 0, 0), MAX_LATITUDE, 180.0, -MAX_LATITUDE, -180.0)]
+    #[case(Tile::new(1, 1, 1), 0.0, 180.0, -MAX_LATITUDE, 0.0)]
+    fn test_tile_bounds(
+        #[case] tile: Tile,
+        #[case] north: Float,
+        #[case] east: Float,
+        #[case] south: Float,
+        #[case] west: Float,
+    ) {
+        let bounds = tile.bounds();
+        assert_approx_eq!(bounds.north, north, 1e-4);
+        assert_approx_eq!(bounds.east, east, 1e-4);
+        assert_approx_eq!(bounds.south, south, 1e-4);
+        assert_approx_eq!(bounds.west, west, 1e-4);
+    }
+
+    #[rstest]
+    #[case(Tile::new(3, 5, 3), "213")]
+    #[case(Tile::new(0, 0, 0), "")]
+    #[case(Tile::new(1, 0, 1), "1")]
+    #[case(Tile::new(262, 380, 10), "0302222310")]
+    fn test_quadkey(#[case] tile: Tile, #[case] quadkey: &str) {
+        assert_eq!(tile.quadkey(), quadkey);
+        assert_eq!(Tile::from_quadkey(quadkey), Ok(tile));
+    }
+
+    #[rstest]
+    #[case("0124")]
+    #[case("a")]
+    #[case("00000000000000000000000000000000")]
+    fn test_invalid_quadkey(#[case] quadkey: &str) {
+        assert_eq!(
+            Tile::from_quadkey(quadkey),
+            Err(InvalidQuadkey(quadkey.to_string()))
+        );
+    }
+
+    #[test]
+    fn test_pixels() {
+        let coordinate = Coordinate::new(41.85, -87.65);
+        let pixel = coordinate_to_pixel(&coordinate, 10.0, DEFAULT_TILE_SIZE);
+        assert_approx_eq!(pixel.x, 67247.218, 0.01);
+        assert_approx_eq!(pixel.y, 97459.125, 0.01);
+
+        let actual = pixel_to_coordinate(&pixel, 10.0, DEFAULT_TILE_SIZE);
+        assert_approx_eq!(actual.latitude, 41.85, 1e-5);
+        assert_approx_eq!(actual.longitude, -87.65, 1e-5);
+    }
+
+    #[rstest]
+    #[case(0.0, 0.0, 156543.034)]
+    #[case(41.85, 10.0, 113.875)]
+    fn test_ground_resolution(
+        #[case] latitude: Float,
+        #[case] zoom: Float,
+        #[case] expected: Float,
+    ) {
+        assert_approx_eq!(
+            ground_resolution(latitude, zoom, DEFAULT_TILE_SIZE),
+            expected,
+            0.001
+        );
+    }
+}
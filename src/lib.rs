@@ -1,4 +1,6 @@
+pub mod geology;
 pub mod math;
 pub mod meteorology;
+pub mod navigation;
 mod test_helper;
 pub mod units;
@@ -1,6 +1,8 @@
+use crate::math::Float;
+
 use super::utils::is_approximately_zero;
 
-pub fn polynomial(x: f32, coefs: &[f32]) -> f32 {
+pub fn polynomial(x: Float, coefs: &[Float]) -> Float {
     let mut running_total = 0.0;
     let mut x_power = 1.0;
     coefs.iter().for_each(|&coef| {
@@ -10,7 +12,7 @@ pub fn polynomial(x: f32, coefs: &[f32]) -> f32 {
     running_total
 }
 
-pub fn solve_quadratic(a: f32, b: f32, c: f32) -> Vec<f32> {
+pub fn solve_quadratic(a: Float, b: Float, c: Float) -> Vec<Float> {
     let discriminant = b * b - 4.0 * a * c;
     if discriminant < 0.0 {
         Vec::new()
@@ -39,7 +41,7 @@ mod tests {
     #[case(3.0, &[0.0, 1.0, -3.0, 1.0], 3.0)]
     #[case(3.0, &[], 0.0)]
     #[case(3.0, &[1.0], 1.0)]
-    fn test_polynomial(#[case] x: f32, #[case] coefs: &[f32], #[case] expected: f32) {
+    fn test_polynomial(#[case] x: Float, #[case] coefs: &[Float], #[case] expected: Float) {
         let result = polynomial(x, coefs);
         assert_approx_eq!(expected, result);
     }
@@ -50,10 +52,10 @@ mod tests {
     #[case(1.0, 0.0, 0.0, vec![0.0])]
     #[case(1.0, 0.0, 1.0, vec![])]
     fn test_solve_quadratic(
-        #[case] a: f32,
-        #[case] b: f32,
-        #[case] c: f32,
-        #[case] expected: Vec<f32>,
+        #[case] a: Float,
+        #[case] b: Float,
+        #[case] c: Float,
+        #[case] expected: Vec<Float>,
     ) {
         let mut result = solve_quadratic(a, b, c);
         result.sort_by(|a, b| a.partial_cmp(b).unwrap());
@@ -1,31 +1,33 @@
+use crate::math::Float;
+
 use super::utils::is_approximately_zero;
 
-pub fn round_places(value: f32, places: i32) -> f32 {
-    let power = 10.0_f32.powi(places);
+pub fn round_places(value: Float, places: i32) -> Float {
+    let power = Float::powi(10.0, places);
     (value * power).round() / power
 }
 
-pub fn round_nearest(value: f32, nearest: f32) -> f32 {
+pub fn round_nearest(value: Float, nearest: Float) -> Float {
     (value / nearest).round() * nearest
 }
 
-pub fn power(value: f32, power: f32) -> f32 {
+pub fn power(value: Float, power: Float) -> Float {
     value.powf(power)
 }
 
-pub fn integer_power(value: f32, power: i32) -> f32 {
+pub fn integer_power(value: Float, power: i32) -> Float {
     value.powi(power)
 }
 
-pub fn square(value: f32) -> f32 {
+pub fn square(value: Float) -> Float {
     value * value
 }
 
-pub fn cube(value: f32) -> f32 {
+pub fn cube(value: Float) -> Float {
     value * value * value
 }
 
-pub fn greatest_common_divisor(a: f32, b: f32, precision: Option<f32>) -> f32 {
+pub fn greatest_common_divisor(a: Float, b: Float, precision: Option<Float>) -> Float {
     let max_iterations = 1000;
     let precision = precision.unwrap_or(0.0001);
     let mut current_a = a;
@@ -42,14 +44,14 @@ pub fn greatest_common_divisor(a: f32, b: f32, precision: Option<f32>) -> f32 {
     current_a
 }
 
-pub fn least_common_multiple(a: f32, b: f32) -> f32 {
+pub fn least_common_multiple(a: Float, b: Float) -> Float {
     if is_approximately_zero(a) || is_approximately_zero(b) {
         return 0.0;
     }
     a.abs() * (b.abs() / greatest_common_divisor(a, b, None))
 }
 
-pub fn is_increasing(arr: &[f32]) -> bool {
+pub fn is_increasing(arr: &[Float]) -> bool {
     let mut has_greater = false;
 
     for i in 1..arr.len() {
@@ -64,7 +66,7 @@ pub fn is_increasing(arr: &[f32]) -> bool {
     has_greater
 }
 
-pub fn is_decreasing(arr: &[f32]) -> bool {
+pub fn is_decreasing(arr: &[Float]) -> bool {
     let mut has_lesser = false;
 
     for i in 1..arr.len() {
@@ -117,7 +119,7 @@ mod tests {
     #[case(8000000.0, 5, 8000000.0)]
     #[case(8000000.125555, 5, 8000000.12556)]
     #[case(8000000.125555555555, 8, 8000000.12555556)]
-    fn test_round_places(#[case] input: f32, #[case] places: i32, #[case] expected: f32) {
+    fn test_round_places(#[case] input: Float, #[case] places: i32, #[case] expected: Float) {
         let result = round_places(input, places);
         assert_approx_eq!(expected, result);
     }
@@ -133,7 +135,11 @@ mod tests {
     #[case(-1.0, 1.0, -1.0)]
     #[case(-1.5, 1.0, -2.0)]
     #[case(-1.6, 1.0, -2.0)]
-    fn test_round_nearest_float(#[case] input: f32, #[case] nearest: f32, #[case] expected: f32) {
+    fn test_round_nearest_float(
+        #[case] input: Float,
+        #[case] nearest: Float,
+        #[case] expected: Float,
+    ) {
         let result = round_nearest(input, nearest);
         assert_approx_eq!(expected, result);
     }
@@ -146,7 +152,7 @@ mod tests {
     #[case(-4.0, 16.0)]
     #[case(0.0, 0.0)]
     #[case(0.5, 0.25)]
-    fn test_square(#[case] input: f32, #[case] expected: f32) {
+    fn test_square(#[case] input: Float, #[case] expected: Float) {
         let result = square(input);
         assert_approx_eq!(expected, result);
     }
@@ -159,7 +165,7 @@ mod tests {
     #[case(-4.0, -64.0)]
     #[case(0.0, 0.0)]
     #[case(0.5, 0.125)]
-    fn test_cube(#[case] input: f32, #[case] expected: f32) {
+    fn test_cube(#[case] input: Float, #[case] expected: Float) {
         let result = cube(input);
         assert_approx_eq!(expected, result);
     }
@@ -178,7 +184,7 @@ mod tests {
     #[case(-2.0, 3, -8.0)]
     #[case(0.5, 2, 0.25)]
     #[case(0.5, -2, 4.0)]
-    fn test_integer_power_f32(#[case] value: f32, #[case] power: i32, #[case] expected: f32) {
+    fn test_integer_power_f32(#[case] value: Float, #[case] power: i32, #[case] expected: Float) {
         let result = integer_power(value, power);
         assert_approx_eq!(expected, result);
     }
@@ -195,7 +201,7 @@ mod tests {
     #[case(4.0, 1.5, 8.0)]
     #[case(0.5, 2.0, 0.25)]
     #[case(0.5, -2.0, 4.0)]
-    fn test_power(#[case] base: f32, #[case] exponent: f32, #[case] expected: f32) {
+    fn test_power(#[case] base: Float, #[case] exponent: Float, #[case] expected: Float) {
         let result = power(base, exponent);
         assert_approx_eq!(expected, result);
     }
@@ -208,7 +214,7 @@ mod tests {
     #[case(0.0, 1.0, 1.0)]
     #[case(1.0, 0.0, 1.0)]
     #[case(0.0, 0.0, 0.0)]
-    fn test_greatest_common_divisor(#[case] a: f32, #[case] b: f32, #[case] expected: f32) {
+    fn test_greatest_common_divisor(#[case] a: Float, #[case] b: Float, #[case] expected: Float) {
         let result = greatest_common_divisor(a, b, None);
         assert_approx_eq!(expected, result);
     }
@@ -221,7 +227,7 @@ mod tests {
     #[case(0.0, 1.0, 0.0)]
     #[case(1.0, 0.0, 0.0)]
     #[case(0.0, 0.0, 0.0)]
-    fn test_least_common_multiple(#[case] a: f32, #[case] b: f32, #[case] expected: f32) {
+    fn test_least_common_multiple(#[case] a: Float, #[case] b: Float, #[case] expected: Float) {
         let result = least_common_multiple(a, b);
         assert_approx_eq!(expected, result);
     }
@@ -234,7 +240,7 @@ mod tests {
     #[case(&[3.0, 2.0, 1.0], false)]
     #[case(&[1.0], false)]
     #[case(&[], false)]
-    fn test_is_increasing(#[case] arr: &[f32], #[case] expected: bool) {
+    fn test_is_increasing(#[case] arr: &[Float], #[case] expected: bool) {
         let result = is_increasing(arr);
         assert_eq!(expected, result);
     }
@@ -247,7 +253,7 @@ mod tests {
     #[case(&[1.0, 2.0, 3.0], false)]
     #[case(&[1.0], false)]
     #[case(&[], false)]
-    fn test_is_decreasing(#[case] arr: &[f32], #[case] expected: bool) {
+    fn test_is_decreasing(#[case] arr: &[Float], #[case] expected: bool) {
         let result = is_decreasing(arr);
         assert_eq!(expected, result);
     }
@@ -1,18 +1,21 @@
+use crate::math::Float;
+
 use super::{Point2D, Shape2D, Translate2D};
 
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Circle {
     pub center: Point2D,
-    pub radius: f32,
+    pub radius: Float,
 }
 
 impl Shape2D for Circle {
-    fn area(&self) -> f32 {
-        std::f32::consts::PI * self.radius.powi(2)
+    fn area(&self) -> Float {
+        crate::math::consts::PI * self.radius.powi(2)
     }
 
-    fn perimeter(&self) -> f32 {
-        2.0 * std::f32::consts::PI * self.radius
+    fn perimeter(&self) -> Float {
+        2.0 * crate::math::consts::PI * self.radius
     }
 
     fn vertex_count(&self) -> usize {
@@ -21,7 +24,7 @@ impl Shape2D for Circle {
 }
 
 impl Translate2D for Circle {
-    fn translate(&self, dx: f32, dy: f32) -> Self {
+    fn translate(&self, dx: Float, dy: Float) -> Self {
         Circle {
             center: self.center.translate(dx, dy),
             radius: self.radius,
@@ -30,7 +33,7 @@ impl Translate2D for Circle {
 }
 
 impl Circle {
-    pub fn new(center: Point2D, radius: f32) -> Self {
+    pub fn new(center: Point2D, radius: Float) -> Self {
         Circle { center, radius }
     }
 }
@@ -41,9 +44,14 @@ mod tests {
     use rstest::*;
 
     #[rstest]
-    #[case(0.0, 0.0, 1.0, std::f32::consts::PI)] // Center at origin, radius 1
-    #[case(1.0, 1.0, 2.0, 4.0 * std::f32::consts::PI)] // Center at (1,1), radius 2
-    fn test_circle_area(#[case] x: f32, #[case] y: f32, #[case] r: f32, #[case] expected: f32) {
+    #[case(0.0, 0.0, 1.0, crate::math::consts::PI)] // Center at origin, radius 1
+    #[case(1.0, 1.0, 2.0, 4.0 * crate::math::consts::PI)] // Center at (1,1), radius 2
+    fn test_circle_area(
+        #[case] x: Float,
+        #[case] y: Float,
+        #[case] r: Float,
+        #[case] expected: Float,
+    ) {
         let circle = Circle {
             center: Point2D::new(x, y),
             radius: r,
@@ -52,13 +60,13 @@ mod tests {
     }
 
     #[rstest]
-    #[case(0.0, 0.0, 1.0, 2.0 * std::f32::consts::PI)] // Center at origin, radius 1
-    #[case(1.0, 1.0, 2.0, 4.0 * std::f32::consts::PI)] // Center at (1,1), radius 2
+    #[case(0.0, 0.0, 1.0, 2.0 * crate::math::consts::PI)] // Center at origin, radius 1
+    #[case(1.0, 1.0, 2.0, 4.0 * crate::math::consts::PI)] // Center at (1,1), radius 2
     fn test_circle_perimeter(
-        #[case] x: f32,
-        #[case] y: f32,
-        #[case] r: f32,
-        #[case] expected: f32,
+        #[case] x: Float,
+        #[case] y: Float,
+        #[case] r: Float,
+        #[case] expected: Float,
     ) {
         let circle = Circle {
             center: Point2D::new(x, y),
@@ -71,11 +79,11 @@ mod tests {
     #[case(0.0, 0.0, 1.0, 1.0, 1.0)] // Translate by (1,1)
     #[case(1.0, 1.0, 2.0, -1.0, -1.0)] // Translate by (-1,-1)
     fn test_circle_translate(
-        #[case] x: f32,
-        #[case] y: f32,
-        #[case] r: f32,
-        #[case] dx: f32,
-        #[case] dy: f32,
+        #[case] x: Float,
+        #[case] y: Float,
+        #[case] r: Float,
+        #[case] dx: Float,
+        #[case] dy: Float,
     ) {
         let circle = Circle {
             center: Point2D::new(x, y),
@@ -94,4 +102,17 @@ mod tests {
         };
         assert_eq!(circle.vertex_count(), 0);
     }
+
+    #[cfg(feature = "serde")]
+    #[rstest]
+    fn test_serde_round_trip() {
+        let circle = Circle {
+            center: Point2D::new(1.0, 2.0),
+            radius: 3.0,
+        };
+        crate::test_helper::assert_serde_round_trip(
+            &circle,
+            r#"{"center":{"x":1.0,"y":2.0},"radius":3.0}"#,
+        );
+    }
 }
@@ -1,14 +1,16 @@
+use crate::math::Float;
+
 use super::Point2D;
 
-pub fn euclidean_distance(p1: &Point2D, p2: &Point2D) -> f32 {
+pub fn euclidean_distance(p1: &Point2D, p2: &Point2D) -> Float {
     ((p1.x - p2.x).powi(2) + (p1.y - p2.y).powi(2)).sqrt()
 }
 
-pub fn manhattan_distance(p1: &Point2D, p2: &Point2D) -> f32 {
+pub fn manhattan_distance(p1: &Point2D, p2: &Point2D) -> Float {
     (p1.x - p2.x).abs() + (p1.y - p2.y).abs()
 }
 
-pub fn chebyshev_distance(p1: &Point2D, p2: &Point2D) -> f32 {
+pub fn chebyshev_distance(p1: &Point2D, p2: &Point2D) -> Float {
     (p1.x - p2.x).abs().max((p1.y - p2.y).abs())
 }
 
@@ -20,21 +22,21 @@ mod tests {
     #[rstest]
     #[case(Point2D { x: 0.0, y: 0.0 }, Point2D { x: 3.0, y: 4.0 }, 5.0)]
     #[case(Point2D { x: 1.0, y: 1.0 }, Point2D { x: 4.0, y: 5.0 }, 5.0)]
-    fn test_euclidean_distance(#[case] p1: Point2D, #[case] p2: Point2D, #[case] expected: f32) {
+    fn test_euclidean_distance(#[case] p1: Point2D, #[case] p2: Point2D, #[case] expected: Float) {
         assert_eq!(euclidean_distance(&p1, &p2), expected);
     }
 
     #[rstest]
     #[case(Point2D { x: 0.0, y: 0.0 }, Point2D { x: 3.0, y: 4.0 }, 7.0)]
     #[case(Point2D { x: 1.0, y: 1.0 }, Point2D { x: 4.0, y: 5.0 }, 7.0)]
-    fn test_manhattan_distance(#[case] p1: Point2D, #[case] p2: Point2D, #[case] expected: f32) {
+    fn test_manhattan_distance(#[case] p1: Point2D, #[case] p2: Point2D, #[case] expected: Float) {
         assert_eq!(manhattan_distance(&p1, &p2), expected);
     }
 
     #[rstest]
     #[case(Point2D { x: 0.0, y: 0.0 }, Point2D { x: 3.0, y: 4.0 }, 4.0)]
     #[case(Point2D { x: 1.0, y: 1.0 }, Point2D { x: 4.0, y: 5.0 }, 4.0)]
-    fn test_chebyshev_distance(#[case] p1: Point2D, #[case] p2: Point2D, #[case] expected: f32) {
+    fn test_chebyshev_distance(#[case] p1: Point2D, #[case] p2: Point2D, #[case] expected: Float) {
         assert_eq!(chebyshev_distance(&p1, &p2), expected);
     }
 }
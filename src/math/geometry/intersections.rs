@@ -167,6 +167,7 @@ pub fn intersects_polygon_polygon(polygon1: &Polygon, polygon2: &Polygon) -> boo
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::math::Float;
     use rstest::rstest;
 
     #[rstest]
@@ -179,10 +180,10 @@ mod test {
     #[case((0.0, 0.0), (1.0, 1.0), 0.5, 0.5, true)] // Point on diagonal line
     #[case((0.0, 0.0), (1.0, 1.0), 0.5, 0.0, false)] // Point below diagonal line
     fn test_contains_line_point(
-        #[case] start: (f32, f32),
-        #[case] end: (f32, f32),
-        #[case] px: f32,
-        #[case] py: f32,
+        #[case] start: (Float, Float),
+        #[case] end: (Float, Float),
+        #[case] px: Float,
+        #[case] py: Float,
         #[case] expected: bool,
     ) {
         let line = Line::new(Point2D::new(start.0, start.1), Point2D::new(end.0, end.1));
@@ -196,7 +197,7 @@ mod test {
     #[case(0.707, 0.707, true)] // Point on edge at 45 degrees
     #[case(2.0, 0.0, false)] // Point outside circle
     #[case(1.5, 1.5, false)] // Point outside circle diagonally
-    fn test_contains_circle_point(#[case] x: f32, #[case] y: f32, #[case] expected: bool) {
+    fn test_contains_circle_point(#[case] x: Float, #[case] y: Float, #[case] expected: bool) {
         let circle = Circle::new(Point2D::new(0.0, 0.0), 1.0);
         let point = Point2D::new(x, y);
         assert_eq!(contains_circle_point(&circle, &point), expected);
@@ -214,9 +215,9 @@ mod test {
     #[case(vec![(0.0, 0.0), (1.0, 0.0), (1.0, 1.0), (0.0, 1.0)], 0.0, 0.0, true)] // Point on vertex
     #[case(vec![(0.0, 0.0), (1.0, 0.0), (1.0, 1.0), (0.0, 1.0)], -0.5, -0.5, false)] // Point outside
     fn test_contains_polygon_point(
-        #[case] vertices: Vec<(f32, f32)>,
-        #[case] x: f32,
-        #[case] y: f32,
+        #[case] vertices: Vec<(Float, Float)>,
+        #[case] x: Float,
+        #[case] y: Float,
         #[case] expected: bool,
     ) {
         let vertices = vertices
@@ -240,10 +241,10 @@ mod test {
     #[case((0.0, 0.0), (1.0, 1.0), (0.0, 2.0), (1.0, 3.0), false)] // Parallel diagonal lines
     #[case((0.0, 0.0), (1.0, 0.0), (1.0, 0.0), (2.0, 0.0), true)] // Lines touch at endpoint
     fn test_intersects_line_line(
-        #[case] start1: (f32, f32),
-        #[case] end1: (f32, f32),
-        #[case] start2: (f32, f32),
-        #[case] end2: (f32, f32),
+        #[case] start1: (Float, Float),
+        #[case] end1: (Float, Float),
+        #[case] start2: (Float, Float),
+        #[case] end2: (Float, Float),
         #[case] expected: bool,
     ) {
         let line1 = Line::new(
@@ -262,13 +263,13 @@ mod test {
     #[case(0.0, 0.0, 1.0, 2.0, 2.0, 3.0, 0.0, false)] // Line outside circle
     #[case(0.0, 0.0, 1.0, 0.1, 0.1, 0.2, 0.5, true)] // Line inside circle
     fn test_intersects_circle_line(
-        #[case] cx: f32,
-        #[case] cy: f32,
-        #[case] r: f32,
-        #[case] x1: f32,
-        #[case] y1: f32,
-        #[case] x2: f32,
-        #[case] y2: f32,
+        #[case] cx: Float,
+        #[case] cy: Float,
+        #[case] r: Float,
+        #[case] x1: Float,
+        #[case] y1: Float,
+        #[case] x2: Float,
+        #[case] y2: Float,
         #[case] expected: bool,
     ) {
         let circle = Circle::new(Point2D::new(cx, cy), r);
@@ -282,12 +283,12 @@ mod test {
     #[case(0.0, 0.0, 1.0, 3.0, 0.0, 1.0, false)] // Circles separate
     #[case(0.0, 0.0, 2.0, 0.0, 0.0, 1.0, true)] // One circle inside other
     fn test_intersects_circle_circle(
-        #[case] x1: f32,
-        #[case] y1: f32,
-        #[case] r1: f32,
-        #[case] x2: f32,
-        #[case] y2: f32,
-        #[case] r2: f32,
+        #[case] x1: Float,
+        #[case] y1: Float,
+        #[case] r1: Float,
+        #[case] x2: Float,
+        #[case] y2: Float,
+        #[case] r2: Float,
         #[case] expected: bool,
     ) {
         let circle1 = Circle::new(Point2D::new(x1, y1), r1);
@@ -305,10 +306,10 @@ mod test {
     #[case(0.0, 0.0, 0.1, vec![(2.0, 2.0), (3.0, 2.0), (3.0, 3.0), (2.0, 3.0)], false)] // Circle outside rectangle
     #[case(1.0, 1.0, 0.1, vec![(0.0, 0.0), (2.0, 0.0), (2.0, 2.0), (0.0, 2.0)], true)] // Circle inside rectangle
     fn test_intersects_circle_polygon(
-        #[case] cx: f32,
-        #[case] cy: f32,
-        #[case] r: f32,
-        #[case] vertices: Vec<(f32, f32)>,
+        #[case] cx: Float,
+        #[case] cy: Float,
+        #[case] r: Float,
+        #[case] vertices: Vec<(Float, Float)>,
         #[case] expected: bool,
     ) {
         let circle = Circle::new(Point2D::new(cx, cy), r);
@@ -328,11 +329,11 @@ mod test {
     #[case(vec![(0.0, 0.0), (1.0, 0.0), (1.0, 1.0), (0.0, 1.0)], 0.25, 0.25, 0.75, 0.75, true)] // Line intersects rectangle
     #[case(vec![(0.0, 0.0), (1.0, 0.0), (1.0, 1.0), (0.0, 1.0)], -1.0, -1.0, -0.5, -0.5, false)] // Line outside rectangle
     fn test_intersects_polygon_line(
-        #[case] vertices: Vec<(f32, f32)>,
-        #[case] x1: f32,
-        #[case] y1: f32,
-        #[case] x2: f32,
-        #[case] y2: f32,
+        #[case] vertices: Vec<(Float, Float)>,
+        #[case] x1: Float,
+        #[case] y1: Float,
+        #[case] x2: Float,
+        #[case] y2: Float,
         #[case] expected: bool,
     ) {
         let vertices = vertices
@@ -368,8 +369,8 @@ mod test {
         false
     )] // Rectangles separate
     fn test_intersects_polygon_polygon(
-        #[case] vertices1: Vec<(f32, f32)>,
-        #[case] vertices2: Vec<(f32, f32)>,
+        #[case] vertices1: Vec<(Float, Float)>,
+        #[case] vertices2: Vec<(Float, Float)>,
         #[case] expected: bool,
     ) {
         let vertices1 = vertices1
@@ -1,13 +1,16 @@
+use crate::math::Float;
+
 use super::{Point2D, Translate2D};
 
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Line {
     pub start: Point2D,
     pub end: Point2D,
 }
 
 impl Line {
-    pub fn len(&self) -> f32 {
+    pub fn len(&self) -> Float {
         self.start.distance(&self.end)
     }
 
@@ -17,7 +20,7 @@ impl Line {
 }
 
 impl Translate2D for Line {
-    fn translate(&self, dx: f32, dy: f32) -> Self {
+    fn translate(&self, dx: Float, dy: Float) -> Self {
         Line {
             start: self.start.translate(dx, dy),
             end: self.end.translate(dx, dy),
@@ -34,7 +37,7 @@ mod test {
     #[case(Point2D::new(0.0, 0.0), Point2D::new(3.0, 4.0), 5.0)]
     #[case(Point2D::new(-1.0, -1.0), Point2D::new(2.0, 3.0), 5.0)]
     #[case(Point2D::new(1.0, 1.0), Point2D::new(1.0, 1.0), 0.0)]
-    fn test_line_length(#[case] start: Point2D, #[case] end: Point2D, #[case] expected: f32) {
+    fn test_line_length(#[case] start: Point2D, #[case] end: Point2D, #[case] expected: Float) {
         let line = Line { start, end };
         assert_eq!(line.len(), expected);
     }
@@ -51,8 +54,8 @@ mod test {
     fn test_line_translate(
         #[case] start: Point2D,
         #[case] end: Point2D,
-        #[case] dx: f32,
-        #[case] dy: f32,
+        #[case] dx: Float,
+        #[case] dy: Float,
         #[case] expected_start: Point2D,
         #[case] expected_end: Point2D,
     ) {
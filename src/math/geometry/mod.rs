@@ -5,6 +5,7 @@ mod line;
 mod point;
 mod polygon;
 mod shape;
+mod simplify;
 mod transform;
 
 pub use self::circle::*;
@@ -14,4 +15,5 @@ pub use self::line::*;
 pub use self::point::*;
 pub use self::polygon::*;
 pub use self::shape::*;
+pub use self::simplify::*;
 pub use self::transform::*;
@@ -1,23 +1,24 @@
-use crate::math::linear_algebra::Vector;
+use crate::math::{Float, linear_algebra::Vector};
 
 use super::{Translate2D, euclidean_distance};
 
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Point2D {
-    pub x: f32,
-    pub y: f32,
+    pub x: Float,
+    pub y: Float,
 }
 
 impl Point2D {
-    pub fn distance(&self, other: &Point2D) -> f32 {
+    pub fn distance(&self, other: &Point2D) -> Float {
         euclidean_distance(self, other)
     }
 
-    pub fn angle(&self, other: &Point2D) -> f32 {
+    pub fn angle(&self, other: &Point2D) -> Float {
         (other.y - self.y).atan2(other.x - self.x)
     }
 
-    pub fn new(x: f32, y: f32) -> Self {
+    pub fn new(x: Float, y: Float) -> Self {
         Point2D { x, y }
     }
 }
@@ -31,7 +32,7 @@ impl std::ops::Sub for Point2D {
 }
 
 impl Translate2D for Point2D {
-    fn translate(&self, dx: f32, dy: f32) -> Self {
+    fn translate(&self, dx: Float, dy: Float) -> Self {
         Point2D {
             x: self.x + dx,
             y: self.y + dy,
@@ -53,11 +54,11 @@ mod tests {
     #[case(3.0, 4.0, 0.0, 0.0, 5.0)]
     #[case(1.0, 1.0, 2.0, 2.0, 1.4142135)]
     fn test_distance(
-        #[case] x1: f32,
-        #[case] y1: f32,
-        #[case] x2: f32,
-        #[case] y2: f32,
-        #[case] expected: f32,
+        #[case] x1: Float,
+        #[case] y1: Float,
+        #[case] x2: Float,
+        #[case] y2: Float,
+        #[case] expected: Float,
     ) {
         let p1 = Point2D::new(x1, y1);
         let p2 = Point2D::new(x2, y2);
@@ -69,12 +70,12 @@ mod tests {
     #[case(0.0, 0.0, 1.0, 1.0, 1.0, 1.0)]
     #[case(-1.0, -2.0, 2.0, 3.0, 1.0, 1.0)]
     fn test_translate(
-        #[case] x: f32,
-        #[case] y: f32,
-        #[case] dx: f32,
-        #[case] dy: f32,
-        #[case] expected_x: f32,
-        #[case] expected_y: f32,
+        #[case] x: Float,
+        #[case] y: Float,
+        #[case] dx: Float,
+        #[case] dy: Float,
+        #[case] expected_x: Float,
+        #[case] expected_y: Float,
     ) {
         let point = Point2D::new(x, y);
         let translated = point.translate(dx, dy);
@@ -83,17 +84,17 @@ mod tests {
     }
 
     #[rstest]
-    #[case(1.0, 0.0, 1.0, 1.0, std::f32::consts::PI / 2.0)]
-    #[case(1.0, 0.0, 0.0, 1.0, 3.0 * std::f32::consts::PI / 4.0)]
-    #[case(0.0, 0.0, -1.0, 0.0, std::f32::consts::PI)]
+    #[case(1.0, 0.0, 1.0, 1.0, crate::math::consts::PI / 2.0)]
+    #[case(1.0, 0.0, 0.0, 1.0, 3.0 * crate::math::consts::PI / 4.0)]
+    #[case(0.0, 0.0, -1.0, 0.0, crate::math::consts::PI)]
     #[case(0.0, 0.0, 1.0, 0.0, 0.0)]
-    #[case(0.0, 0.0, 0.0, -1.0, -std::f32::consts::PI / 2.0)]
+    #[case(0.0, 0.0, 0.0, -1.0, -crate::math::consts::PI / 2.0)]
     fn test_angle(
-        #[case] x1: f32,
-        #[case] y1: f32,
-        #[case] x2: f32,
-        #[case] y2: f32,
-        #[case] expected: f32,
+        #[case] x1: Float,
+        #[case] y1: Float,
+        #[case] x2: Float,
+        #[case] y2: Float,
+        #[case] expected: Float,
     ) {
         let p1 = Point2D::new(x1, y1);
         let p2 = Point2D::new(x2, y2);
@@ -105,12 +106,12 @@ mod tests {
     #[case(0.0, 0.0, 1.0, 1.0, -1.0, -1.0)]
     #[case(-1.0, -2.0, 2.0, 3.0, -3.0, -5.0)]
     fn test_sub(
-        #[case] x1: f32,
-        #[case] y1: f32,
-        #[case] x2: f32,
-        #[case] y2: f32,
-        #[case] expected_x: f32,
-        #[case] expected_y: f32,
+        #[case] x1: Float,
+        #[case] y1: Float,
+        #[case] x2: Float,
+        #[case] y2: Float,
+        #[case] expected_x: Float,
+        #[case] expected_y: Float,
     ) {
         let p1 = Point2D::new(x1, y1);
         let p2 = Point2D::new(x2, y2);
@@ -118,4 +119,21 @@ mod tests {
         assert_eq!(result.x, expected_x);
         assert_eq!(result.y, expected_y);
     }
+
+    #[cfg(feature = "serde")]
+    #[rstest]
+    fn test_serde_round_trip() {
+        crate::test_helper::assert_serde_round_trip(
+            &Point2D::new(1.5, -2.0),
+            r#"{"x":1.5,"y":-2.0}"#,
+        );
+    }
+
+    #[cfg(feature = "f64")]
+    #[rstest]
+    fn test_distance_keeps_f64_precision() {
+        let p1 = Point2D::new(1e9, 0.0);
+        let p2 = Point2D::new(1e9 + 0.5, 0.0);
+        assert_eq!(p1.distance(&p2), 0.5);
+    }
 }
@@ -1,12 +1,15 @@
+use crate::math::Float;
+
 use super::{Line, Point2D, Shape2D, Translate2D};
 
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Polygon {
     pub vertices: Vec<Point2D>,
 }
 
 impl Shape2D for Polygon {
-    fn area(&self) -> f32 {
+    fn area(&self) -> Float {
         let mut area = 0.0;
         let n = self.vertices.len();
         for i in 0..n {
@@ -17,7 +20,7 @@ impl Shape2D for Polygon {
         area.abs() / 2.0
     }
 
-    fn perimeter(&self) -> f32 {
+    fn perimeter(&self) -> Float {
         let mut perimeter = 0.0;
         let n = self.vertices.len();
         for i in 0..n {
@@ -61,7 +64,7 @@ impl Polygon {
     }
 
     /// A rectangle with the bottom left corner at the origin
-    pub fn rectangle_from_sides(width: f32, height: f32) -> Self {
+    pub fn rectangle_from_sides(width: Float, height: Float) -> Self {
         let p1 = Point2D::new(0.0, 0.0);
         let p2 = Point2D::new(width, 0.0);
         let p3 = Point2D::new(width, height);
@@ -73,7 +76,7 @@ impl Polygon {
 }
 
 impl Translate2D for Polygon {
-    fn translate(&self, dx: f32, dy: f32) -> Self {
+    fn translate(&self, dx: Float, dy: Float) -> Self {
         Polygon {
             vertices: self.vertices.iter().map(|v| v.translate(dx, dy)).collect(),
         }
@@ -88,13 +91,13 @@ mod tests {
     #[rstest]
     #[case(0.0, 0.0, 1.0, 0.0, 0.0, 1.0, 0.5)]
     fn test_triangle_area(
-        #[case] x1: f32,
-        #[case] y1: f32,
-        #[case] x2: f32,
-        #[case] y2: f32,
-        #[case] x3: f32,
-        #[case] y3: f32,
-        #[case] expected: f32,
+        #[case] x1: Float,
+        #[case] y1: Float,
+        #[case] x2: Float,
+        #[case] y2: Float,
+        #[case] x3: Float,
+        #[case] y3: Float,
+        #[case] expected: Float,
     ) {
         let p1 = Point2D::new(x1, y1);
         let p2 = Point2D::new(x2, y2);
@@ -106,30 +109,30 @@ mod tests {
     #[rstest]
     #[case(0.0, 0.0, 2.0, 0.0, 2.0, 1.0, 0.0, 1.0, 6.0)]
     fn test_rectangle_perimeter(
-        #[case] x1: f32,
-        #[case] y1: f32,
-        #[case] x2: f32,
-        #[case] y2: f32,
-        #[case] x3: f32,
-        #[case] y3: f32,
-        #[case] x4: f32,
-        #[case] y4: f32,
-        #[case] expected: f32,
+        #[case] x1: Float,
+        #[case] y1: Float,
+        #[case] x2: Float,
+        #[case] y2: Float,
+        #[case] x3: Float,
+        #[case] y3: Float,
+        #[case] x4: Float,
+        #[case] y4: Float,
+        #[case] expected: Float,
     ) {
         let p1 = Point2D::new(x1, y1);
         let p2 = Point2D::new(x2, y2);
         let p3 = Point2D::new(x3, y3);
         let p4 = Point2D::new(x4, y4);
         let rectangle = Polygon::rectangle(p1, p2, p3, p4);
-        assert!((rectangle.perimeter() - expected).abs() < f32::EPSILON);
+        assert!((rectangle.perimeter() - expected).abs() < Float::EPSILON);
     }
 
     #[rstest]
     #[case(2.0, 3.0, 6.0)]
     fn test_rectangle_from_sides_area(
-        #[case] width: f32,
-        #[case] height: f32,
-        #[case] expected: f32,
+        #[case] width: Float,
+        #[case] height: Float,
+        #[case] expected: Float,
     ) {
         let rectangle = Polygon::rectangle_from_sides(width, height);
         assert_eq!(rectangle.area(), expected);
@@ -138,13 +141,29 @@ mod tests {
     #[rstest]
     #[case(1.0, 1.0, 2.0, 2.0)]
     fn test_polygon_translate(
-        #[case] dx: f32,
-        #[case] dy: f32,
-        #[case] new_x: f32,
-        #[case] new_y: f32,
+        #[case] dx: Float,
+        #[case] dy: Float,
+        #[case] new_x: Float,
+        #[case] new_y: Float,
     ) {
         let polygon = Polygon::rectangle_from_sides(1.0, 1.0);
         let translated = polygon.translate(dx, dy);
         assert_eq!(translated.vertices[2], Point2D::new(new_x, new_y));
     }
+
+    #[cfg(feature = "serde")]
+    #[rstest]
+    fn test_serde_round_trip() {
+        let polygon = Polygon {
+            vertices: vec![
+                Point2D::new(0.0, 0.0),
+                Point2D::new(1.0, 0.0),
+                Point2D::new(0.0, 1.0),
+            ],
+        };
+        crate::test_helper::assert_serde_round_trip(
+            &polygon,
+            r#"{"vertices":[{"x":0.0,"y":0.0},{"x":1.0,"y":0.0},{"x":0.0,"y":1.0}]}"#,
+        );
+    }
 }
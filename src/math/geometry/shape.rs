@@ -1,5 +1,7 @@
+use crate::math::Float;
+
 pub trait Shape2D {
-    fn area(&self) -> f32;
-    fn perimeter(&self) -> f32;
+    fn area(&self) -> Float;
+    fn perimeter(&self) -> Float;
     fn vertex_count(&self) -> usize;
 }
@@ -0,0 +1,349 @@
+use std::{cmp::Ordering, collections::BinaryHeap};
+
+use crate::math::Float;
+
+use super::{Point2D, Polygon};
+
+/// Simplifies the polyline with the Douglas-Peucker algorithm, keeping the points needed so no
+/// removed point is further than the tolerance from the simplified line. The first and last points
+/// are always kept.
+pub fn douglas_peucker(points: &[Point2D], tolerance: Float) -> Vec<Point2D> {
+    let keep = douglas_peucker_mask(points.len(), tolerance, |i, start, end| {
+        distance_to_segment(&points[i], &points[start], &points[end])
+    });
+    filter(points, &keep)
+}
+
+/// Simplifies the polyline with the Visvalingam-Whyatt algorithm, repeatedly removing the point which
+/// forms the smallest triangle with its neighbors until every remaining triangle is at least the
+/// minimum area. This tends to keep the overall shape better than Douglas-Peucker. The first and last
+/// points are always kept.
+pub fn visvalingam_whyatt(points: &[Point2D], min_area: Float) -> Vec<Point2D> {
+    let keep = visvalingam_whyatt_mask(points.len(), min_area, |a, b, c| {
+        triangle_area(&points[a], &points[b], &points[c])
+    });
+    filter(points, &keep)
+}
+
+/// Returns points evenly spaced along the polyline, starting at the first point. The last point is
+/// always included, so the final gap may be shorter than the spacing.
+pub fn resample_polyline(points: &[Point2D], spacing: Float) -> Vec<Point2D> {
+    if points.len() < 2 || spacing <= 0.0 {
+        return points.to_vec();
+    }
+    let mut resampled = vec![points[0]];
+    // The distance along the current segment where the next point goes
+    let mut next = spacing;
+    for pair in points.windows(2) {
+        let length = pair[0].distance(&pair[1]);
+        while next <= length {
+            let t = next / length;
+            resampled.push(Point2D::new(
+                pair[0].x + (pair[1].x - pair[0].x) * t,
+                pair[0].y + (pair[1].y - pair[0].y) * t,
+            ));
+            next += spacing;
+        }
+        next -= length;
+    }
+    let last = points[points.len() - 1];
+    if resampled.last() != Some(&last) {
+        resampled.push(last);
+    }
+    resampled
+}
+
+impl Polygon {
+    /// Simplifies the outline with the Douglas-Peucker algorithm. Very small polygons may collapse to
+    /// fewer than 3 vertices.
+    pub fn simplify_douglas_peucker(&self, tolerance: Float) -> Polygon {
+        let n = self.vertices.len();
+        let keep = douglas_peucker_mask(n + 1, tolerance, |i, start, end| {
+            distance_to_segment(
+                &self.vertices[i % n],
+                &self.vertices[start % n],
+                &self.vertices[end % n],
+            )
+        });
+        Polygon::new(filter(&self.vertices, &keep))
+    }
+
+    /// Simplifies the outline with the Visvalingam-Whyatt algorithm. Very small polygons may collapse
+    /// to fewer than 3 vertices.
+    pub fn simplify_visvalingam_whyatt(&self, min_area: Float) -> Polygon {
+        let n = self.vertices.len();
+        let keep = visvalingam_whyatt_mask(n + 1, min_area, |a, b, c| {
+            triangle_area(
+                &self.vertices[a % n],
+                &self.vertices[b % n],
+                &self.vertices[c % n],
+            )
+        });
+        Polygon::new(filter(&self.vertices, &keep))
+    }
+
+    /// Returns vertices evenly spaced along the outline, starting at the first vertex
+    pub fn resample(&self, spacing: Float) -> Polygon {
+        let Some(first) = self.vertices.first() else {
+            return self.clone();
+        };
+        let mut ring = self.vertices.clone();
+        ring.push(*first);
+        let mut vertices = resample_polyline(&ring, spacing);
+        if vertices.len() > 1 {
+            vertices.pop();
+        }
+        Polygon::new(vertices)
+    }
+}
+
+/// Returns which of the points to keep using the Douglas-Peucker algorithm. The distance function
+/// returns the distance from a point to the segment between two other points (by index).
+pub(crate) fn douglas_peucker_mask(
+    count: usize,
+    tolerance: Float,
+    distance: impl Fn(usize, usize, usize) -> Float,
+) -> Vec<bool> {
+    let mut keep = vec![false; count];
+    if count == 0 {
+        return keep;
+    }
+    keep[0] = true;
+    keep[count - 1] = true;
+
+    let mut ranges = vec![(0, count - 1)];
+    while let Some((start, end)) = ranges.pop() {
+        let farthest = (start + 1..end)
+            .map(|i| (i, distance(i, start, end)))
+            .max_by(|a, b| a.1.total_cmp(&b.1));
+        if let Some((i, max_distance)) = farthest
+            && max_distance > tolerance
+        {
+            keep[i] = true;
+            ranges.push((start, i));
+            ranges.push((i, end));
+        }
+    }
+    keep
+}
+
+/// Returns which of the points to keep using the Visvalingam-Whyatt algorithm. The area function
+/// returns the area of the triangle formed by three points (by index).
+pub(crate) fn visvalingam_whyatt_mask(
+    count: usize,
+    min_area: Float,
+    area: impl Fn(usize, usize, usize) -> Float,
+) -> Vec<bool> {
+    let mut keep = vec![true; count];
+    if count < 3 {
+        return keep;
+    }
+    let mut previous: Vec<usize> = (0..count).map(|i| i.saturating_sub(1)).collect();
+    let mut next: Vec<usize> = (0..count).map(|i| i + 1).collect();
+    let mut areas: Vec<Float> = (0..count)
+        .map(|i| {
+            if i == 0 || i == count - 1 {
+                Float::INFINITY
+            } else {
+                area(i - 1, i, i + 1)
+            }
+        })
+        .collect();
+
+    let mut queue: BinaryHeap<QueuedPoint> = (1..count - 1)
+        .map(|index| QueuedPoint {
+            area: areas[index],
+            index,
+        })
+        .collect();
+
+    while let Some(point) = queue.pop() {
+        // Skip entries which were removed or have since had their area updated
+        if !keep[point.index] || point.area != areas[point.index] {
+            continue;
+        }
+        if point.area >= min_area {
+            break;
+        }
+        keep[point.index] = false;
+        let before = previous[point.index];
+        let after = next[point.index];
+        next[before] = after;
+        previous[after] = before;
+
+        // The neighbors' areas never drop below the removed area, so points are removed in order
+        for neighbor in [before, after] {
+            if neighbor == 0 || neighbor == count - 1 {
+                continue;
+            }
+            let neighbor_area = area(previous[neighbor], neighbor, next[neighbor]).max(point.area);
+            areas[neighbor] = neighbor_area;
+            queue.push(QueuedPoint {
+                area: neighbor_area,
+                index: neighbor,
+            });
+        }
+    }
+    keep
+}
+
+/// A point in the Visvalingam-Whyatt queue, ordered so the smallest area is popped first
+struct QueuedPoint {
+    area: Float,
+    index: usize,
+}
+
+impl PartialEq for QueuedPoint {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for QueuedPoint {}
+
+impl PartialOrd for QueuedPoint {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for QueuedPoint {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other
+            .area
+            .total_cmp(&self.area)
+            .then_with(|| other.index.cmp(&self.index))
+    }
+}
+
+fn filter<T: Copy>(values: &[T], keep: &[bool]) -> Vec<T> {
+    values
+        .iter()
+        .zip(keep)
+        .filter(|(_, keep)| **keep)
+        .map(|(value, _)| *value)
+        .collect()
+}
+
+fn distance_to_segment(point: &Point2D, start: &Point2D, end: &Point2D) -> Float {
+    let dx = end.x - start.x;
+    let dy = end.y - start.y;
+    let length_squared = dx * dx + dy * dy;
+    if length_squared == 0.0 {
+        return point.distance(start);
+    }
+    let t =
+        (((point.x - start.x) * dx + (point.y - start.y) * dy) / length_squared).clamp(0.0, 1.0);
+    point.distance(&Point2D::new(start.x + t * dx, start.y + t * dy))
+}
+
+fn triangle_area(a: &Point2D, b: &Point2D, c: &Point2D) -> Float {
+    ((b.x - a.x) * (c.y - a.y) - (c.x - a.x) * (b.y - a.y)).abs() / 2.0
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::{assert_approx_eq, math::geometry::Shape2D};
+
+    fn points(values: &[(Float, Float)]) -> Vec<Point2D> {
+        values.iter().map(|(x, y)| Point2D::new(*x, *y)).collect()
+    }
+
+    #[rstest]
+    #[case(&[(0.0, 0.0), (1.0, 0.1), (2.0, -0.1), (3.0, 5.0), (4.0, 6.0), (5.0, 7.0), (6.0, 8.1), (7.0, 9.0)], 0.5, &[(0.0, 0.0), (2.0, -0.1), (3.0, 5.0), (7.0, 9.0)])]
+    #[case(&[(0.0, 0.0), (1.0, 0.1), (2.0, -0.1), (3.0, 5.0), (4.0, 6.0), (5.0, 7.0), (6.0, 8.1), (7.0, 9.0)], 0.0, &[(0.0, 0.0), (1.0, 0.1), (2.0, -0.1), (3.0, 5.0), (5.0, 7.0), (6.0, 8.1), (7.0, 9.0)])]
+    #[case(&[(0.0, 0.0), (1.0, 0.0), (2.0, 0.0)], 0.1, &[(0.0, 0.0), (2.0, 0.0)])]
+    #[case(&[(0.0, 0.0), (5.0, 1.0), (0.0, 0.0)], 0.1, &[(0.0, 0.0), (5.0, 1.0), (0.0, 0.0)])]
+    #[case(&[(0.0, 0.0)], 1.0, &[(0.0, 0.0)])]
+    #[case(&[], 1.0, &[])]
+    fn test_douglas_peucker(
+        #[case] values: &[(Float, Float)],
+        #[case] tolerance: Float,
+        #[case] expected: &[(Float, Float)],
+    ) {
+        assert_eq!(
+            douglas_peucker(&points(values), tolerance),
+            points(expected)
+        );
+    }
+
+    #[rstest]
+    #[case(&[(0.0, 0.0), (1.0, 0.1), (2.0, -0.1), (3.0, 5.0), (4.0, 6.0), (5.0, 7.0), (6.0, 8.1), (7.0, 9.0)], 0.5, &[(0.0, 0.0), (2.0, -0.1), (3.0, 5.0), (7.0, 9.0)])]
+    #[case(&[(0.0, 0.0), (1.0, 0.0), (2.0, 0.0)], 0.1, &[(0.0, 0.0), (2.0, 0.0)])]
+    #[case(&[(0.0, 0.0), (1.0, 1.0), (2.0, 0.0)], 0.5, &[(0.0, 0.0), (1.0, 1.0), (2.0, 0.0)])]
+    #[case(&[(0.0, 0.0), (1.0, 1.0), (2.0, 0.0)], 1.5, &[(0.0, 0.0), (2.0, 0.0)])]
+    #[case(&[(0.0, 0.0), (2.0, 0.0)], 10.0, &[(0.0, 0.0), (2.0, 0.0)])]
+    fn test_visvalingam_whyatt(
+        #[case] values: &[(Float, Float)],
+        #[case] min_area: Float,
+        #[case] expected: &[(Float, Float)],
+    ) {
+        assert_eq!(
+            visvalingam_whyatt(&points(values), min_area),
+            points(expected)
+        );
+    }
+
+    #[test]
+    fn test_visvalingam_whyatt_removes_smallest_first() {
+        // Removing (1, 0.2) makes the triangle at (2, 0) bigger than the threshold
+        let simplified = visvalingam_whyatt(
+            &points(&[(0.0, 0.0), (1.0, 0.2), (2.0, 0.0), (3.0, 3.0)]),
+            1.0,
+        );
+        assert_eq!(simplified, points(&[(0.0, 0.0), (2.0, 0.0), (3.0, 3.0)]));
+    }
+
+    #[rstest]
+    #[case(&[(0.0, 0.0), (10.0, 0.0)], 2.5, &[(0.0, 0.0), (2.5, 0.0), (5.0, 0.0), (7.5, 0.0), (10.0, 0.0)])]
+    #[case(&[(0.0, 0.0), (3.0, 0.0), (3.0, 4.0)], 2.0, &[(0.0, 0.0), (2.0, 0.0), (3.0, 1.0), (3.0, 3.0), (3.0, 4.0)])]
+    #[case(&[(0.0, 0.0), (1.0, 0.0)], 5.0, &[(0.0, 0.0), (1.0, 0.0)])]
+    #[case(&[(0.0, 0.0), (1.0, 0.0)], 0.0, &[(0.0, 0.0), (1.0, 0.0)])]
+    fn test_resample_polyline(
+        #[case] values: &[(Float, Float)],
+        #[case] spacing: Float,
+        #[case] expected: &[(Float, Float)],
+    ) {
+        let resampled = resample_polyline(&points(values), spacing);
+        assert_eq!(resampled.len(), expected.len());
+        for (actual, expected) in resampled.iter().zip(points(expected)) {
+            assert_approx_eq!(actual.x, expected.x);
+            assert_approx_eq!(actual.y, expected.y);
+        }
+    }
+
+    #[test]
+    fn test_simplify_polygon() {
+        let polygon = Polygon::new(points(&[
+            (0.0, 0.0),
+            (5.0, 0.1),
+            (10.0, 0.0),
+            (10.1, 5.0),
+            (10.0, 10.0),
+            (0.0, 10.0),
+            (-0.1, 5.0),
+        ]));
+        let square = Polygon::new(points(&[
+            (0.0, 0.0),
+            (10.0, 0.0),
+            (10.0, 10.0),
+            (0.0, 10.0),
+        ]));
+        assert_eq!(polygon.simplify_douglas_peucker(0.5), square);
+        assert_eq!(polygon.simplify_visvalingam_whyatt(1.0), square);
+        assert_eq!(polygon.simplify_douglas_peucker(0.0), polygon);
+    }
+
+    #[test]
+    fn test_resample_polygon() {
+        let square = Polygon::rectangle_from_sides(2.0, 2.0);
+        let resampled = square.resample(1.0);
+        assert_eq!(resampled.vertices.len(), 8);
+        assert_approx_eq!(resampled.perimeter(), 8.0);
+        assert_eq!(resampled.vertices[1], Point2D::new(1.0, 0.0));
+        assert_eq!(Polygon::new(vec![]).resample(1.0), Polygon::new(vec![]));
+    }
+}
@@ -1,3 +1,5 @@
+use crate::math::Float;
+
 pub trait Translate2D {
-    fn translate(&self, dx: f32, dy: f32) -> Self;
+    fn translate(&self, dx: Float, dy: Float) -> Self;
 }
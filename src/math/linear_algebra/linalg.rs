@@ -1,4 +1,6 @@
-pub type RawTensor = Vec<Vec<f32>>;
+use crate::math::Float;
+
+pub type RawTensor = Vec<Vec<Float>>;
 
 fn rows(tensor: &RawTensor) -> usize {
     tensor.len()
@@ -52,7 +54,9 @@ pub fn subtract(tensor1: &RawTensor, tensor2: &RawTensor) -> RawTensor {
     combine(tensor1, tensor2, |x1, x2| x1 - x2)
 }
 
-pub fn magnitude(tensor: &RawTensor) -> f32 {
+// The casts are no-ops when the f64 feature is enabled
+#[allow(clippy::unnecessary_cast)]
+pub fn magnitude(tensor: &RawTensor) -> Float {
     // Using f64 to retain intermediate precision
     let mut sum: f64 = 0.0;
 
@@ -62,7 +66,7 @@ pub fn magnitude(tensor: &RawTensor) -> f32 {
         }
     }
 
-    sum.sqrt() as f32
+    sum.sqrt() as Float
 }
 
 pub fn transpose(tensor: &RawTensor) -> RawTensor {
@@ -91,7 +95,7 @@ pub fn divide(tensor1: &RawTensor, tensor2: &RawTensor) -> RawTensor {
     combine(tensor1, tensor2, |x1, x2| x1 / x2)
 }
 
-pub fn map(tensor: &RawTensor, operation: impl Fn(f32) -> f32) -> RawTensor {
+pub fn map(tensor: &RawTensor, operation: impl Fn(Float) -> Float) -> RawTensor {
     let mut result = Vec::new();
 
     for row in tensor {
@@ -108,7 +112,7 @@ pub fn map(tensor: &RawTensor, operation: impl Fn(f32) -> f32) -> RawTensor {
 pub fn combine(
     tensor1: &RawTensor,
     tensor2: &RawTensor,
-    operation: impl Fn(f32, f32) -> f32,
+    operation: impl Fn(Float, Float) -> Float,
 ) -> RawTensor {
     if tensor1.len() != tensor2.len() || tensor1[0].len() != tensor2[0].len() {
         panic!("Tensors must have the same dimensions");
@@ -129,9 +133,9 @@ pub fn combine(
 
 pub fn aggregate(
     tensor: &RawTensor,
-    initial_value: f32,
-    operation: impl Fn(f32, f32) -> f32,
-) -> f32 {
+    initial_value: Float,
+    operation: impl Fn(Float, Float) -> Float,
+) -> Float {
     let mut result = initial_value;
     for row in tensor {
         for element in row {
@@ -143,8 +147,8 @@ pub fn aggregate(
 
 pub fn aggregate_rows(
     tensor: &RawTensor,
-    initial_value: f32,
-    operation: impl Fn(f32, f32) -> f32,
+    initial_value: Float,
+    operation: impl Fn(Float, Float) -> Float,
 ) -> RawTensor {
     if is_empty(tensor) {
         return empty();
@@ -163,8 +167,8 @@ pub fn aggregate_rows(
 
 pub fn aggregate_columns(
     tensor: &RawTensor,
-    initial_value: f32,
-    operation: impl Fn(f32, f32) -> f32,
+    initial_value: Float,
+    operation: impl Fn(Float, Float) -> Float,
 ) -> RawTensor {
     if is_empty(tensor) {
         return empty();
@@ -217,7 +221,7 @@ pub fn adjugate(tensor: &RawTensor) -> RawTensor {
     result
 }
 
-pub fn determinant(tensor: &RawTensor) -> f32 {
+pub fn determinant(tensor: &RawTensor) -> Float {
     if rows(tensor) != columns(tensor) {
         panic!("Matrix must be square to calculate determinant");
     }
@@ -298,7 +302,7 @@ pub fn solve_linear(tensor: &RawTensor, vector: &RawTensor) -> RawTensor {
     vec![x]
 }
 
-pub fn least_norm(tensor: &RawTensor, vector: &[f32]) -> Vec<f32> {
+pub fn least_norm(tensor: &RawTensor, vector: &[Float]) -> Vec<Float> {
     let q_r = qr(&transpose(tensor));
     let q = q_r.0;
     let r = q_r.1;
@@ -313,7 +317,7 @@ pub fn least_norm(tensor: &RawTensor, vector: &[f32]) -> Vec<f32> {
     transpose(&y)[0].clone()
 }
 
-pub fn least_squares(tensor: &RawTensor, vector: &[f32]) -> Vec<f32> {
+pub fn least_squares(tensor: &RawTensor, vector: &[Float]) -> Vec<Float> {
     let is_underdetermined = rows(tensor) < columns(tensor);
 
     if is_underdetermined {
@@ -416,7 +420,7 @@ mod tests {
     #[case(&vec![vec![1.0], vec![2.0]], 2.23606797749979)]
     #[case(&vec![vec![42.0]], 42.0)]
     #[case(&vec![vec![]], 0.0)]
-    fn test_magnitude(#[case] tensor: &RawTensor, #[case] expected: f32) {
+    fn test_magnitude(#[case] tensor: &RawTensor, #[case] expected: Float) {
         assert_approx_eq!(magnitude(tensor), expected);
     }
 
@@ -447,7 +451,7 @@ mod tests {
     #[case(&vec![vec![1.0], vec![2.0]], 3.0, vec![vec![3.0], vec![6.0]])]
     #[case(&vec![vec![1.0, 2.0], vec![3.0, 4.0]], 2.0, vec![vec![2.0, 4.0], vec![6.0, 8.0]])]
     #[case(&vec![vec![]], 2.0, vec![vec![]])]
-    fn test_map(#[case] tensor: &RawTensor, #[case] scalar: f32, #[case] expected: RawTensor) {
+    fn test_map(#[case] tensor: &RawTensor, #[case] scalar: Float, #[case] expected: RawTensor) {
         assert_tensor_eq!(map(tensor, |x| x * scalar), expected);
     }
 
@@ -471,7 +475,7 @@ mod tests {
     #[case(&vec![vec![]], 0.0, vec![vec![]])]
     fn test_aggregate_rows(
         #[case] tensor: &RawTensor,
-        #[case] initial: f32,
+        #[case] initial: Float,
         #[case] expected: RawTensor,
     ) {
         assert_tensor_eq!(aggregate_rows(tensor, initial, |acc, x| acc + x), expected);
@@ -484,7 +488,7 @@ mod tests {
     #[case(&vec![vec![]], 0.0, vec![vec![]])]
     fn test_aggregate_columns(
         #[case] tensor: &RawTensor,
-        #[case] initial: f32,
+        #[case] initial: Float,
         #[case] expected: RawTensor,
     ) {
         assert_tensor_eq!(
@@ -498,14 +502,14 @@ mod tests {
     #[case(&vec![vec![1.0], vec![2.0]], 0.0, 3.0)]
     #[case(&vec![vec![1.0, 2.0], vec![3.0, 4.0]], 0.0, 10.0)]
     #[case(&vec![vec![]], 0.0, 0.0)]
-    fn test_aggregate(#[case] tensor: &RawTensor, #[case] initial: f32, #[case] expected: f32) {
+    fn test_aggregate(#[case] tensor: &RawTensor, #[case] initial: Float, #[case] expected: Float) {
         assert_approx_eq!(aggregate(tensor, initial, |acc, x| acc + x), expected);
     }
 
     #[rstest]
     #[case(&vec![vec![2.0, -3.0, 1.0], vec![2.0, 0.0, -1.0], vec![1.0, 4.0, 5.0]], 49.0)]
     #[case(&vec![vec![1.0, 2.0], vec![3.0, 4.0]], -2.0)]
-    fn test_determinant(#[case] tensor: &RawTensor, #[case] expected: f32) {
+    fn test_determinant(#[case] tensor: &RawTensor, #[case] expected: Float) {
         assert_approx_eq!(determinant(tensor), expected);
     }
 
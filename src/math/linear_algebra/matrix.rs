@@ -1,8 +1,11 @@
+use crate::math::Float;
+
 use super::{
     RawTensor, Tensor, Vector, adjugate, aggregate, aggregate_columns, aggregate_rows, combine,
     determinant, dot, inverse, magnitude, map, transpose,
 };
 
+#[derive(Debug, Clone, PartialEq)]
 pub struct Matrix {
     pub rows: usize,
     pub cols: usize,
@@ -78,7 +81,7 @@ impl Matrix {
         Matrix::from(dot(self.get_tensor(), other.get_tensor()))
     }
 
-    pub fn magnitude(&self) -> f32 {
+    pub fn magnitude(&self) -> Float {
         magnitude(self.get_tensor())
     }
 
@@ -86,30 +89,38 @@ impl Matrix {
         Matrix::from(transpose(self.get_tensor()))
     }
 
-    pub fn map(&self, operation: impl Fn(f32) -> f32) -> Matrix {
+    pub fn map(&self, operation: impl Fn(Float) -> Float) -> Matrix {
         Matrix::from(map(self.get_tensor(), operation))
     }
 
-    pub fn combine<T: Tensor>(&self, other: &T, operation: impl Fn(f32, f32) -> f32) -> Matrix {
+    pub fn combine<T: Tensor>(
+        &self,
+        other: &T,
+        operation: impl Fn(Float, Float) -> Float,
+    ) -> Matrix {
         Matrix::from(combine(self.get_tensor(), other.get_tensor(), operation))
     }
 
-    pub fn aggregate(&self, initial_value: f32, operation: impl Fn(f32, f32) -> f32) -> f32 {
+    pub fn aggregate(
+        &self,
+        initial_value: Float,
+        operation: impl Fn(Float, Float) -> Float,
+    ) -> Float {
         aggregate(self.get_tensor(), initial_value, operation)
     }
 
     pub fn aggregate_rows(
         &self,
-        initial_value: f32,
-        operation: impl Fn(f32, f32) -> f32,
+        initial_value: Float,
+        operation: impl Fn(Float, Float) -> Float,
     ) -> Matrix {
         Matrix::from(aggregate_rows(self.get_tensor(), initial_value, operation))
     }
 
     pub fn aggregate_columns(
         &self,
-        initial_value: f32,
-        operation: impl Fn(f32, f32) -> f32,
+        initial_value: Float,
+        operation: impl Fn(Float, Float) -> Float,
     ) -> Matrix {
         Matrix::from(aggregate_columns(
             self.get_tensor(),
@@ -126,11 +137,33 @@ impl Matrix {
         Matrix::from(adjugate(self.get_tensor()))
     }
 
-    pub fn determinant(&self) -> f32 {
+    pub fn determinant(&self) -> Float {
         determinant(self.get_tensor())
     }
 }
 
+/// Matrices are serialized as a list of rows (ex. `[[1.0, 2.0], [3.0, 4.0]]`)
+#[cfg(feature = "serde")]
+impl serde::Serialize for Matrix {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.data.serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Matrix {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let data = RawTensor::deserialize(deserializer)?;
+        let cols = data.first().map_or(0, |row| row.len());
+        if data.iter().any(|row| row.len() != cols) {
+            return Err(serde::de::Error::custom(
+                "every row of a matrix must have the same length",
+            ));
+        }
+        Ok(Matrix::from(data))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::assert_approx_eq;
@@ -182,4 +215,14 @@ mod tests {
         assert_approx_eq!(matrix.data[2][0], 0.0);
         assert_approx_eq!(matrix.data[2][1], 0.0);
     }
+
+    #[cfg(feature = "serde")]
+    #[rstest]
+    fn test_serde_round_trip() {
+        let matrix = Matrix::from(vec![vec![1.0, 2.0], vec![3.0, 4.0]]);
+        crate::test_helper::assert_serde_round_trip(&matrix, "[[1.0,2.0],[3.0,4.0]]");
+
+        let ragged = serde_json::from_str::<Matrix>("[[1.0,2.0],[3.0]]");
+        assert!(ragged.is_err());
+    }
 }
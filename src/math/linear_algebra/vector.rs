@@ -1,12 +1,12 @@
-use crate::math::utils::is_approximately_zero;
+use crate::math::{Float, utils::is_approximately_zero};
 
 use super::{Matrix, RawTensor, Tensor, dot, magnitude, map, transpose};
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct Vector {
-    pub x: f32,
-    pub y: f32,
-    pub z: f32,
+    pub x: Float,
+    pub y: Float,
+    pub z: Float,
     pub data: RawTensor,
 }
 
@@ -17,11 +17,11 @@ impl Tensor for Vector {
 }
 
 impl Vector {
-    pub fn new_2d(x: f32, y: f32) -> Self {
+    pub fn new_2d(x: Float, y: Float) -> Self {
         Vector::new_3d(x, y, 0.0)
     }
 
-    pub fn new_3d(x: f32, y: f32, z: f32) -> Self {
+    pub fn new_3d(x: Float, y: Float, z: Float) -> Self {
         Vector {
             x,
             y,
@@ -30,7 +30,7 @@ impl Vector {
         }
     }
 
-    pub fn from(data: Vec<f32>) -> Self {
+    pub fn from(data: Vec<Float>) -> Self {
         Vector {
             x: data.first().copied().unwrap_or(0.0),
             y: data.get(1).copied().unwrap_or(0.0),
@@ -43,7 +43,7 @@ impl Vector {
         Matrix::from(self.data.clone())
     }
 
-    pub fn dot(&self, other: &Vector) -> f32 {
+    pub fn dot(&self, other: &Vector) -> Float {
         dot(&transpose(self.get_tensor()), other.get_tensor())[0][0]
     }
 
@@ -55,11 +55,11 @@ impl Vector {
         )
     }
 
-    pub fn magnitude(&self) -> f32 {
+    pub fn magnitude(&self) -> Float {
         magnitude(self.get_tensor())
     }
 
-    pub fn map(&self, operation: impl Fn(f32) -> f32) -> Vector {
+    pub fn map(&self, operation: impl Fn(Float) -> Float) -> Vector {
         Matrix::from(map(self.get_tensor(), operation)).to_vector()
     }
 }
@@ -112,6 +112,21 @@ impl std::ops::Div for Vector {
     }
 }
 
+/// Vectors are serialized as a list of their components (ex. `[1.0, 2.0, 3.0]`)
+#[cfg(feature = "serde")]
+impl serde::Serialize for Vector {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.data.iter().flatten())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Vector {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Vec::<Float>::deserialize(deserializer).map(Vector::from)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::assert_approx_eq;
@@ -188,4 +203,11 @@ mod tests {
 
         assert_eq!(expected, cross);
     }
+
+    #[cfg(feature = "serde")]
+    #[rstest]
+    fn test_serde_round_trip() {
+        let vector = Vector::new_3d(1.0, 2.0, 3.0);
+        crate::test_helper::assert_serde_round_trip(&vector, "[1.0,2.0,3.0]");
+    }
 }
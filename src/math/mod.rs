@@ -5,3 +5,21 @@ pub mod geometry;
 pub mod linear_algebra;
 pub mod trigonometry;
 pub mod utils;
+
+/// The floating point type used throughout the crate. This is `f32` by default, which is fast on
+/// low end hardware, and `f64` when the `f64` feature is enabled.
+#[cfg(not(feature = "f64"))]
+pub type Float = f32;
+
+/// The floating point type used throughout the crate. This is `f32` by default, which is fast on
+/// low end hardware, and `f64` when the `f64` feature is enabled.
+#[cfg(feature = "f64")]
+pub type Float = f64;
+
+/// Mathematical constants for `Float`
+#[cfg(not(feature = "f64"))]
+pub use std::f32::consts;
+
+/// Mathematical constants for `Float`
+#[cfg(feature = "f64")]
+pub use std::f64::consts;
@@ -1,62 +1,64 @@
+use crate::math::Float;
+
 use crate::units::{
     angle::Angle,
     quantity::{Convertable, Quantity},
 };
 
-pub fn sin_degrees(degrees: f32) -> f32 {
+pub fn sin_degrees(degrees: Float) -> Float {
     degrees.to_radians().sin()
 }
 
-pub fn cos_degrees(degrees: f32) -> f32 {
+pub fn cos_degrees(degrees: Float) -> Float {
     degrees.to_radians().cos()
 }
 
-pub fn tan_degrees(degrees: f32) -> f32 {
+pub fn tan_degrees(degrees: Float) -> Float {
     degrees.to_radians().tan()
 }
 
-pub fn asin_degrees(value: f32) -> f32 {
+pub fn asin_degrees(value: Float) -> Float {
     value.asin().to_degrees()
 }
 
-pub fn acos_degrees(value: f32) -> f32 {
+pub fn acos_degrees(value: Float) -> Float {
     value.acos().to_degrees()
 }
 
-pub fn atan_degrees(value: f32) -> f32 {
+pub fn atan_degrees(value: Float) -> Float {
     value.atan().to_degrees()
 }
 
-pub fn atan2_degrees(y: f32, x: f32) -> f32 {
+pub fn atan2_degrees(y: Float, x: Float) -> Float {
     y.atan2(x).to_degrees()
 }
 
 // Trig functions that operator on Quantity<Angle>
-pub fn sin(angle: Quantity<Angle>) -> f32 {
+pub fn sin(angle: Quantity<Angle>) -> Float {
     angle.convert(Angle::Radians).amount.sin()
 }
 
-pub fn cos(angle: Quantity<Angle>) -> f32 {
+pub fn cos(angle: Quantity<Angle>) -> Float {
     angle.convert(Angle::Radians).amount.cos()
 }
 
-pub fn tan(angle: Quantity<Angle>) -> f32 {
+pub fn tan(angle: Quantity<Angle>) -> Float {
     angle.convert(Angle::Radians).amount.tan()
 }
 
-pub fn asin(value: f32) -> Quantity<Angle> {
+pub fn asin(value: Float) -> Quantity<Angle> {
     Quantity::new(value.asin(), Angle::Radians)
 }
 
-pub fn acos(value: f32) -> Quantity<Angle> {
+pub fn acos(value: Float) -> Quantity<Angle> {
     Quantity::new(value.acos(), Angle::Radians)
 }
 
-pub fn atan(value: f32) -> Quantity<Angle> {
+pub fn atan(value: Float) -> Quantity<Angle> {
     Quantity::new(value.atan(), Angle::Radians)
 }
 
-pub fn atan2(y: f32, x: f32) -> Quantity<Angle> {
+pub fn atan2(y: Float, x: Float) -> Quantity<Angle> {
     Quantity::new(y.atan2(x), Angle::Radians)
 }
 
@@ -71,7 +73,7 @@ mod tests {
     #[case(30.0, 0.5)]
     #[case(90.0, 1.0)]
     #[case(45.0, 0.7071)]
-    fn test_sin_degrees(#[case] input: f32, #[case] expected: f32) {
+    fn test_sin_degrees(#[case] input: Float, #[case] expected: Float) {
         assert_approx_eq!(sin_degrees(input), expected);
     }
 
@@ -79,14 +81,14 @@ mod tests {
     #[case(0.0, 1.0)]
     #[case(60.0, 0.5)]
     #[case(45.0, 0.7071)]
-    fn test_cos_degrees(#[case] input: f32, #[case] expected: f32) {
+    fn test_cos_degrees(#[case] input: Float, #[case] expected: Float) {
         assert_approx_eq!(cos_degrees(input), expected);
     }
 
     #[rstest]
     #[case(45.0, 1.0)]
     #[case(0.0, 0.0)]
-    fn test_tan_degrees(#[case] input: f32, #[case] expected: f32) {
+    fn test_tan_degrees(#[case] input: Float, #[case] expected: Float) {
         assert_approx_eq!(tan_degrees(input), expected);
     }
 
@@ -94,7 +96,7 @@ mod tests {
     #[case(1.0, 90.0)]
     #[case(0.0, 0.0)]
     #[case(0.5, 30.0)]
-    fn test_asin_degrees(#[case] input: f32, #[case] expected: f32) {
+    fn test_asin_degrees(#[case] input: Float, #[case] expected: Float) {
         assert_approx_eq!(asin_degrees(input), expected);
     }
 
@@ -102,7 +104,7 @@ mod tests {
     #[case(1.0, 0.0)]
     #[case(0.5, 60.0)]
     #[case(0.0, 90.0)]
-    fn test_acos_degrees(#[case] input: f32, #[case] expected: f32) {
+    fn test_acos_degrees(#[case] input: Float, #[case] expected: Float) {
         assert_approx_eq!(acos_degrees(input), expected);
     }
 
@@ -110,7 +112,7 @@ mod tests {
     #[case(0.0, 0.0)]
     #[case(1.0, 45.0)]
     #[case(0.5, 26.565052)]
-    fn test_atan_degrees(#[case] input: f32, #[case] expected: f32) {
+    fn test_atan_degrees(#[case] input: Float, #[case] expected: Float) {
         assert_approx_eq!(atan_degrees(input), expected);
     }
 
@@ -118,7 +120,7 @@ mod tests {
     #[case(1.0, 1.0, 45.0)]
     #[case(0.0, 1.0, 0.0)]
     #[case(1.0, 0.0, 90.0)]
-    fn test_atan2_degrees(#[case] y: f32, #[case] x: f32, #[case] expected: f32) {
+    fn test_atan2_degrees(#[case] y: Float, #[case] x: Float, #[case] expected: Float) {
         assert_approx_eq!(atan2_degrees(y, x), expected);
     }
 
@@ -126,7 +128,7 @@ mod tests {
     #[case(30.0, 0.5)]
     #[case(90.0, 1.0)]
     #[case(45.0, 0.7071)]
-    fn test_sin_quantity(#[case] input: f32, #[case] expected: f32) {
+    fn test_sin_quantity(#[case] input: Float, #[case] expected: Float) {
         let angle = Quantity::new(input, Angle::Degrees);
         assert_approx_eq!(sin(angle), expected);
     }
@@ -135,7 +137,7 @@ mod tests {
     #[case(0.0, 1.0)]
     #[case(60.0, 0.5)]
     #[case(45.0, 0.7071)]
-    fn test_cos_quantity(#[case] input: f32, #[case] expected: f32) {
+    fn test_cos_quantity(#[case] input: Float, #[case] expected: Float) {
         let angle = Quantity::new(input, Angle::Degrees);
         assert_approx_eq!(cos(angle), expected);
     }
@@ -143,7 +145,7 @@ mod tests {
     #[rstest]
     #[case(45.0, 1.0)]
     #[case(0.0, 0.0)]
-    fn test_tan_quantity(#[case] input: f32, #[case] expected: f32) {
+    fn test_tan_quantity(#[case] input: Float, #[case] expected: Float) {
         let angle = Quantity::new(input, Angle::Degrees);
         assert_approx_eq!(tan(angle), expected);
     }
@@ -152,7 +154,7 @@ mod tests {
     #[case(1.0, 90.0)]
     #[case(0.0, 0.0)]
     #[case(0.5, 30.0)]
-    fn test_asin_quantity(#[case] input: f32, #[case] expected: f32) {
+    fn test_asin_quantity(#[case] input: Float, #[case] expected: Float) {
         let result = asin(input).convert(Angle::Degrees);
         assert_approx_eq!(result.amount, expected);
     }
@@ -161,7 +163,7 @@ mod tests {
     #[case(1.0, 0.0)]
     #[case(0.5, 60.0)]
     #[case(0.0, 90.0)]
-    fn test_acos_quantity(#[case] input: f32, #[case] expected: f32) {
+    fn test_acos_quantity(#[case] input: Float, #[case] expected: Float) {
         let result = acos(input).convert(Angle::Degrees);
         assert_approx_eq!(result.amount, expected);
     }
@@ -170,7 +172,7 @@ mod tests {
     #[case(0.0, 0.0)]
     #[case(1.0, 45.0)]
     #[case(0.5, 26.565052)]
-    fn test_atan_quantity(#[case] input: f32, #[case] expected: f32) {
+    fn test_atan_quantity(#[case] input: Float, #[case] expected: Float) {
         let result = atan(input).convert(Angle::Degrees);
         assert_approx_eq!(result.amount, expected);
     }
@@ -179,7 +181,7 @@ mod tests {
     #[case(1.0, 1.0, 45.0)]
     #[case(0.0, 1.0, 0.0)]
     #[case(1.0, 0.0, 90.0)]
-    fn test_atan2_quantity(#[case] y: f32, #[case] x: f32, #[case] expected: f32) {
+    fn test_atan2_quantity(#[case] y: Float, #[case] x: Float, #[case] expected: Float) {
         let result = atan2(y, x).convert(Angle::Degrees);
         assert_approx_eq!(result.amount, expected);
     }
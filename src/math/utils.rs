@@ -1,8 +1,10 @@
+use crate::math::Float;
+
 use super::arithmetic::round_places;
 
-pub const EPSILON: f32 = 1e-5;
+pub const EPSILON: Float = 1e-5;
 
-pub fn clamp(value: f32, min: f32, max: f32) -> f32 {
+pub fn clamp(value: Float, min: Float, max: Float) -> Float {
     if value > max {
         return max;
     }
@@ -12,7 +14,7 @@ pub fn clamp(value: f32, min: f32, max: f32) -> f32 {
     value
 }
 
-pub fn wrap(value: f32, min: f32, max: f32) -> f32 {
+pub fn wrap(value: Float, min: Float, max: Float) -> Float {
     let range = max - min;
     if value < min {
         return max - (min - value) % range;
@@ -23,7 +25,7 @@ pub fn wrap(value: f32, min: f32, max: f32) -> f32 {
     value
 }
 
-pub fn lerp(percent: f32, start: f32, end: f32, should_clamp: bool) -> f32 {
+pub fn lerp(percent: Float, start: Float, end: Float, should_clamp: bool) -> Float {
     let value = start + (end - start) * percent;
 
     if should_clamp {
@@ -33,7 +35,7 @@ pub fn lerp(percent: f32, start: f32, end: f32, should_clamp: bool) -> f32 {
     }
 }
 
-pub fn norm(value: f32, minimum: f32, maximum: f32, should_clamp: bool) -> f32 {
+pub fn norm(value: Float, minimum: Float, maximum: Float, should_clamp: bool) -> Float {
     let range = maximum - minimum;
     if range == 0.0 {
         return 0.0;
@@ -48,18 +50,18 @@ pub fn norm(value: f32, minimum: f32, maximum: f32, should_clamp: bool) -> f32 {
 }
 
 pub fn map(
-    value: f32,
-    original_min: f32,
-    original_max: f32,
-    new_min: f32,
-    new_max: f32,
+    value: Float,
+    original_min: Float,
+    original_max: Float,
+    new_min: Float,
+    new_max: Float,
     should_clamp: bool,
-) -> f32 {
+) -> Float {
     let normal = norm(value, original_min, original_max, should_clamp);
     lerp(normal, new_min, new_max, should_clamp)
 }
 
-pub fn is_approximately_equal(value1: f32, value2: f32, precision: Option<f32>) -> bool {
+pub fn is_approximately_equal(value1: Float, value2: Float, precision: Option<Float>) -> bool {
     let actual_precision = match precision {
         Some(precision) => precision,
         None => EPSILON,
@@ -67,12 +69,12 @@ pub fn is_approximately_equal(value1: f32, value2: f32, precision: Option<f32>)
     (value1 - value2).abs() <= actual_precision
 }
 
-pub fn is_approximately_zero(value: f32) -> bool {
+pub fn is_approximately_zero(value: Float) -> bool {
     value.abs() <= EPSILON
 }
 
 /// This will round to the desired number of places if the value is approximately equal at 2 places below the desired. This can be used to correct floating point errors.
-pub fn approximate_round(value: f32, desired_places: i32) -> f32 {
+pub fn approximate_round(value: Float, desired_places: i32) -> Float {
     let rounded = round_places(value, desired_places);
     let extra_rounded = round_places(value, desired_places + 2);
     if is_approximately_equal(extra_rounded, rounded, None) {
@@ -98,7 +100,12 @@ mod tests {
     #[case(4.0, 2.0, 5.0, 4.0)]
     #[case(1.0, 2.0, 5.0, 2.0)]
     #[case(6.0, 2.0, 5.0, 5.0)]
-    fn test_clamp(#[case] value: f32, #[case] min: f32, #[case] max: f32, #[case] expected: f32) {
+    fn test_clamp(
+        #[case] value: Float,
+        #[case] min: Float,
+        #[case] max: Float,
+        #[case] expected: Float,
+    ) {
         assert_approx_eq!(expected, clamp(value, min, max));
     }
 
@@ -116,7 +123,12 @@ mod tests {
     #[case(-1800.0, 0.0, 360.0, 360.0)]
     #[case(1799.0, 0.0, 360.0, 359.0)]
     #[case(-1799.0, 0.0, 360.0, 1.0)]
-    fn test_wrap(#[case] value: f32, #[case] min: f32, #[case] max: f32, #[case] expected: f32) {
+    fn test_wrap(
+        #[case] value: Float,
+        #[case] min: Float,
+        #[case] max: Float,
+        #[case] expected: Float,
+    ) {
         assert_approx_eq!(expected, wrap(value, min, max));
     }
 
@@ -134,11 +146,11 @@ mod tests {
     #[case(0.5, 0.0, 1.0, true, 0.5)]
     #[case(2.0, 0.0, 1.0, true, 1.0)]
     fn test_norm(
-        #[case] value: f32,
-        #[case] min: f32,
-        #[case] max: f32,
+        #[case] value: Float,
+        #[case] min: Float,
+        #[case] max: Float,
         #[case] should_clamp: bool,
-        #[case] expected: f32,
+        #[case] expected: Float,
     ) {
         assert_approx_eq!(expected, norm(value, min, max, should_clamp));
     }
@@ -157,11 +169,11 @@ mod tests {
     #[case(0.5, 0.0, 1.0, true, 0.5)]
     #[case(2.0, 0.0, 1.0, true, 1.0)]
     fn test_lerp(
-        #[case] percent: f32,
-        #[case] start: f32,
-        #[case] end: f32,
+        #[case] percent: Float,
+        #[case] start: Float,
+        #[case] end: Float,
         #[case] should_clamp: bool,
-        #[case] expected: f32,
+        #[case] expected: Float,
     ) {
         assert_approx_eq!(expected, lerp(percent, start, end, should_clamp));
     }
@@ -180,13 +192,13 @@ mod tests {
     #[case(0.5, 0.0, 1.0, 2.0, 4.0, true, 3.0)]
     #[case(2.0, 0.0, 1.0, 2.0, 4.0, true, 4.0)]
     fn test_map(
-        #[case] value: f32,
-        #[case] original_min: f32,
-        #[case] original_max: f32,
-        #[case] new_min: f32,
-        #[case] new_max: f32,
+        #[case] value: Float,
+        #[case] original_min: Float,
+        #[case] original_max: Float,
+        #[case] new_min: Float,
+        #[case] new_max: Float,
         #[case] should_clamp: bool,
-        #[case] expected: f32,
+        #[case] expected: Float,
     ) {
         assert_approx_eq!(
             expected,
@@ -210,9 +222,9 @@ mod tests {
     #[case(10000.0, 10000.0 + EPSILON / 2.0, None, true)]
     #[case(-0.1, -0.11, Some(0.1), true)]
     fn test_is_approximately_equal(
-        #[case] value1: f32,
-        #[case] value2: f32,
-        #[case] precision: Option<f32>,
+        #[case] value1: Float,
+        #[case] value2: Float,
+        #[case] precision: Option<Float>,
         #[case] expected: bool,
     ) {
         assert_eq!(expected, is_approximately_equal(value1, value2, precision));
@@ -224,7 +236,7 @@ mod tests {
     #[case(EPSILON * 2.0, false)]
     #[case(-EPSILON / 2.0, true)]
     #[case(-EPSILON * 2.0, false)]
-    fn test_is_approximately_zero(#[case] value: f32, #[case] expected: bool) {
+    fn test_is_approximately_zero(#[case] value: Float, #[case] expected: bool) {
         assert_eq!(expected, is_approximately_zero(value));
     }
 
@@ -237,7 +249,7 @@ mod tests {
     #[case(1.234567, 2, 1.234567)]
     #[case(1.234567, 3, 1.234567)]
     #[case(-0.0, 7, 0.0)]
-    fn test_approximate_round(#[case] value: f32, #[case] places: i32, #[case] expected: f32) {
+    fn test_approximate_round(#[case] value: Float, #[case] places: i32, #[case] expected: Float) {
         assert_approx_eq!(expected, approximate_round(value, places));
     }
 }
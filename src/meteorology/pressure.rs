@@ -1,17 +1,21 @@
-use crate::units::{distance::Distance, pressure::Pressure, quantity::Quantity};
+use crate::units::{
+    dimension::{self, Measure},
+    distance::Distance,
+    pressure::Pressure,
+    quantity::Quantity,
+};
 
 // TODO: Factor in temperature
 pub fn get_sea_level_pressure(
     pressure: &Quantity<Pressure>,
     altitude: &Quantity<Distance>,
 ) -> Quantity<Pressure> {
-    let hpa = pressure.amount;
-    let meters = altitude.amount;
-    let sea_level_hpa = hpa * (1.0 - meters / 44330.0).powf(-5.255);
-    Quantity {
-        amount: sea_level_hpa,
-        units: Pressure::Hectopascals,
-    }
+    let pressure: Measure<dimension::Pressure> = (*pressure).into();
+    let altitude: Measure<dimension::Length> = (*altitude).into();
+    let scale_height = Measure::<dimension::Length>::new(44330.0);
+    let ratio: Measure<dimension::Dimensionless> = altitude / scale_height;
+    let sea_level_pressure = pressure * (1.0 - ratio.value).powf(-5.255);
+    sea_level_pressure.to_quantity(Pressure::Hectopascals)
 }
 
 #[cfg(test)]
@@ -37,6 +41,11 @@ mod tests {
         Quantity { amount: 200.0, units: Distance::Meters },
         Quantity { amount: 1003.56573, units: Pressure::Hectopascals }
     )]
+    #[case(
+        Quantity { amount: 28.9391, units: Pressure::InchesHg },
+        Quantity { amount: 656.168, units: Distance::Feet },
+        Quantity { amount: 1003.56573, units: Pressure::Hectopascals }
+    )]
     fn can_get_sea_level_pressure(
         #[case] pressure: Quantity<Pressure>,
         #[case] altitude: Quantity<Distance>,
@@ -0,0 +1,155 @@
+use crate::{
+    geology::{
+        EARTH_MEAN_RADIUS,
+        geodesy::{haversine_distance, spherical_bearing},
+    },
+    units::{
+        bearing::Bearing,
+        coordinate::Coordinate,
+        distance::Distance,
+        quantity::{Convertable, Quantity},
+        speed::Speed,
+        time::Time,
+    },
+};
+
+/// The direction, distance and estimated time to a destination
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct NavigationVector {
+    /// The true bearing to start out on
+    pub bearing: Bearing,
+    pub distance: Quantity<Distance>,
+    /// The time to reach the destination at the current speed, or None if not moving
+    pub eta: Option<Quantity<Time>>,
+}
+
+impl NavigationVector {
+    /// Returns the time of arrival, in milliseconds since the Unix epoch, given the current time
+    pub fn arrival_time(&self, now: i64) -> Option<i64> {
+        self.eta
+            .map(|eta| now + eta.convert(Time::Milliseconds).amount.round() as i64)
+    }
+}
+
+/// How far off a planned leg the current position is
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CourseDeviation {
+    /// The distance from the course, positive to the right and negative to the left
+    pub cross_track: Quantity<Distance>,
+    /// The distance along the course from the start to the closest point, negative if behind the start
+    pub along_track: Quantity<Distance>,
+    /// The distance left along the course to the end of the leg
+    pub remaining: Quantity<Distance>,
+}
+
+/// Returns the bearing, distance and estimated time from the position to the destination. The ETA is
+/// based on the current speed over ground.
+pub fn navigate(
+    position: &Coordinate,
+    destination: &Coordinate,
+    speed: &Quantity<Speed>,
+) -> NavigationVector {
+    let distance = position.distance_to(destination);
+    let meters_per_second = speed.convert(Speed::MetersPerSecond).amount;
+    let eta = if meters_per_second > 0.0 {
+        Some(Quantity::new(
+            distance.amount / meters_per_second,
+            Time::Seconds,
+        ))
+    } else {
+        None
+    };
+    NavigationVector {
+        bearing: position.bearing_to(destination),
+        distance,
+        eta,
+    }
+}
+
+/// Returns the cross-track and along-track distances of the position from the great circle leg between
+/// the start and end, on a sphere with the Earth's mean radius
+pub fn course_deviation(
+    position: &Coordinate,
+    start: &Coordinate,
+    end: &Coordinate,
+) -> CourseDeviation {
+    let angular_distance = haversine_distance(start, position) / EARTH_MEAN_RADIUS;
+    let course = spherical_bearing(start, end).to_radians();
+    let bearing = spherical_bearing(start, position).to_radians();
+    let relative_bearing = bearing - course;
+
+    let cross_track = (angular_distance.sin() * relative_bearing.sin())
+        .clamp(-1.0, 1.0)
+        .asin();
+    // tan(along track) = tan(distance) × cos(relative bearing), which stays precise for short distances
+    let along_track =
+        (angular_distance.sin() * relative_bearing.cos()).atan2(angular_distance.cos());
+    let along_track = along_track * EARTH_MEAN_RADIUS;
+    let leg_length = haversine_distance(start, end);
+
+    CourseDeviation {
+        cross_track: Quantity::new(cross_track * EARTH_MEAN_RADIUS, Distance::Meters),
+        along_track: Quantity::new(along_track, Distance::Meters),
+        remaining: Quantity::new(leg_length - along_track, Distance::Meters),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::{assert_approx_eq, math::Float};
+
+    #[test]
+    fn test_navigate() {
+        let vector = navigate(
+            &Coordinate::new(0.0, 0.0),
+            &Coordinate::new(0.0, 1.0),
+            &Quantity::new(1.5, Speed::MetersPerSecond),
+        );
+        assert_approx_eq!(vector.bearing.degrees, 90.0, 1e-3);
+        assert_approx_eq!(vector.distance.amount, 111319.49, 2.0);
+        let eta = vector.eta.unwrap().convert(Time::Seconds).amount;
+        assert_approx_eq!(eta, 74213.0, 2.0);
+        assert_approx_eq!(
+            vector.arrival_time(1_000_000).unwrap() as Float,
+            1_000_000.0 + 74213.0 * 1000.0,
+            2000.0
+        );
+    }
+
+    #[test]
+    fn test_navigate_stopped() {
+        let vector = navigate(
+            &Coordinate::new(0.0, 0.0),
+            &Coordinate::new(1.0, 0.0),
+            &Quantity::new(0.0, Speed::MetersPerSecond),
+        );
+        assert_approx_eq!(vector.bearing.degrees, 0.0, 1e-3);
+        assert_eq!(vector.eta, None);
+        assert_eq!(vector.arrival_time(0), None);
+    }
+
+    #[rstest]
+    #[case(Coordinate::new(0.01, 0.5), -1111.95, 55597.54)]
+    #[case(Coordinate::new(-0.01, -0.1), 1111.95, -11119.51)]
+    #[case(Coordinate::new(0.0, 1.5), 0.0, 166792.6)]
+    #[case(Coordinate::new(0.0, 0.0), 0.0, 0.0)]
+    fn test_course_deviation(
+        #[case] position: Coordinate,
+        #[case] cross_track: Float,
+        #[case] along_track: Float,
+    ) {
+        let deviation = course_deviation(
+            &position,
+            &Coordinate::new(0.0, 0.0),
+            &Coordinate::new(0.0, 1.0),
+        );
+        assert_approx_eq!(deviation.cross_track.amount, cross_track, 1.0);
+        assert_approx_eq!(deviation.along_track.amount, along_track, 2.0);
+        assert_approx_eq!(deviation.remaining.amount, 111195.08 - along_track, 2.0);
+    }
+}
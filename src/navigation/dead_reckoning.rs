@@ -0,0 +1,92 @@
+use crate::{
+    math::Float,
+    units::{bearing::Bearing, coordinate::Coordinate, distance::Distance, quantity::Quantity},
+};
+
+/// A straight leg travelled on a constant bearing, such as from a compass heading and step count
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Leg {
+    pub bearing: Bearing,
+    pub distance: Quantity<Distance>,
+}
+
+impl Leg {
+    pub fn new(bearing: Bearing, distance: Quantity<Distance>) -> Self {
+        Leg { bearing, distance }
+    }
+
+    /// Creates a leg from a number of steps (or paces) of the stride length
+    pub fn from_steps(bearing: Bearing, steps: u32, stride: &Quantity<Distance>) -> Self {
+        Leg::new(bearing, *stride * steps as Float)
+    }
+}
+
+/// Returns the estimated position after travelling the legs from the start. Bearings are true bearings,
+/// so magnetic compass readings should be corrected for declination first.
+pub fn dead_reckon(start: &Coordinate, legs: &[Leg]) -> Coordinate {
+    legs.iter().fold(*start, |position, leg| {
+        position.destination(&leg.distance, &leg.bearing)
+    })
+}
+
+/// Returns the start and the estimated position at the end of each leg
+pub fn dead_reckon_track(start: &Coordinate, legs: &[Leg]) -> Vec<Coordinate> {
+    let mut positions = Vec::with_capacity(legs.len() + 1);
+    positions.push(*start);
+    for leg in legs {
+        let position = positions[positions.len() - 1].destination(&leg.distance, &leg.bearing);
+        positions.push(position);
+    }
+    positions
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{assert_approx_eq, units::quantity::Convertable};
+
+    #[test]
+    fn test_dead_reckon() {
+        let legs = [
+            Leg::new(Bearing::new(0.0), Quantity::new(1.0, Distance::Kilometers)),
+            Leg::new(Bearing::new(90.0), Quantity::new(1000.0, Distance::Meters)),
+        ];
+        let start = Coordinate::new(0.0, 0.0);
+        let position = dead_reckon(&start, &legs);
+        assert_approx_eq!(position.latitude, 1000.0 / 110574.39, 2e-5);
+        assert_approx_eq!(position.longitude, 1000.0 / 111319.49, 2e-5);
+
+        let track = dead_reckon_track(&start, &legs);
+        assert_eq!(track.len(), 3);
+        assert_eq!(track[0], start);
+        assert_eq!(track[2], position);
+
+        assert_eq!(dead_reckon(&start, &[]), start);
+    }
+
+    #[test]
+    fn test_dead_reckon_out_and_back() {
+        let start = Coordinate::new(45.0, -70.0);
+        let legs = [
+            Leg::new(Bearing::new(30.0), Quantity::new(2.0, Distance::Kilometers)),
+            Leg::new(
+                Bearing::new(210.0),
+                Quantity::new(2.0, Distance::Kilometers),
+            ),
+        ];
+        let position = dead_reckon(&start, &legs);
+        assert_approx_eq!(position.distance_to(&start).amount, 0.0, 1.0);
+    }
+
+    #[test]
+    fn test_leg_from_steps() {
+        let leg = Leg::from_steps(
+            Bearing::new(45.0),
+            1300,
+            &Quantity::new(0.77, Distance::Meters),
+        );
+        assert_eq!(leg.bearing, Bearing::new(45.0));
+        assert_approx_eq!(leg.distance.convert(Distance::Meters).amount, 1001.0, 1e-3);
+    }
+}
@@ -0,0 +1,452 @@
+use crate::{geology::geo_polygon::GeoPolygon, math::Float, units::coordinate::Coordinate};
+
+use super::{
+    gpx::format_timestamp,
+    json::{JsonValue, parse_json},
+    path::{Path, Waypoint},
+};
+
+/// A GeoJSON geometry. Elevations (the third value of a position) are not kept.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Geometry {
+    Point(Coordinate),
+    MultiPoint(Vec<Coordinate>),
+    LineString(Vec<Coordinate>),
+    MultiLineString(Vec<Vec<Coordinate>>),
+    /// The outer boundary followed by any holes. The rings are not closed (the first vertex is not
+    /// repeated at the end).
+    Polygon(Vec<GeoPolygon>),
+    MultiPolygon(Vec<Vec<GeoPolygon>>),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Feature {
+    pub geometry: Option<Geometry>,
+    pub properties: Vec<(String, JsonValue)>,
+}
+
+impl Feature {
+    pub fn new(geometry: Geometry) -> Self {
+        Feature {
+            geometry: Some(geometry),
+            properties: vec![],
+        }
+    }
+
+    pub fn property(&self, key: &str) -> Option<&JsonValue> {
+        self.properties
+            .iter()
+            .find(|(name, _)| name == key)
+            .map(|(_, value)| value)
+    }
+}
+
+impl From<&Waypoint> for Feature {
+    /// Creates a point with the name, description, elevation and time (RFC 3339) as properties
+    fn from(waypoint: &Waypoint) -> Self {
+        let mut feature = Feature::new(Geometry::Point(waypoint.coordinate));
+        if let Some(name) = &waypoint.name {
+            feature
+                .properties
+                .push(("name".to_string(), name.as_str().into()));
+        }
+        if let Some(description) = &waypoint.description {
+            feature
+                .properties
+                .push(("description".to_string(), description.as_str().into()));
+        }
+        if let Some(elevation) = waypoint.elevation {
+            feature
+                .properties
+                .push(("elevation".to_string(), number(elevation)));
+        }
+        if let Some(time) = waypoint.time {
+            feature
+                .properties
+                .push(("time".to_string(), format_timestamp(time).as_str().into()));
+        }
+        feature
+    }
+}
+
+impl From<&Path> for Feature {
+    /// Creates a line string with the name as a property
+    fn from(path: &Path) -> Self {
+        let mut feature = Feature::new(Geometry::LineString(path.coordinates()));
+        if let Some(name) = &path.name {
+            feature
+                .properties
+                .push(("name".to_string(), name.as_str().into()));
+        }
+        feature
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FeatureCollection {
+    pub features: Vec<Feature>,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct InvalidGeoJson(pub String);
+
+impl std::fmt::Display for InvalidGeoJson {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "invalid geojson: {}", self.0)
+    }
+}
+
+impl std::error::Error for InvalidGeoJson {}
+
+/// Reads a GeoJSON feature collection. A single feature or geometry is read as a collection of one
+/// feature.
+pub fn parse_geojson(geojson: &str) -> Result<FeatureCollection, InvalidGeoJson> {
+    let value = parse_json(geojson).map_err(|error| InvalidGeoJson(error.0))?;
+    match type_of(&value)? {
+        "FeatureCollection" => {
+            let features = value
+                .get("features")
+                .and_then(JsonValue::as_array)
+                .ok_or_else(|| InvalidGeoJson("missing features".to_string()))?;
+            Ok(FeatureCollection {
+                features: features
+                    .iter()
+                    .map(parse_feature)
+                    .collect::<Result<_, _>>()?,
+            })
+        }
+        "Feature" => Ok(FeatureCollection {
+            features: vec![parse_feature(&value)?],
+        }),
+        _ => Ok(FeatureCollection {
+            features: vec![Feature::new(parse_geometry(&value)?)],
+        }),
+    }
+}
+
+/// Writes the features as a GeoJSON feature collection
+pub fn write_geojson(collection: &FeatureCollection) -> String {
+    let features = collection.features.iter().map(feature_to_json).collect();
+    JsonValue::Object(vec![
+        ("type".to_string(), "FeatureCollection".into()),
+        ("features".to_string(), JsonValue::Array(features)),
+    ])
+    .to_string()
+}
+
+fn type_of(value: &JsonValue) -> Result<&str, InvalidGeoJson> {
+    value
+        .get("type")
+        .and_then(JsonValue::as_str)
+        .ok_or_else(|| InvalidGeoJson("missing type".to_string()))
+}
+
+fn parse_feature(value: &JsonValue) -> Result<Feature, InvalidGeoJson> {
+    let feature_type = type_of(value)?;
+    if feature_type != "Feature" {
+        return Err(InvalidGeoJson(format!(
+            "expected a Feature but found '{feature_type}'"
+        )));
+    }
+    let geometry = match value.get("geometry") {
+        None | Some(JsonValue::Null) => None,
+        Some(geometry) => Some(parse_geometry(geometry)?),
+    };
+    let properties = match value.get("properties") {
+        Some(JsonValue::Object(members)) => members.clone(),
+        None | Some(JsonValue::Null) => vec![],
+        Some(_) => return Err(InvalidGeoJson("properties must be an object".to_string())),
+    };
+    Ok(Feature {
+        geometry,
+        properties,
+    })
+}
+
+fn parse_geometry(value: &JsonValue) -> Result<Geometry, InvalidGeoJson> {
+    let coordinates = value
+        .get("coordinates")
+        .ok_or_else(|| InvalidGeoJson("missing coordinates".to_string()))?;
+    let geometry = match type_of(value)? {
+        "Point" => Geometry::Point(parse_position(coordinates)?),
+        "MultiPoint" => Geometry::MultiPoint(parse_positions(coordinates)?),
+        "LineString" => Geometry::LineString(parse_positions(coordinates)?),
+        "MultiLineString" => Geometry::MultiLineString(
+            array(coordinates)?
+                .iter()
+                .map(parse_positions)
+                .collect::<Result<_, _>>()?,
+        ),
+        "Polygon" => Geometry::Polygon(parse_rings(coordinates)?),
+        "MultiPolygon" => Geometry::MultiPolygon(
+            array(coordinates)?
+                .iter()
+                .map(parse_rings)
+                .collect::<Result<_, _>>()?,
+        ),
+        other => {
+            return Err(InvalidGeoJson(format!(
+                "unsupported geometry type '{other}'"
+            )));
+        }
+    };
+    Ok(geometry)
+}
+
+fn array(value: &JsonValue) -> Result<&[JsonValue], InvalidGeoJson> {
+    value
+        .as_array()
+        .ok_or_else(|| InvalidGeoJson(format!("expected an array but found {value}")))
+}
+
+fn parse_position(value: &JsonValue) -> Result<Coordinate, InvalidGeoJson> {
+    let invalid = || InvalidGeoJson(format!("invalid position {value}"));
+    let values = array(value)?;
+    if values.len() < 2 {
+        return Err(invalid());
+    }
+    let longitude = values[0].as_f64().ok_or_else(invalid)?;
+    let latitude = values[1].as_f64().ok_or_else(invalid)?;
+    if !(-90.0..=90.0).contains(&latitude) || !(-180.0..=180.0).contains(&longitude) {
+        return Err(invalid());
+    }
+    Ok(Coordinate::new(latitude as Float, longitude as Float))
+}
+
+fn parse_positions(value: &JsonValue) -> Result<Vec<Coordinate>, InvalidGeoJson> {
+    array(value)?.iter().map(parse_position).collect()
+}
+
+fn parse_rings(value: &JsonValue) -> Result<Vec<GeoPolygon>, InvalidGeoJson> {
+    array(value)?
+        .iter()
+        .map(|ring| {
+            let mut vertices = parse_positions(ring)?;
+            if vertices.len() > 1 && vertices.first() == vertices.last() {
+                vertices.pop();
+            }
+            Ok(GeoPolygon::new(vertices))
+        })
+        .collect()
+}
+
+fn feature_to_json(feature: &Feature) -> JsonValue {
+    JsonValue::Object(vec![
+        ("type".to_string(), "Feature".into()),
+        (
+            "geometry".to_string(),
+            feature
+                .geometry
+                .as_ref()
+                .map(geometry_to_json)
+                .unwrap_or(JsonValue::Null),
+        ),
+        (
+            "properties".to_string(),
+            JsonValue::Object(feature.properties.clone()),
+        ),
+    ])
+}
+
+fn geometry_to_json(geometry: &Geometry) -> JsonValue {
+    let (geometry_type, coordinates) = match geometry {
+        Geometry::Point(coordinate) => ("Point", position_to_json(coordinate)),
+        Geometry::MultiPoint(coordinates) => ("MultiPoint", positions_to_json(coordinates)),
+        Geometry::LineString(coordinates) => ("LineString", positions_to_json(coordinates)),
+        Geometry::MultiLineString(lines) => (
+            "MultiLineString",
+            JsonValue::Array(lines.iter().map(|line| positions_to_json(line)).collect()),
+        ),
+        Geometry::Polygon(rings) => ("Polygon", rings_to_json(rings)),
+        Geometry::MultiPolygon(polygons) => (
+            "MultiPolygon",
+            JsonValue::Array(polygons.iter().map(|rings| rings_to_json(rings)).collect()),
+        ),
+    };
+    JsonValue::Object(vec![
+        ("type".to_string(), geometry_type.into()),
+        ("coordinates".to_string(), coordinates),
+    ])
+}
+
+fn position_to_json(coordinate: &Coordinate) -> JsonValue {
+    JsonValue::Array(vec![
+        number(coordinate.longitude),
+        number(coordinate.latitude),
+    ])
+}
+
+#[allow(clippy::unnecessary_cast)]
+fn number(value: Float) -> JsonValue {
+    JsonValue::Number(value as f64)
+}
+
+fn positions_to_json(coordinates: &[Coordinate]) -> JsonValue {
+    JsonValue::Array(coordinates.iter().map(position_to_json).collect())
+}
+
+/// GeoJSON rings are closed by repeating the first position
+fn rings_to_json(rings: &[GeoPolygon]) -> JsonValue {
+    JsonValue::Array(
+        rings
+            .iter()
+            .map(|ring| {
+                let mut positions: Vec<JsonValue> =
+                    ring.vertices.iter().map(position_to_json).collect();
+                if let Some(first) = ring.vertices.first() {
+                    positions.push(position_to_json(first));
+                }
+                JsonValue::Array(positions)
+            })
+            .collect(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    const SAMPLE: &str = r#"{
+        "type": "FeatureCollection",
+        "features": [
+            {"type": "Feature", "geometry": {"type": "Point", "coordinates": [102.0, 0.5]}, "properties": {"prop0": "value0"}},
+            {"type": "Feature", "geometry": {"type": "LineString", "coordinates": [[102.0, 0.0], [103.0, 1.0, 25.0], [104.0, 0.0]]}, "properties": null},
+            {"type": "Feature", "geometry": {"type": "Polygon", "coordinates": [
+                [[100.0, 0.0], [101.0, 0.0], [101.0, 1.0], [100.0, 1.0], [100.0, 0.0]],
+                [[100.2, 0.2], [100.8, 0.2], [100.8, 0.8], [100.2, 0.2]]
+            ]}, "properties": {"count": 2}},
+            {"type": "Feature", "geometry": null}
+        ]
+    }"#;
+
+    #[test]
+    fn test_parse_geojson() {
+        let collection = parse_geojson(SAMPLE).unwrap();
+        assert_eq!(collection.features.len(), 4);
+
+        let point = &collection.features[0];
+        assert_eq!(
+            point.geometry,
+            Some(Geometry::Point(Coordinate::new(0.5, 102.0)))
+        );
+        assert_eq!(point.property("prop0"), Some(&"value0".into()));
+
+        assert_eq!(
+            collection.features[1].geometry,
+            Some(Geometry::LineString(vec![
+                Coordinate::new(0.0, 102.0),
+                Coordinate::new(1.0, 103.0),
+                Coordinate::new(0.0, 104.0),
+            ]))
+        );
+        assert!(collection.features[1].properties.is_empty());
+
+        let Some(Geometry::Polygon(rings)) = &collection.features[2].geometry else {
+            panic!("expected a polygon");
+        };
+        assert_eq!(rings.len(), 2);
+        assert_eq!(rings[0].vertices.len(), 4);
+        assert_eq!(rings[1].vertices.len(), 3);
+        assert_eq!(
+            collection.features[2].property("count"),
+            Some(&JsonValue::Number(2.0))
+        );
+
+        assert_eq!(collection.features[3].geometry, None);
+    }
+
+    #[rstest]
+    #[case(
+        r#"{"type": "Point", "coordinates": [1, 2]}"#,
+        Geometry::Point(Coordinate::new(2.0, 1.0))
+    )]
+    #[case(r#"{"type": "Feature", "geometry": {"type": "MultiPoint", "coordinates": [[1, 2]]}}"#, Geometry::MultiPoint(vec![Coordinate::new(2.0, 1.0)]))]
+    #[case(r#"{"type": "MultiLineString", "coordinates": [[[1, 2], [3, 4]]]}"#, Geometry::MultiLineString(vec![vec![Coordinate::new(2.0, 1.0), Coordinate::new(4.0, 3.0)]]))]
+    #[case(r#"{"type": "MultiPolygon", "coordinates": [[[[0, 0], [1, 0], [0, 1], [0, 0]]]]}"#, Geometry::MultiPolygon(vec![vec![GeoPolygon::new(vec![Coordinate::new(0.0, 0.0), Coordinate::new(0.0, 1.0), Coordinate::new(1.0, 0.0)])]]))]
+    fn test_parse_single_geometry(#[case] geojson: &str, #[case] expected: Geometry) {
+        let collection = parse_geojson(geojson).unwrap();
+        assert_eq!(collection.features.len(), 1);
+        assert_eq!(collection.features[0].geometry, Some(expected));
+    }
+
+    #[rstest]
+    #[case("[]")]
+    #[case("{\"type\": \"Point\"}")]
+    #[case("{\"type\": \"Point\", \"coordinates\": [1]}")]
+    #[case("{\"type\": \"Point\", \"coordinates\": [1, 91]}")]
+    #[case("{\"type\": \"Point\", \"coordinates\": [\"1\", 2]}")]
+    #[case("{\"type\": \"GeometryCollection\", \"geometries\": []}")]
+    #[case("{\"type\": \"FeatureCollection\"}")]
+    #[case(
+        "{\"type\": \"FeatureCollection\", \"features\": [{\"type\": \"Point\", \"coordinates\": [1, 2]}]}"
+    )]
+    #[case("{\"type\": \"Feature\", \"geometry\": null, \"properties\": 1}")]
+    #[case("{\"type\": \"Point\", \"coordinates\": [1, 2]")]
+    fn test_parse_geojson_invalid(#[case] geojson: &str) {
+        assert!(parse_geojson(geojson).is_err());
+    }
+
+    #[test]
+    fn test_write_geojson() {
+        let collection = FeatureCollection {
+            features: vec![Feature::new(Geometry::Polygon(vec![GeoPolygon::new(
+                vec![
+                    Coordinate::new(0.0, 0.0),
+                    Coordinate::new(0.0, 1.0),
+                    Coordinate::new(1.0, 1.0),
+                ],
+            )]))],
+        };
+        assert_eq!(
+            write_geojson(&collection),
+            r#"{"type":"FeatureCollection","features":[{"type":"Feature","geometry":{"type":"Polygon","coordinates":[[[0,0],[1,0],[1,1],[0,0]]]},"properties":{}}]}"#
+        );
+    }
+
+    #[test]
+    fn test_write_geojson_round_trip() {
+        let collection = parse_geojson(SAMPLE).unwrap();
+        let written = write_geojson(&collection);
+        assert_eq!(parse_geojson(&written).unwrap(), collection);
+    }
+
+    #[test]
+    fn test_feature_from_waypoint_and_path() {
+        let mut waypoint = Waypoint::new(Coordinate::new(42.0, -71.0));
+        waypoint.name = Some("Summit".to_string());
+        waypoint.elevation = Some(1200.0);
+        waypoint.time = Some(0);
+        let feature = Feature::from(&waypoint);
+        assert_eq!(
+            feature.geometry,
+            Some(Geometry::Point(Coordinate::new(42.0, -71.0)))
+        );
+        assert_eq!(feature.property("name"), Some(&"Summit".into()));
+        assert_eq!(feature.property("description"), None);
+        assert_eq!(feature.property("elevation"), Some(&1200.0.into()));
+        assert_eq!(
+            feature.property("time"),
+            Some(&"1970-01-01T00:00:00Z".into())
+        );
+
+        let mut path = Path::new(vec![
+            waypoint.clone(),
+            Waypoint::new(Coordinate::new(43.0, -72.0)),
+        ]);
+        path.name = Some("Trail".to_string());
+        let feature = Feature::from(&path);
+        assert_eq!(
+            feature.geometry,
+            Some(Geometry::LineString(vec![
+                Coordinate::new(42.0, -71.0),
+                Coordinate::new(43.0, -72.0)
+            ]))
+        );
+        assert_eq!(feature.property("name"), Some(&"Trail".into()));
+    }
+}
@@ -0,0 +1,435 @@
+use crate::{math::Float, units::coordinate::Coordinate};
+
+use super::{
+    path::{Path, Waypoint},
+    xml::{Element, parse_xml},
+};
+
+/// The contents of a GPX 1.1 file
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Gpx {
+    pub waypoints: Vec<Waypoint>,
+    pub routes: Vec<Path>,
+    pub tracks: Vec<Track>,
+}
+
+/// A recorded track, split into segments where recording was interrupted
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Track {
+    pub name: Option<String>,
+    pub segments: Vec<Vec<Waypoint>>,
+}
+
+impl Track {
+    /// Joins the segments into a single path
+    pub fn to_path(&self) -> Path {
+        Path {
+            name: self.name.clone(),
+            points: self.segments.concat(),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct InvalidGpx(pub String);
+
+impl std::fmt::Display for InvalidGpx {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "invalid gpx: {}", self.0)
+    }
+}
+
+impl std::error::Error for InvalidGpx {}
+
+/// Reads the waypoints, routes and tracks from a GPX file. Other elements (such as metadata and
+/// extensions) are ignored.
+pub fn parse_gpx(gpx: &str) -> Result<Gpx, InvalidGpx> {
+    let root = parse_xml(gpx).map_err(|error| InvalidGpx(error.to_string()))?;
+    if root.name != "gpx" {
+        return Err(InvalidGpx(format!(
+            "expected a 'gpx' root element but found '{}'",
+            root.name
+        )));
+    }
+
+    let waypoints = root
+        .children_named("wpt")
+        .map(parse_point)
+        .collect::<Result<_, _>>()?;
+    let routes = root
+        .children_named("rte")
+        .map(|route| {
+            Ok(Path {
+                name: route.child_text("name").map(str::to_string),
+                points: route
+                    .children_named("rtept")
+                    .map(parse_point)
+                    .collect::<Result<_, _>>()?,
+            })
+        })
+        .collect::<Result<_, _>>()?;
+    let tracks = root
+        .children_named("trk")
+        .map(|track| {
+            Ok(Track {
+                name: track.child_text("name").map(str::to_string),
+                segments: track
+                    .children_named("trkseg")
+                    .map(|segment| {
+                        segment
+                            .children_named("trkpt")
+                            .map(parse_point)
+                            .collect::<Result<_, _>>()
+                    })
+                    .collect::<Result<_, _>>()?,
+            })
+        })
+        .collect::<Result<_, InvalidGpx>>()?;
+
+    Ok(Gpx {
+        waypoints,
+        routes,
+        tracks,
+    })
+}
+
+/// Writes the waypoints, routes and tracks as a GPX 1.1 file
+pub fn write_gpx(gpx: &Gpx) -> String {
+    let mut root = Element::new("gpx")
+        .with_attribute("version", "1.1")
+        .with_attribute("creator", "trail-sense-sol")
+        .with_attribute("xmlns", "http://www.topografix.com/GPX/1/1");
+    for waypoint in &gpx.waypoints {
+        root = root.with_child(point_element("wpt", waypoint));
+    }
+    for route in &gpx.routes {
+        let mut element = with_optional_text(Element::new("rte"), "name", &route.name);
+        for point in &route.points {
+            element = element.with_child(point_element("rtept", point));
+        }
+        root = root.with_child(element);
+    }
+    for track in &gpx.tracks {
+        let mut element = with_optional_text(Element::new("trk"), "name", &track.name);
+        for segment in &track.segments {
+            let mut segment_element = Element::new("trkseg");
+            for point in segment {
+                segment_element = segment_element.with_child(point_element("trkpt", point));
+            }
+            element = element.with_child(segment_element);
+        }
+        root = root.with_child(element);
+    }
+    root.to_document()
+}
+
+/// Parses an ISO 8601 / RFC 3339 timestamp (such as 2024-05-01T12:30:45.5Z) into milliseconds since
+/// the Unix epoch. Timestamps without a time zone are treated as UTC.
+pub fn parse_timestamp(timestamp: &str) -> Option<i64> {
+    let timestamp = timestamp.trim();
+    let (date, time) = timestamp.split_once(['T', 't', ' '])?;
+
+    let mut date_parts = date.splitn(3, '-');
+    let year: i64 = date_parts.next()?.parse().ok()?;
+    let month: u32 = date_parts.next()?.parse().ok()?;
+    let day: u32 = date_parts.next()?.parse().ok()?;
+    if !(0..=9999).contains(&year)
+        || !(1..=12).contains(&month)
+        || !(1..=days_in_month(year, month)).contains(&day)
+    {
+        return None;
+    }
+
+    // Split off the time zone
+    let (time, offset_minutes) = if let Some(time) = time.strip_suffix(['Z', 'z']) {
+        (time, 0)
+    } else if let Some(index) = time.rfind(['+', '-']) {
+        let (hours, minutes) = time[index + 1..].split_once(':')?;
+        let hours: i64 = hours.parse().ok()?;
+        let minutes: i64 = minutes.parse().ok()?;
+        if !(0..=23).contains(&hours) || !(0..=59).contains(&minutes) {
+            return None;
+        }
+        let minutes = hours * 60 + minutes;
+        let sign = if time[index..].starts_with('-') {
+            -1
+        } else {
+            1
+        };
+        (&time[..index], sign * minutes)
+    } else {
+        (time, 0)
+    };
+
+    let mut time_parts = time.splitn(3, ':');
+    let hours: i64 = time_parts.next()?.parse().ok()?;
+    let minutes: i64 = time_parts.next()?.parse().ok()?;
+    let seconds: f64 = time_parts.next().unwrap_or("0").parse().ok()?;
+    if !(0..=23).contains(&hours) || !(0..=59).contains(&minutes) || !(0.0..61.0).contains(&seconds)
+    {
+        return None;
+    }
+
+    let days = days_from_civil(year, month, day);
+    let minutes = days * 1440 + hours * 60 + minutes - offset_minutes;
+    Some(minutes * 60_000 + (seconds * 1000.0).round() as i64)
+}
+
+/// Formats milliseconds since the Unix epoch as an RFC 3339 timestamp in UTC. Milliseconds are only
+/// included when they are not zero.
+pub fn format_timestamp(milliseconds: i64) -> String {
+    let days = milliseconds.div_euclid(86_400_000);
+    let time = milliseconds.rem_euclid(86_400_000);
+    let (year, month, day) = civil_from_days(days);
+    let seconds = time / 1000;
+    let date_time = format!(
+        "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}",
+        year,
+        month,
+        day,
+        seconds / 3600,
+        seconds / 60 % 60,
+        seconds % 60
+    );
+    if time % 1000 == 0 {
+        format!("{date_time}Z")
+    } else {
+        format!("{date_time}.{:03}Z", time % 1000)
+    }
+}
+
+fn parse_point(element: &Element) -> Result<Waypoint, InvalidGpx> {
+    let latitude = parse_number(element, element.attribute("lat"), "lat")?;
+    let longitude = parse_number(element, element.attribute("lon"), "lon")?;
+    if !(-90.0..=90.0).contains(&latitude) || !(-180.0..=180.0).contains(&longitude) {
+        return Err(InvalidGpx(format!(
+            "'{}' is outside the valid range ({}, {})",
+            element.name, latitude, longitude
+        )));
+    }
+
+    let elevation = match element.child_text("ele") {
+        Some(text) => Some(parse_number(element, Some(text), "ele")?),
+        None => None,
+    };
+    let time = match element.child_text("time") {
+        Some(text) => Some(
+            parse_timestamp(text).ok_or_else(|| InvalidGpx(format!("invalid time '{text}'")))?,
+        ),
+        None => None,
+    };
+
+    Ok(Waypoint {
+        coordinate: Coordinate::new(latitude, longitude),
+        elevation,
+        time,
+        name: element.child_text("name").map(str::to_string),
+        description: element.child_text("desc").map(str::to_string),
+    })
+}
+
+fn parse_number(element: &Element, text: Option<&str>, name: &str) -> Result<Float, InvalidGpx> {
+    let text =
+        text.ok_or_else(|| InvalidGpx(format!("'{}' is missing '{}'", element.name, name)))?;
+    text.trim()
+        .parse()
+        .map_err(|_| InvalidGpx(format!("invalid {name} '{text}'")))
+}
+
+fn point_element(name: &str, point: &Waypoint) -> Element {
+    // The GPX schema requires the children in this order
+    let mut element = Element::new(name)
+        .with_attribute("lat", &point.coordinate.latitude.to_string())
+        .with_attribute("lon", &point.coordinate.longitude.to_string());
+    if let Some(elevation) = point.elevation {
+        element = element.with_child(Element::new("ele").with_text(&elevation.to_string()));
+    }
+    if let Some(time) = point.time {
+        element = element.with_child(Element::new("time").with_text(&format_timestamp(time)));
+    }
+    element = with_optional_text(element, "name", &point.name);
+    with_optional_text(element, "desc", &point.description)
+}
+
+fn with_optional_text(element: Element, name: &str, text: &Option<String>) -> Element {
+    match text {
+        Some(text) => element.with_child(Element::new(name).with_text(text)),
+        None => element,
+    }
+}
+
+/// Returns the number of days in the month, accounting for leap years
+fn days_in_month(year: i64, month: u32) -> u32 {
+    match month {
+        2 if year % 4 == 0 && (year % 100 != 0 || year % 400 == 0) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+/// Returns the number of days since 1970-01-01 (Howard Hinnant's algorithm)
+fn days_from_civil(year: i64, month: u32, day: u32) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year.rem_euclid(400);
+    let month = month as i64;
+    let day_of_year =
+        (153 * (if month > 2 { month - 3 } else { month + 9 }) + 2) / 5 + day as i64 - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+/// Returns the year, month and day of the number of days since 1970-01-01
+fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let days = days + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days.rem_euclid(146_097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+    let day = (day_of_year - (153 * month_index + 2) / 5 + 1) as u32;
+    let month = if month_index < 10 {
+        month_index + 3
+    } else {
+        month_index - 9
+    } as u32;
+    let year = year_of_era + era * 400 + if month <= 2 { 1 } else { 0 };
+    (year, month, day)
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::assert_approx_eq;
+
+    const SAMPLE: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<gpx version="1.1" creator="Test" xmlns="http://www.topografix.com/GPX/1/1">
+  <metadata><name>Sample</name></metadata>
+  <wpt lat="42.4396" lon="-71.1187">
+    <ele>44.586548</ele>
+    <time>2001-11-28T21:05:28Z</time>
+    <name>5066</name>
+    <desc><![CDATA[Crossing]]></desc>
+    <sym>Crossing</sym>
+  </wpt>
+  <wpt lat="42.43" lon="-71.11"/>
+  <rte>
+    <name>Route &amp; back</name>
+    <rtept lat="42.1" lon="-71.1"/>
+    <rtept lat="42.2" lon="-71.2"/>
+  </rte>
+  <trk>
+    <name>Morning hike</name>
+    <trkseg>
+      <trkpt lat="47.644548" lon="-122.326897"><ele>4.46</ele><time>2009-10-17T18:37:26Z</time></trkpt>
+      <trkpt lat="47.644549" lon="-122.326898"><ele>4.94</ele><time>2009-10-17T18:37:31Z</time></trkpt>
+    </trkseg>
+    <trkseg>
+      <trkpt lat="47.644550" lon="-122.326899"><ele>6.87</ele><time>2009-10-17T18:37:34.5+01:00</time></trkpt>
+    </trkseg>
+  </trk>
+</gpx>"#;
+
+    #[test]
+    fn test_parse_gpx() {
+        let gpx = parse_gpx(SAMPLE).unwrap();
+
+        assert_eq!(gpx.waypoints.len(), 2);
+        let waypoint = &gpx.waypoints[0];
+        assert_approx_eq!(waypoint.coordinate.latitude, 42.4396);
+        assert_approx_eq!(waypoint.coordinate.longitude, -71.1187);
+        assert_approx_eq!(waypoint.elevation.unwrap(), 44.586548);
+        assert_eq!(waypoint.time, Some(1006981528000));
+        assert_eq!(waypoint.name.as_deref(), Some("5066"));
+        assert_eq!(waypoint.description.as_deref(), Some("Crossing"));
+        assert_eq!(
+            gpx.waypoints[1],
+            Waypoint::new(Coordinate::new(42.43, -71.11))
+        );
+
+        assert_eq!(gpx.routes.len(), 1);
+        assert_eq!(gpx.routes[0].name.as_deref(), Some("Route & back"));
+        assert_eq!(
+            gpx.routes[0].coordinates(),
+            vec![Coordinate::new(42.1, -71.1), Coordinate::new(42.2, -71.2)]
+        );
+
+        assert_eq!(gpx.tracks.len(), 1);
+        let track = &gpx.tracks[0];
+        assert_eq!(track.name.as_deref(), Some("Morning hike"));
+        assert_eq!(track.segments.len(), 2);
+        assert_eq!(track.segments[0].len(), 2);
+        assert_eq!(track.segments[1][0].time, Some(1255801054500));
+        let path = track.to_path();
+        assert_eq!(path.points.len(), 3);
+        assert_eq!(path.name.as_deref(), Some("Morning hike"));
+    }
+
+    #[rstest]
+    #[case("<kml></kml>")]
+    #[case("<gpx><wpt lon=\"1\"/></gpx>")]
+    #[case("<gpx><wpt lat=\"91\" lon=\"1\"/></gpx>")]
+    #[case("<gpx><wpt lat=\"a\" lon=\"1\"/></gpx>")]
+    #[case("<gpx><wpt lat=\"1\" lon=\"1\"><ele>high</ele></wpt></gpx>")]
+    #[case(
+        "<gpx><trk><trkseg><trkpt lat=\"1\" lon=\"1\"><time>yesterday</time></trkpt></trkseg></trk></gpx>"
+    )]
+    #[case("<gpx><wpt lat=\"1\" lon=\"1\"></gpx>")]
+    fn test_parse_gpx_invalid(#[case] gpx: &str) {
+        assert!(parse_gpx(gpx).is_err());
+    }
+
+    #[test]
+    fn test_write_gpx_round_trip() {
+        let gpx = parse_gpx(SAMPLE).unwrap();
+        let written = write_gpx(&gpx);
+        assert!(written.starts_with("<?xml"));
+        assert!(written.contains("<gpx version=\"1.1\""));
+        assert!(written.contains("<time>2001-11-28T21:05:28Z</time>"));
+        assert_eq!(parse_gpx(&written).unwrap(), gpx);
+    }
+
+    #[test]
+    fn test_write_gpx_empty() {
+        let written = write_gpx(&Gpx::default());
+        assert_eq!(parse_gpx(&written).unwrap(), Gpx::default());
+    }
+
+    #[rstest]
+    #[case("1970-01-01T00:00:00Z", Some(0))]
+    #[case("2001-11-28T21:05:28Z", Some(1006981528000))]
+    #[case("2009-10-17T18:37:34.5+01:00", Some(1255801054500))]
+    #[case("2009-10-17T18:37:34-05:30", Some(1255824454000))]
+    #[case("2024-02-29T12:00:00", Some(1709208000000))]
+    #[case("1969-12-31T23:59:59.999Z", Some(-1))]
+    #[case("2024-13-01T00:00:00Z", None)]
+    #[case("2024-02-31T00:00:00Z", None)]
+    #[case("2023-02-29T00:00:00Z", None)]
+    #[case("2024-04-31T00:00:00Z", None)]
+    #[case("99999999999-01-01T00:00:00Z", None)]
+    #[case("2024-01-01T00:00:00+99999999999999:00", None)]
+    #[case("2024-01-01T-5:00:00Z", None)]
+    #[case("2024-01-01T24:59:30Z", None)]
+    #[case("2024-01-01T24:00:00Z", None)]
+    #[case("2024-01-01", None)]
+    #[case("noon", None)]
+    fn test_parse_timestamp(#[case] timestamp: &str, #[case] expected: Option<i64>) {
+        assert_eq!(parse_timestamp(timestamp), expected);
+    }
+
+    #[rstest]
+    #[case(0, "1970-01-01T00:00:00Z")]
+    #[case(1006981528000, "2001-11-28T21:05:28Z")]
+    #[case(1255801054500, "2009-10-17T17:37:34.500Z")]
+    #[case(1709208000000, "2024-02-29T12:00:00Z")]
+    #[case(-1, "1969-12-31T23:59:59.999Z")]
+    fn test_format_timestamp(#[case] milliseconds: i64, #[case] expected: &str) {
+        assert_eq!(format_timestamp(milliseconds), expected);
+    }
+}
@@ -0,0 +1,257 @@
+use crate::{
+    math::Float,
+    units::{
+        distance::Distance,
+        quantity::{Convertable, Quantity},
+        time::Time,
+    },
+};
+
+use super::{
+    path::Path,
+    path_statistics::{JitterTolerance, hold_elevation},
+};
+
+/// A point on an elevation profile
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ProfilePoint {
+    /// The horizontal distance from the start of the path
+    pub distance: Quantity<Distance>,
+    pub elevation: Quantity<Distance>,
+}
+
+impl ProfilePoint {
+    pub fn new(distance: Quantity<Distance>, elevation: Quantity<Distance>) -> Self {
+        ProfilePoint {
+            distance,
+            elevation,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum HikingTimeModel {
+    /// 5 km/h, plus 1 hour for every 600 m of ascent
+    Naismith,
+    /// Naismith's rule, minus 10 minutes for every 300 m of descent on gentle slopes (5° to 12°) and plus
+    /// 10 minutes for every 300 m of descent on steep slopes (over 12°)
+    NaismithLangmuir,
+    /// Tobler's hiking function, 6 km/h × e^(-3.5 × |slope + 0.05|), which is fastest on a slight downhill
+    Tobler,
+}
+
+/// Estimates how long it takes to hike the elevation profile. The pace factor scales the time for the
+/// hiker (1.0 for an average hiker, 1.2 for someone 20% slower), see `fit_pace_factor`.
+pub fn hiking_time(
+    profile: &[ProfilePoint],
+    model: HikingTimeModel,
+    pace_factor: Float,
+) -> Quantity<Time> {
+    let hours: Float = profile
+        .windows(2)
+        .map(|pair| {
+            let distance = (pair[1].distance.convert(Distance::Meters).amount
+                - pair[0].distance.convert(Distance::Meters).amount)
+                .abs();
+            let climb = pair[1].elevation.convert(Distance::Meters).amount
+                - pair[0].elevation.convert(Distance::Meters).amount;
+            segment_hours(distance, climb, model)
+        })
+        .sum();
+    Quantity::new((hours * pace_factor).max(0.0), Time::Hours)
+}
+
+/// Fits the pace factor to past hikes, as the ratio of the total moving time to the total estimated time.
+/// Elevation changes within the jitter tolerance are ignored, so GPS noise doesn't count as climbing.
+/// Returns None if the paths have no moving time or elevation profile.
+pub fn fit_pace_factor(
+    paths: &[Path],
+    model: HikingTimeModel,
+    tolerance: &JitterTolerance,
+) -> Option<Float> {
+    let mut actual = 0.0;
+    let mut estimated = 0.0;
+    for path in paths {
+        let moving_time = path
+            .statistics(tolerance)
+            .moving_time
+            .convert(Time::Hours)
+            .amount;
+        if moving_time <= 0.0 {
+            continue;
+        }
+        actual += moving_time;
+        estimated += hiking_time(
+            &without_jitter(&path.elevation_profile(), tolerance),
+            model,
+            1.0,
+        )
+        .convert(Time::Hours)
+        .amount;
+    }
+    if estimated > 0.0 {
+        Some(actual / estimated)
+    } else {
+        None
+    }
+}
+
+/// Returns the profile with elevation changes smaller than the tolerance removed
+fn without_jitter(profile: &[ProfilePoint], tolerance: &JitterTolerance) -> Vec<ProfilePoint> {
+    let elevations = hold_elevation(
+        profile
+            .iter()
+            .map(|point| point.elevation.convert(Distance::Meters).amount),
+        tolerance.elevation.convert(Distance::Meters).amount,
+    );
+    profile
+        .iter()
+        .zip(elevations)
+        .map(|(point, elevation)| {
+            ProfilePoint::new(point.distance, Quantity::new(elevation, Distance::Meters))
+        })
+        .collect()
+}
+
+/// Returns the hours to hike the segment, given the horizontal distance and climb in meters
+fn segment_hours(distance: Float, climb: Float, model: HikingTimeModel) -> Float {
+    match model {
+        HikingTimeModel::Naismith => naismith_hours(distance, climb),
+        HikingTimeModel::NaismithLangmuir => {
+            let descent = -climb;
+            let slope = descent.atan2(distance).to_degrees();
+            let correction = if descent <= 0.0 || slope < 5.0 {
+                0.0
+            } else if slope <= 12.0 {
+                -descent / 300.0 / 6.0
+            } else {
+                descent / 300.0 / 6.0
+            };
+            naismith_hours(distance, climb) + correction
+        }
+        HikingTimeModel::Tobler => {
+            if distance == 0.0 {
+                // Straight up or down, so fall back to Naismith's climbing rate
+                return naismith_hours(0.0, climb);
+            }
+            let speed = 6.0 * (-3.5 * (climb / distance + 0.05).abs()).exp();
+            distance / 1000.0 / speed
+        }
+    }
+}
+
+fn naismith_hours(distance: Float, climb: Float) -> Float {
+    distance / 5000.0 + climb.max(0.0) / 600.0
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::{assert_approx_eq, navigation::path::Waypoint, units::coordinate::Coordinate};
+
+    fn profile(points: &[(Float, Float)]) -> Vec<ProfilePoint> {
+        points
+            .iter()
+            .map(|(distance, elevation)| {
+                ProfilePoint::new(
+                    Quantity::new(*distance, Distance::Kilometers),
+                    Quantity::new(*elevation, Distance::Meters),
+                )
+            })
+            .collect()
+    }
+
+    #[rstest]
+    #[case(&[(0.0, 0.0), (10.0, 0.0)], HikingTimeModel::Naismith, 2.0)]
+    #[case(&[(0.0, 0.0), (10.0, 600.0)], HikingTimeModel::Naismith, 3.0)]
+    #[case(&[(0.0, 0.0), (5.0, 600.0), (10.0, 0.0)], HikingTimeModel::Naismith, 3.0)]
+    // 300 m down over 2 km is about 8.5°, so 10 minutes faster
+    #[case(&[(0.0, 300.0), (2.0, 0.0)], HikingTimeModel::NaismithLangmuir, 0.4 - 1.0 / 6.0)]
+    // 300 m down over 1 km is about 16.7°, so 10 minutes slower
+    #[case(&[(0.0, 300.0), (1.0, 0.0)], HikingTimeModel::NaismithLangmuir, 0.2 + 1.0 / 6.0)]
+    // Under 5°
+    #[case(&[(0.0, 300.0), (5.0, 0.0)], HikingTimeModel::NaismithLangmuir, 1.0)]
+    #[case(&[(0.0, 0.0), (10.0, 600.0)], HikingTimeModel::NaismithLangmuir, 3.0)]
+    #[case(&[(0.0, 0.0), (10.0, 0.0)], HikingTimeModel::Tobler, 1.98541)]
+    #[case(&[(0.0, 500.0), (10.0, 0.0)], HikingTimeModel::Tobler, 1.66667)]
+    #[case(&[(0.0, 0.0), (1.0, 100.0)], HikingTimeModel::Tobler, 0.28174)]
+    #[case(&[(0.0, 0.0), (0.0, 600.0)], HikingTimeModel::Tobler, 1.0)]
+    #[case(&[(0.0, 0.0)], HikingTimeModel::Tobler, 0.0)]
+    fn test_hiking_time(
+        #[case] points: &[(Float, Float)],
+        #[case] model: HikingTimeModel,
+        #[case] hours: Float,
+    ) {
+        let time = hiking_time(&profile(points), model, 1.0);
+        assert_approx_eq!(time.convert(Time::Hours).amount, hours, 1e-4);
+    }
+
+    #[test]
+    fn test_hiking_time_pace_factor() {
+        let time = hiking_time(
+            &profile(&[(0.0, 0.0), (10.0, 600.0)]),
+            HikingTimeModel::Naismith,
+            1.5,
+        );
+        assert_approx_eq!(time.convert(Time::Hours).amount, 4.5, 1e-4);
+    }
+
+    /// A path heading north at the speed (m/s), climbing 100 m per km, with a point every minute
+    fn recorded_path(speed: Float, minutes: usize) -> Path {
+        Path::new(
+            (0..=minutes)
+                .map(|i| {
+                    let meters = speed * 60.0 * i as Float;
+                    let mut point = Waypoint::new(Coordinate::new(meters / 110574.0, 0.0));
+                    point.elevation = Some(meters / 10.0);
+                    point.time = Some(i as i64 * 60_000);
+                    point
+                })
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn test_fit_pace_factor() {
+        // Naismith estimates 1/5 + 1/6 = 0.36667 hours per km, or 0.7576 m/s
+        let average_speed = 1000.0 / (0.36667 * 3600.0);
+        let paths = vec![
+            recorded_path(average_speed / 1.25, 60),
+            recorded_path(average_speed / 1.25, 120),
+        ];
+        let factor = fit_pace_factor(
+            &paths,
+            HikingTimeModel::Naismith,
+            &JitterTolerance::default(),
+        )
+        .unwrap();
+        assert_approx_eq!(factor, 1.25, 0.01);
+
+        assert_eq!(
+            fit_pace_factor(&[], HikingTimeModel::Naismith, &JitterTolerance::default()),
+            None
+        );
+    }
+
+    #[test]
+    fn test_fit_pace_factor_ignores_elevation_noise() {
+        // A flat path where the elevation jumps up and down by 2 m at every point
+        let flat = |noise: Float| {
+            let mut path = recorded_path(1.0, 60);
+            for (i, point) in path.points.iter_mut().enumerate() {
+                let offset = if i % 2 == 0 { noise } else { -noise };
+                point.elevation = Some(100.0 + offset);
+            }
+            path
+        };
+        let tolerance = JitterTolerance::default();
+        let expected =
+            fit_pace_factor(&[flat(0.0)], HikingTimeModel::Naismith, &tolerance).unwrap();
+        let actual = fit_pace_factor(&[flat(1.0)], HikingTimeModel::Naismith, &tolerance).unwrap();
+        assert_approx_eq!(actual, expected, 1e-4);
+    }
+}
@@ -0,0 +1,10 @@
+pub mod course;
+pub mod dead_reckoning;
+pub mod geojson;
+pub mod gpx;
+pub mod hiking_time;
+pub mod json;
+pub mod path;
+pub mod path_statistics;
+pub mod triangulation;
+mod xml;
@@ -0,0 +1,139 @@
+use crate::{
+    math::Float,
+    units::{coordinate::Coordinate, distance::Distance, quantity::Quantity},
+};
+
+use super::{
+    hiking_time::ProfilePoint,
+    path_statistics::{JitterTolerance, PathStatistics, path_splits, path_statistics},
+};
+
+/// A named location, or a point along a path
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Waypoint {
+    pub coordinate: Coordinate,
+    /// The elevation in meters above sea level
+    pub elevation: Option<Float>,
+    /// The time the point was recorded, in milliseconds since the Unix epoch (UTC)
+    pub time: Option<i64>,
+    pub name: Option<String>,
+    pub description: Option<String>,
+}
+
+impl Waypoint {
+    pub fn new(coordinate: Coordinate) -> Self {
+        Waypoint {
+            coordinate,
+            elevation: None,
+            time: None,
+            name: None,
+            description: None,
+        }
+    }
+}
+
+/// An ordered list of points, such as a planned route or a recorded track
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Path {
+    pub name: Option<String>,
+    pub points: Vec<Waypoint>,
+}
+
+impl Path {
+    pub fn new(points: Vec<Waypoint>) -> Self {
+        Path { name: None, points }
+    }
+
+    pub fn coordinates(&self) -> Vec<Coordinate> {
+        self.points.iter().map(|point| point.coordinate).collect()
+    }
+
+    /// Returns the total distance between the points on the WGS84 ellipsoid, including any GPS jitter
+    pub fn distance(&self) -> Quantity<Distance> {
+        let meters = self
+            .points
+            .windows(2)
+            .map(|pair| pair[0].coordinate.distance_to(&pair[1].coordinate).amount)
+            .sum();
+        Quantity::new(meters, Distance::Meters)
+    }
+
+    /// Returns the distance along the path and elevation of each point. Points without an elevation use
+    /// the previous elevation (or the next one at the start of the path).
+    pub fn elevation_profile(&self) -> Vec<ProfilePoint> {
+        let mut elevation = self
+            .points
+            .iter()
+            .find_map(|point| point.elevation)
+            .unwrap_or(0.0);
+        let mut distance = 0.0;
+        self.points
+            .iter()
+            .enumerate()
+            .map(|(i, point)| {
+                if i > 0 {
+                    distance += self.points[i - 1]
+                        .coordinate
+                        .distance_to(&point.coordinate)
+                        .amount;
+                }
+                elevation = point.elevation.unwrap_or(elevation);
+                ProfilePoint::new(
+                    Quantity::new(distance, Distance::Meters),
+                    Quantity::new(elevation, Distance::Meters),
+                )
+            })
+            .collect()
+    }
+
+    /// Returns the distance, elevation, time and speed statistics, ignoring GPS jitter
+    pub fn statistics(&self, tolerance: &JitterTolerance) -> PathStatistics {
+        path_statistics(&self.points, tolerance)
+    }
+
+    /// Returns the statistics of each split of the path (ex. every kilometer)
+    pub fn splits(
+        &self,
+        split_distance: &Quantity<Distance>,
+        tolerance: &JitterTolerance,
+    ) -> Vec<PathStatistics> {
+        path_splits(&self.points, split_distance, tolerance)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::assert_approx_eq;
+
+    #[test]
+    fn test_distance() {
+        let path = Path::new(vec![
+            Waypoint::new(Coordinate::new(0.0, 0.0)),
+            Waypoint::new(Coordinate::new(0.0, 1.0)),
+            Waypoint::new(Coordinate::new(1.0, 1.0)),
+        ]);
+        assert_approx_eq!(path.distance().amount, 221893.88, 2.0);
+        assert_eq!(Path::default().distance().amount, 0.0);
+    }
+
+    #[test]
+    fn test_elevation_profile() {
+        let mut points = vec![
+            Waypoint::new(Coordinate::new(0.0, 0.0)),
+            Waypoint::new(Coordinate::new(0.0, 0.01)),
+            Waypoint::new(Coordinate::new(0.0, 0.02)),
+            Waypoint::new(Coordinate::new(0.0, 0.03)),
+        ];
+        points[1].elevation = Some(100.0);
+        points[3].elevation = Some(150.0);
+        let profile = Path::new(points).elevation_profile();
+        let distances: Vec<Float> = profile.iter().map(|p| p.distance.amount).collect();
+        let elevations: Vec<Float> = profile.iter().map(|p| p.elevation.amount).collect();
+        assert_approx_eq!(distances[0], 0.0);
+        assert_approx_eq!(distances[3], 3339.58, 5.0);
+        assert_eq!(elevations, vec![100.0, 100.0, 100.0, 150.0]);
+    }
+}
@@ -0,0 +1,390 @@
+use crate::{
+    math::Float,
+    units::{
+        distance::Distance,
+        quantity::{Convertable, Quantity},
+        speed::Speed,
+        time::Time,
+    },
+};
+
+use super::path::Waypoint;
+
+/// How much GPS noise to ignore when calculating path statistics
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct JitterTolerance {
+    /// Elevation changes smaller than this are not counted as gain or loss
+    pub elevation: Quantity<Distance>,
+    /// Moving slower than this is considered stopped
+    pub stopped_speed: Quantity<Speed>,
+    /// Speeds are averaged over at least this long, so a single bad location doesn't cause a spike
+    pub speed_window: Quantity<Time>,
+}
+
+impl Default for JitterTolerance {
+    /// 3 m of elevation, stopped below 0.3 m/s and speeds averaged over 10 s
+    fn default() -> Self {
+        JitterTolerance {
+            elevation: Quantity::new(3.0, Distance::Meters),
+            stopped_speed: Quantity::new(0.3, Speed::MetersPerSecond),
+            speed_window: Quantity::new(10.0, Time::Seconds),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PathStatistics {
+    /// The distance travelled while moving
+    pub distance: Quantity<Distance>,
+    pub elevation_gain: Quantity<Distance>,
+    pub elevation_loss: Quantity<Distance>,
+    /// The time from the first to the last recorded point
+    pub elapsed_time: Quantity<Time>,
+    pub moving_time: Quantity<Time>,
+    pub stopped_time: Quantity<Time>,
+    /// The distance divided by the elapsed time
+    pub average_speed: Quantity<Speed>,
+    /// The distance divided by the moving time
+    pub moving_speed: Quantity<Speed>,
+    pub max_speed: Quantity<Speed>,
+}
+
+impl PathStatistics {
+    /// Returns the moving time needed to travel the distance at the moving speed (ex. minutes per
+    /// kilometer). Returns None if there was no movement.
+    pub fn pace(&self, per: &Quantity<Distance>) -> Option<Quantity<Time>> {
+        let speed = self.moving_speed.convert(Speed::MetersPerSecond).amount;
+        if speed <= 0.0 {
+            return None;
+        }
+        let meters = per.convert(Distance::Meters).amount;
+        Some(Quantity::new(meters / speed, Time::Seconds))
+    }
+}
+
+/// Calculates the statistics of the points. Segments between points where the speed (averaged over the
+/// speed window) is below the stopped speed count as stopped, and their distance is ignored since it is
+/// most likely GPS jitter. Segments missing a time always count as moving. Time and speed statistics are
+/// zero if the points have no times.
+pub fn path_statistics(points: &[Waypoint], tolerance: &JitterTolerance) -> PathStatistics {
+    let segments = segments(points, tolerance);
+    let (elevation_gain, elevation_loss) = elevation_change(
+        points.iter().filter_map(|point| point.elevation),
+        tolerance.elevation.convert(Distance::Meters).amount,
+    );
+
+    let mut distance = 0.0;
+    let mut moving_time = 0.0;
+    let mut max_speed: Float = 0.0;
+    for segment in segments.iter().filter(|segment| segment.is_moving) {
+        distance += segment.distance;
+        moving_time += segment.duration.unwrap_or(0.0);
+        max_speed = max_speed.max(segment.speed.unwrap_or(0.0));
+    }
+
+    let times: Vec<i64> = points.iter().filter_map(|point| point.time).collect();
+    let elapsed_time = match (times.first(), times.last()) {
+        (Some(first), Some(last)) => (last - first).max(0) as Float / 1000.0,
+        _ => 0.0,
+    };
+    let speed = |seconds: Float| {
+        if seconds > 0.0 {
+            distance / seconds
+        } else {
+            0.0
+        }
+    };
+
+    PathStatistics {
+        distance: Quantity::new(distance, Distance::Meters),
+        elevation_gain: Quantity::new(elevation_gain, Distance::Meters),
+        elevation_loss: Quantity::new(elevation_loss, Distance::Meters),
+        elapsed_time: Quantity::new(elapsed_time, Time::Seconds),
+        moving_time: Quantity::new(moving_time, Time::Seconds),
+        stopped_time: Quantity::new((elapsed_time - moving_time).max(0.0), Time::Seconds),
+        average_speed: Quantity::new(speed(elapsed_time), Speed::MetersPerSecond),
+        moving_speed: Quantity::new(speed(moving_time), Speed::MetersPerSecond),
+        max_speed: Quantity::new(max_speed, Speed::MetersPerSecond),
+    }
+}
+
+/// Splits the points every time the distance reaches a multiple of the split distance (ex. every
+/// kilometer) and calculates the statistics of each split. Splits end at the first point past each
+/// multiple and the next split starts from that point, so the last split may be shorter.
+pub fn path_splits(
+    points: &[Waypoint],
+    split_distance: &Quantity<Distance>,
+    tolerance: &JitterTolerance,
+) -> Vec<PathStatistics> {
+    let split_meters = split_distance.convert(Distance::Meters).amount;
+    if points.len() < 2 || split_meters <= 0.0 {
+        return vec![];
+    }
+
+    let segments = segments(points, tolerance);
+    let mut splits = vec![];
+    let mut start = 0;
+    let mut distance = 0.0;
+    for (i, segment) in segments.iter().enumerate() {
+        if segment.is_moving {
+            distance += segment.distance;
+        }
+        if distance >= split_meters || i == segments.len() - 1 {
+            splits.push(path_statistics(&points[start..=i + 1], tolerance));
+            start = i + 1;
+            distance -= split_meters * (distance / split_meters).floor();
+        }
+    }
+    splits
+}
+
+/// Returns the elevation gain and loss, ignoring changes smaller than the threshold. The reference
+/// elevation only moves once the elevation has changed by the threshold, so small bumps from noise
+/// are not counted.
+fn elevation_change(elevations: impl Iterator<Item = Float>, threshold: Float) -> (Float, Float) {
+    let mut gain = 0.0;
+    let mut loss = 0.0;
+    let mut last: Option<Float> = None;
+    for elevation in hold_elevation(elevations, threshold) {
+        if let Some(last) = last {
+            let change = elevation - last;
+            if change > 0.0 {
+                gain += change;
+            } else {
+                loss -= change;
+            }
+        }
+        last = Some(elevation);
+    }
+    (gain, loss)
+}
+
+/// Holds each elevation at the last one which changed by at least the threshold, so changes smaller than
+/// the threshold (most likely GPS noise) don't add up
+pub(crate) fn hold_elevation(
+    elevations: impl Iterator<Item = Float>,
+    threshold: Float,
+) -> impl Iterator<Item = Float> {
+    let mut reference: Option<Float> = None;
+    elevations.map(move |elevation| match reference {
+        Some(last) if (elevation - last).abs() < threshold.max(Float::EPSILON) => last,
+        _ => {
+            reference = Some(elevation);
+            elevation
+        }
+    })
+}
+
+struct Segment {
+    /// Meters
+    distance: Float,
+    /// Seconds
+    duration: Option<Float>,
+    /// Meters per second, averaged over the speed window
+    speed: Option<Float>,
+    is_moving: bool,
+}
+
+fn segments(points: &[Waypoint], tolerance: &JitterTolerance) -> Vec<Segment> {
+    let window = tolerance.speed_window.convert(Time::Seconds).amount;
+    let stopped_speed = tolerance
+        .stopped_speed
+        .convert(Speed::MetersPerSecond)
+        .amount;
+
+    let mut cumulative_distance = vec![0.0; points.len()];
+    for i in 1..points.len() {
+        cumulative_distance[i] = cumulative_distance[i - 1]
+            + points[i - 1]
+                .coordinate
+                .distance_to(&points[i].coordinate)
+                .amount;
+    }
+    let seconds_between = |start: usize, end: usize| match (points[start].time, points[end].time) {
+        (Some(start), Some(end)) => Some((end - start) as Float / 1000.0),
+        _ => None,
+    };
+
+    (1..points.len())
+        .map(|end| {
+            let start = end - 1;
+            let duration = seconds_between(start, end).filter(|seconds| *seconds >= 0.0);
+
+            // Widen the segment until it covers the speed window or reaches a point without a time
+            let mut window_start = start;
+            let mut window_end = end;
+            let speed = duration.filter(|seconds| *seconds > 0.0).map(|_| {
+                while seconds_between(window_start, window_end).unwrap_or(window) < window {
+                    if window_end + 1 < points.len()
+                        && seconds_between(window_start, window_end + 1).is_some()
+                    {
+                        window_end += 1;
+                    } else if window_start > 0
+                        && seconds_between(window_start - 1, window_end).is_some()
+                    {
+                        window_start -= 1;
+                    } else {
+                        break;
+                    }
+                }
+                let distance = cumulative_distance[window_end] - cumulative_distance[window_start];
+                distance / seconds_between(window_start, window_end).unwrap_or(1.0)
+            });
+
+            Segment {
+                distance: cumulative_distance[end] - cumulative_distance[start],
+                duration,
+                speed,
+                is_moving: speed.is_none_or(|speed| speed >= stopped_speed),
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::{assert_approx_eq, units::coordinate::Coordinate};
+
+    /// A point every 10 s, moving north by the distance in meters (about 1 m = 0.000009°)
+    fn track(steps: &[(Float, Float)]) -> Vec<Waypoint> {
+        let mut latitude = 0.0;
+        steps
+            .iter()
+            .enumerate()
+            .map(|(i, (meters, elevation))| {
+                latitude += meters / 110574.0;
+                let mut point = Waypoint::new(Coordinate::new(latitude, 0.0));
+                point.elevation = Some(*elevation);
+                point.time = Some(i as i64 * 10_000);
+                point
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_path_statistics() {
+        // Walk at 1.5 m/s, stop for 30 s while the GPS drifts, then walk again
+        let points = track(&[
+            (0.0, 100.0),
+            (15.0, 105.0),
+            (15.0, 110.0),
+            (1.0, 111.0),
+            (-1.0, 110.0),
+            (1.0, 111.0),
+            (15.0, 104.0),
+            (15.0, 100.0),
+        ]);
+        let statistics = path_statistics(&points, &JitterTolerance::default());
+        assert_approx_eq!(statistics.distance.amount, 60.0, 0.1);
+        assert_approx_eq!(statistics.elevation_gain.amount, 10.0, 0.01);
+        assert_approx_eq!(statistics.elevation_loss.amount, 10.0, 0.01);
+        assert_approx_eq!(statistics.elapsed_time.amount, 70.0, 0.01);
+        assert_approx_eq!(statistics.moving_time.amount, 40.0, 0.01);
+        assert_approx_eq!(statistics.stopped_time.amount, 30.0, 0.01);
+        assert_approx_eq!(statistics.average_speed.amount, 60.0 / 70.0, 0.01);
+        assert_approx_eq!(statistics.moving_speed.amount, 1.5, 0.01);
+        assert_approx_eq!(statistics.max_speed.amount, 1.5, 0.01);
+        assert_approx_eq!(
+            statistics
+                .pace(&Quantity::new(1.0, Distance::Kilometers))
+                .unwrap()
+                .amount,
+            666.67,
+            0.5
+        );
+    }
+
+    #[test]
+    fn test_path_statistics_smooths_speed_spikes() {
+        let mut points = track(&[(0.0, 0.0), (10.0, 0.0), (10.0, 0.0), (10.0, 0.0)]);
+        // A point 1 s after the previous one, 5 m away
+        let mut spike = points[1].clone();
+        spike.coordinate.latitude += 5.0 / 110574.0;
+        spike.time = Some(11_000);
+        points.insert(2, spike);
+
+        let statistics = path_statistics(&points, &JitterTolerance::default());
+        assert!(statistics.max_speed.amount < 1.6);
+    }
+
+    #[test]
+    fn test_path_statistics_without_times() {
+        let points: Vec<Waypoint> = [(0.0, 0.0), (0.0, 0.01)]
+            .iter()
+            .map(|(latitude, longitude)| Waypoint::new(Coordinate::new(*latitude, *longitude)))
+            .collect();
+        let statistics = path_statistics(&points, &JitterTolerance::default());
+        assert_approx_eq!(statistics.distance.amount, 1113.19, 5.0);
+        assert_eq!(statistics.elevation_gain.amount, 0.0);
+        assert_eq!(statistics.elapsed_time.amount, 0.0);
+        assert_eq!(statistics.moving_time.amount, 0.0);
+        assert_eq!(statistics.average_speed.amount, 0.0);
+        assert_eq!(statistics.max_speed.amount, 0.0);
+        assert_eq!(
+            statistics.pace(&Quantity::new(1.0, Distance::Kilometers)),
+            None
+        );
+    }
+
+    #[test]
+    fn test_path_statistics_empty() {
+        let statistics = path_statistics(&[], &JitterTolerance::default());
+        assert_eq!(statistics.distance.amount, 0.0);
+        assert_eq!(statistics.elapsed_time.amount, 0.0);
+    }
+
+    #[rstest]
+    #[case(&[100.0, 102.0, 99.0, 101.0, 100.0], 3.0, 0.0, 0.0)]
+    #[case(&[100.0, 102.0, 99.0, 101.0, 100.0], 0.0, 4.0, 4.0)]
+    #[case(&[100.0, 104.0, 110.0, 108.0, 103.0], 3.0, 10.0, 7.0)]
+    #[case(&[100.0, 101.0, 102.0, 103.0, 104.0], 3.0, 3.0, 0.0)]
+    #[case(&[], 3.0, 0.0, 0.0)]
+    fn test_elevation_change(
+        #[case] elevations: &[Float],
+        #[case] threshold: Float,
+        #[case] gain: Float,
+        #[case] loss: Float,
+    ) {
+        let (actual_gain, actual_loss) = elevation_change(elevations.iter().copied(), threshold);
+        assert_approx_eq!(actual_gain, gain);
+        assert_approx_eq!(actual_loss, loss);
+    }
+
+    #[test]
+    fn test_path_splits() {
+        let points = track(&[
+            (0.0, 0.0),
+            (20.0, 0.0),
+            (20.0, 10.0),
+            (20.0, 10.0),
+            (20.0, 0.0),
+            (20.0, 0.0),
+        ]);
+        let splits = path_splits(
+            &points,
+            &Quantity::new(40.0, Distance::Meters),
+            &JitterTolerance::default(),
+        );
+        assert_eq!(splits.len(), 3);
+        assert_approx_eq!(splits[0].distance.amount, 40.0, 0.1);
+        assert_approx_eq!(splits[0].elevation_gain.amount, 10.0, 0.01);
+        assert_approx_eq!(splits[0].elapsed_time.amount, 20.0, 0.01);
+        assert_approx_eq!(splits[1].distance.amount, 40.0, 0.1);
+        assert_approx_eq!(splits[1].elevation_loss.amount, 10.0, 0.01);
+        assert_approx_eq!(splits[2].distance.amount, 20.0, 0.1);
+
+        assert!(
+            path_splits(
+                &points,
+                &Quantity::new(0.0, Distance::Meters),
+                &JitterTolerance::default()
+            )
+            .is_empty()
+        );
+    }
+}
@@ -0,0 +1,380 @@
+use crate::{
+    geology::projection::{AzimuthalEquidistant, Projection},
+    math::{Float, geometry::Point2D, linear_algebra::least_squares},
+    units::{
+        angle::Angle,
+        bearing::Bearing,
+        coordinate::Coordinate,
+        distance::Distance,
+        quantity::{Convertable, Quantity},
+    },
+};
+
+/// Scales a one standard deviation ellipse to contain the true position 95% of the time (the square
+/// root of the 95% point of the chi-squared distribution with 2 degrees of freedom)
+const CONFIDENCE_95: Float = 2.447_747;
+
+/// The number of times the solution is refined after the initial estimate
+const REFINEMENTS: usize = 2;
+
+/// A true bearing involving a known location. For triangulation it is the bearing from the known
+/// location to the target, and for resection it is the bearing from the unknown position to the known
+/// landmark.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BearingObservation {
+    pub coordinate: Coordinate,
+    pub bearing: Bearing,
+}
+
+impl BearingObservation {
+    pub fn new(coordinate: Coordinate, bearing: Bearing) -> Self {
+        BearingObservation {
+            coordinate,
+            bearing,
+        }
+    }
+}
+
+/// The area that likely (95%) contains the true position
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ErrorEllipse {
+    pub semi_major_axis: Quantity<Distance>,
+    pub semi_minor_axis: Quantity<Distance>,
+    /// The direction of the major axis, between 0° and 180°
+    pub orientation: Bearing,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PositionFix {
+    pub coordinate: Coordinate,
+    pub error: ErrorEllipse,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TriangulationError {
+    /// At least two bearings are needed
+    NotEnoughBearings { actual: usize },
+    /// The bearings are (nearly) parallel, so they don't cross at a single point
+    ParallelBearings,
+    /// The bearings only cross behind where they point
+    NoIntersection,
+}
+
+impl std::fmt::Display for TriangulationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TriangulationError::NotEnoughBearings { actual } => write!(
+                f,
+                "at least 2 bearings are required, but {} were provided",
+                actual
+            ),
+            TriangulationError::ParallelBearings => {
+                write!(f, "the bearings are parallel")
+            }
+            TriangulationError::NoIntersection => {
+                write!(f, "the bearings do not intersect")
+            }
+        }
+    }
+}
+
+impl std::error::Error for TriangulationError {}
+
+/// Locates a distant target from the bearings to it taken at known locations. More than two bearings
+/// are combined with least squares. The bearing error is the standard deviation of the bearings (ex.
+/// 1° to 2° for a handheld compass).
+pub fn triangulate(
+    observations: &[BearingObservation],
+    bearing_error: &Quantity<Angle>,
+) -> Result<PositionFix, TriangulationError> {
+    solve(observations, bearing_error, false)
+}
+
+/// Finds your position from the bearings to known landmarks. More than two bearings are combined with
+/// least squares. The bearing error is the standard deviation of the bearings (ex. 1° to 2° for a
+/// handheld compass).
+pub fn resection(
+    landmarks: &[BearingObservation],
+    bearing_error: &Quantity<Angle>,
+) -> Result<PositionFix, TriangulationError> {
+    solve(landmarks, bearing_error, true)
+}
+
+/// Intersects the lines of position on an azimuthal equidistant plane centered on the estimate, which
+/// is refined as the center moves closer to the solution. Each line is weighted by how far its
+/// position error grows at the estimate (distance × bearing error).
+fn solve(
+    observations: &[BearingObservation],
+    bearing_error: &Quantity<Angle>,
+    is_resection: bool,
+) -> Result<PositionFix, TriangulationError> {
+    if observations.len() < 2 {
+        return Err(TriangulationError::NotEnoughBearings {
+            actual: observations.len(),
+        });
+    }
+    let sigma = bearing_error
+        .convert(Angle::Radians)
+        .amount
+        .abs()
+        .max(Float::EPSILON);
+
+    let mut center = mean_coordinate(observations);
+    let mut estimate: Option<Point2D> = None;
+    let mut fix = None;
+    for _ in 0..=REFINEMENTS {
+        let projection = AzimuthalEquidistant::new(center);
+        let lines: Vec<(Point2D, Float, Float)> = observations
+            .iter()
+            .map(|observation| {
+                let point = projection.project(&observation.coordinate);
+                let distance = estimate.map(|estimate| point.distance(&estimate));
+                let direction = match distance {
+                    // Follow the geodesic toward the estimate, since bearings away from the center are
+                    // distorted on the plane
+                    Some(distance) if !is_resection && distance > 0.0 => {
+                        let along = observation.coordinate.destination(
+                            &Quantity::new(distance, Distance::Meters),
+                            &observation.bearing,
+                        );
+                        point.angle(&projection.project(&along))
+                    }
+                    _ => (90.0 - observation.bearing.degrees).to_radians(),
+                };
+                // Unweighted until there is an estimate
+                let weight = distance.map_or(1.0, |distance| 1.0 / (distance.max(1.0) * sigma));
+                (point, direction, weight)
+            })
+            .collect();
+
+        let (position, covariance) = intersect(&lines)?;
+        let coordinate = projection.unproject(&position);
+        fix = Some(PositionFix {
+            coordinate,
+            error: error_ellipse(covariance),
+        });
+        center = coordinate;
+        estimate = Some(Point2D::new(0.0, 0.0));
+    }
+    let fix = fix.ok_or(TriangulationError::ParallelBearings)?;
+
+    // The lines also cross behind the bearings, so make sure the fix is ahead of each one
+    let is_ahead = observations.iter().all(|observation| {
+        if observation.coordinate == fix.coordinate {
+            return true;
+        }
+        let bearing = if is_resection {
+            fix.coordinate.bearing_to(&observation.coordinate)
+        } else {
+            observation.coordinate.bearing_to(&fix.coordinate)
+        };
+        let difference =
+            (bearing.degrees - observation.bearing.degrees + 540.0).rem_euclid(360.0) - 180.0;
+        difference.abs() < 90.0
+    });
+    if !is_ahead {
+        return Err(TriangulationError::NoIntersection);
+    }
+    Ok(fix)
+}
+
+/// Returns the least squares intersection of the weighted lines (a point and a direction angle from
+/// the x axis) and its covariance (xx, xy, yy)
+fn intersect(
+    lines: &[(Point2D, Float, Float)],
+) -> Result<(Point2D, (Float, Float, Float)), TriangulationError> {
+    // The distance from (x, y) to a line is sin(a) (x - x0) - cos(a) (y - y0)
+    let mut rows = Vec::with_capacity(lines.len());
+    let mut values = Vec::with_capacity(lines.len());
+    let mut angle_check = [0.0; 3];
+    for (point, angle, weight) in lines {
+        let (sin, cos) = angle.sin_cos();
+        rows.push(vec![sin * weight, -cos * weight]);
+        values.push((sin * point.x - cos * point.y) * weight);
+        angle_check[0] += sin * sin;
+        angle_check[1] += sin * cos;
+        angle_check[2] += cos * cos;
+    }
+
+    // The lines must cross at a meaningful angle, regardless of their weights
+    let determinant = angle_check[0] * angle_check[2] - angle_check[1].powi(2);
+    if determinant < 1e-6 * (angle_check[0] + angle_check[2]).powi(2) {
+        return Err(TriangulationError::ParallelBearings);
+    }
+
+    let solution = least_squares(&rows, &values);
+    let (x, y) = (solution[0], solution[1]);
+    if !x.is_finite() || !y.is_finite() {
+        return Err(TriangulationError::ParallelBearings);
+    }
+
+    // Covariance of the weighted least squares, (JᵀJ)⁻¹
+    let mut normal = [0.0; 3];
+    let mut chi_squared = 0.0;
+    for (row, value) in rows.iter().zip(&values) {
+        normal[0] += row[0] * row[0];
+        normal[1] += row[0] * row[1];
+        normal[2] += row[1] * row[1];
+        chi_squared += (row[0] * x + row[1] * y - value).powi(2);
+    }
+    let determinant = normal[0] * normal[2] - normal[1].powi(2);
+    // With extra bearings, grow the ellipse if they disagree more than the bearing error suggests
+    let redundancy = lines.len() as Float - 2.0;
+    let variance_factor = if redundancy > 0.0 {
+        (chi_squared / redundancy).max(1.0)
+    } else {
+        1.0
+    };
+    let scale = variance_factor / determinant;
+    Ok((
+        Point2D::new(x, y),
+        (normal[2] * scale, -normal[1] * scale, normal[0] * scale),
+    ))
+}
+
+/// Returns the 95% error ellipse of the covariance (xx, xy, yy), with x east and y north
+fn error_ellipse((xx, xy, yy): (Float, Float, Float)) -> ErrorEllipse {
+    let mean = (xx + yy) / 2.0;
+    let spread = (((xx - yy) / 2.0).powi(2) + xy.powi(2)).sqrt();
+    let major = (mean + spread).max(0.0).sqrt() * CONFIDENCE_95;
+    let minor = (mean - spread).max(0.0).sqrt() * CONFIDENCE_95;
+    // The angle of the major axis from the x axis, converted to a bearing from north
+    let angle = 0.5 * (2.0 * xy).atan2(xx - yy);
+    let orientation = (90.0 - angle.to_degrees()).rem_euclid(180.0);
+    ErrorEllipse {
+        semi_major_axis: Quantity::new(major, Distance::Meters),
+        semi_minor_axis: Quantity::new(minor, Distance::Meters),
+        orientation: Bearing::new(orientation),
+    }
+}
+
+fn mean_coordinate(observations: &[BearingObservation]) -> Coordinate {
+    // Average unit vectors so locations on either side of the antimeridian don't cancel out
+    let mut sum = [0.0; 3];
+    for observation in observations {
+        let (sin_phi, cos_phi) = observation.coordinate.latitude.to_radians().sin_cos();
+        let (sin_lambda, cos_lambda) = observation.coordinate.longitude.to_radians().sin_cos();
+        sum[0] += cos_phi * cos_lambda;
+        sum[1] += cos_phi * sin_lambda;
+        sum[2] += sin_phi;
+    }
+    Coordinate::new(
+        sum[2].atan2(sum[0].hypot(sum[1])).to_degrees(),
+        sum[1].atan2(sum[0]).to_degrees(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::assert_approx_eq;
+
+    fn degrees(value: Float) -> Quantity<Angle> {
+        Quantity::new(value, Angle::Degrees)
+    }
+
+    /// Observations from the known locations to the target
+    fn bearings_to(target: &Coordinate, locations: &[Coordinate]) -> Vec<BearingObservation> {
+        locations
+            .iter()
+            .map(|location| BearingObservation::new(*location, location.bearing_to(target)))
+            .collect()
+    }
+
+    #[rstest]
+    #[case(Coordinate::new(0.01, 0.01), vec![Coordinate::new(0.0, 0.0), Coordinate::new(0.0, 0.02)])]
+    #[case(Coordinate::new(45.2, -70.1), vec![Coordinate::new(45.0, -70.0), Coordinate::new(45.1, -70.4), Coordinate::new(45.3, -69.9)])]
+    #[case(Coordinate::new(0.0, 180.0), vec![Coordinate::new(0.1, 179.9), Coordinate::new(-0.1, -179.95)])]
+    fn test_triangulate(#[case] target: Coordinate, #[case] observers: Vec<Coordinate>) {
+        let fix = triangulate(&bearings_to(&target, &observers), &degrees(1.0)).unwrap();
+        assert_approx_eq!(fix.coordinate.distance_to(&target).amount, 0.0, 5.0);
+    }
+
+    #[test]
+    fn test_resection() {
+        let position = Coordinate::new(45.0, -70.0);
+        let landmarks: Vec<BearingObservation> = [(3.0, 10.0), (5.0, 120.0), (4.0, 250.0)]
+            .iter()
+            .map(|(kilometers, bearing)| {
+                let landmark = position.destination(
+                    &Quantity::new(*kilometers, Distance::Kilometers),
+                    &Bearing::new(*bearing),
+                );
+                BearingObservation::new(landmark, position.bearing_to(&landmark))
+            })
+            .collect();
+
+        let fix = resection(&landmarks, &degrees(1.0)).unwrap();
+        assert_approx_eq!(fix.coordinate.distance_to(&position).amount, 0.0, 2.0);
+        assert!(fix.error.semi_major_axis.amount >= fix.error.semi_minor_axis.amount);
+        assert!(fix.error.semi_minor_axis.amount > 10.0);
+        assert!(fix.error.semi_major_axis.amount < 500.0);
+
+        // Two bearings still give a fix
+        let fix = resection(&landmarks[..2], &degrees(1.0)).unwrap();
+        assert_approx_eq!(fix.coordinate.distance_to(&position).amount, 0.0, 2.0);
+    }
+
+    #[test]
+    fn test_inconsistent_bearings_grow_the_ellipse() {
+        let target = Coordinate::new(45.2, -70.1);
+        let observers = [
+            Coordinate::new(45.0, -70.0),
+            Coordinate::new(45.1, -70.4),
+            Coordinate::new(45.3, -69.9),
+        ];
+        let exact = triangulate(&bearings_to(&target, &observers), &degrees(0.5)).unwrap();
+        let mut observations = bearings_to(&target, &observers);
+        observations[0].bearing = Bearing::new(observations[0].bearing.degrees + 5.0);
+        let inconsistent = triangulate(&observations, &degrees(0.5)).unwrap();
+        assert!(
+            inconsistent.error.semi_major_axis.amount > 1.2 * exact.error.semi_major_axis.amount
+        );
+    }
+
+    #[test]
+    fn test_error_ellipse() {
+        // A close bearing from the south pins down east-west, and a far bearing from the west leaves
+        // north-south uncertain
+        let target = Coordinate::new(0.01, 0.0);
+        let observers = [Coordinate::new(0.0, 0.0), Coordinate::new(0.01, -0.09)];
+        let fix = triangulate(&bearings_to(&target, &observers), &degrees(1.0)).unwrap();
+
+        let near = 1105.74 * (1.0 as Float).to_radians() * CONFIDENCE_95;
+        let far = 10018.76 * (1.0 as Float).to_radians() * CONFIDENCE_95;
+        assert_approx_eq!(fix.error.semi_major_axis.amount, far, 5.0);
+        assert_approx_eq!(fix.error.semi_minor_axis.amount, near, 1.0);
+        let orientation = fix.error.orientation.degrees;
+        assert!(!(0.5..=179.5).contains(&orientation));
+    }
+
+    #[rstest]
+    #[case(vec![], TriangulationError::NotEnoughBearings { actual: 0 })]
+    #[case(vec![BearingObservation::new(Coordinate::new(0.0, 0.0), Bearing::new(10.0))], TriangulationError::NotEnoughBearings { actual: 1 })]
+    #[case(vec![BearingObservation::new(Coordinate::new(0.0, 0.0), Bearing::new(0.0)), BearingObservation::new(Coordinate::new(0.0, 0.01), Bearing::new(0.0))], TriangulationError::ParallelBearings)]
+    #[case(vec![BearingObservation::new(Coordinate::new(0.0, 0.0), Bearing::new(90.0)), BearingObservation::new(Coordinate::new(0.0, 0.01), Bearing::new(270.0))], TriangulationError::ParallelBearings)]
+    #[case(vec![BearingObservation::new(Coordinate::new(0.0, 0.0), Bearing::new(315.0)), BearingObservation::new(Coordinate::new(0.0, 0.02), Bearing::new(45.0))], TriangulationError::NoIntersection)]
+    fn test_triangulate_invalid(
+        #[case] observations: Vec<BearingObservation>,
+        #[case] expected: TriangulationError,
+    ) {
+        assert_eq!(triangulate(&observations, &degrees(1.0)), Err(expected));
+    }
+
+    #[test]
+    fn test_resection_behind_landmarks() {
+        let landmarks = [
+            BearingObservation::new(Coordinate::new(0.01, 0.0), Bearing::new(135.0)),
+            BearingObservation::new(Coordinate::new(0.01, 0.02), Bearing::new(225.0)),
+        ];
+        assert_eq!(
+            resection(&landmarks, &degrees(1.0)),
+            Err(TriangulationError::NoIntersection)
+        );
+    }
+}
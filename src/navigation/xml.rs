@@ -0,0 +1,384 @@
+//! A minimal XML reader and writer, supporting the subset needed for GPX files (no DTDs)
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Element {
+    pub name: String,
+    pub attributes: Vec<(String, String)>,
+    pub children: Vec<Element>,
+    pub text: String,
+}
+
+impl Element {
+    pub fn new(name: &str) -> Self {
+        Element {
+            name: name.to_string(),
+            ..Default::default()
+        }
+    }
+
+    pub fn attribute(&self, name: &str) -> Option<&str> {
+        self.attributes
+            .iter()
+            .find(|(key, _)| key == name)
+            .map(|(_, value)| value.as_str())
+    }
+
+    pub fn child(&self, name: &str) -> Option<&Element> {
+        self.children.iter().find(|child| child.name == name)
+    }
+
+    pub fn children_named<'a>(&'a self, name: &'a str) -> impl Iterator<Item = &'a Element> {
+        self.children.iter().filter(move |child| child.name == name)
+    }
+
+    /// Returns the trimmed text of the child, if it exists and is not empty
+    pub fn child_text(&self, name: &str) -> Option<&str> {
+        self.child(name)
+            .map(|child| child.text.trim())
+            .filter(|text| !text.is_empty())
+    }
+
+    pub fn with_attribute(mut self, name: &str, value: &str) -> Self {
+        self.attributes.push((name.to_string(), value.to_string()));
+        self
+    }
+
+    pub fn with_child(mut self, child: Element) -> Self {
+        self.children.push(child);
+        self
+    }
+
+    pub fn with_text(mut self, text: &str) -> Self {
+        self.text = text.to_string();
+        self
+    }
+
+    /// Writes the element as indented XML, with an XML declaration
+    pub fn to_document(&self) -> String {
+        let mut xml = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+        self.write(&mut xml, 0);
+        xml
+    }
+
+    fn write(&self, xml: &mut String, depth: usize) {
+        let indent = "  ".repeat(depth);
+        xml.push_str(&indent);
+        xml.push('<');
+        xml.push_str(&self.name);
+        for (name, value) in &self.attributes {
+            xml.push_str(&format!(" {}=\"{}\"", name, escape(value)));
+        }
+        if self.children.is_empty() && self.text.is_empty() {
+            xml.push_str("/>\n");
+            return;
+        }
+        xml.push('>');
+        if self.children.is_empty() {
+            xml.push_str(&escape(&self.text));
+        } else {
+            xml.push('\n');
+            for child in &self.children {
+                child.write(xml, depth + 1);
+            }
+            xml.push_str(&indent);
+        }
+        xml.push_str(&format!("</{}>\n", self.name));
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct InvalidXml(pub String);
+
+impl std::fmt::Display for InvalidXml {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "invalid xml: {}", self.0)
+    }
+}
+
+impl std::error::Error for InvalidXml {}
+
+/// Parses the root element of the document. Namespace prefixes are removed from element and
+/// attribute names.
+pub fn parse_xml(xml: &str) -> Result<Element, InvalidXml> {
+    let mut reader = Reader { xml, position: 0 };
+    reader.document().map_err(InvalidXml)
+}
+
+/// Elements nested deeper than this are rejected, so malicious input can't overflow the stack
+const MAX_DEPTH: usize = 128;
+
+struct Reader<'a> {
+    xml: &'a str,
+    position: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn rest(&self) -> &'a str {
+        &self.xml[self.position..]
+    }
+
+    fn document(&mut self) -> Result<Element, String> {
+        self.skip_prolog()?;
+        let root = self.element(0)?;
+        self.skip_misc()?;
+        if !self.rest().is_empty() {
+            return Err("unexpected content after the root element".to_string());
+        }
+        Ok(root)
+    }
+
+    fn skip_whitespace(&mut self) {
+        let rest = self.rest();
+        self.position += rest.len() - rest.trim_start().len();
+    }
+
+    fn skip_past(&mut self, end: &str) -> Result<(), String> {
+        match self.rest().find(end) {
+            Some(index) => {
+                self.position += index + end.len();
+                Ok(())
+            }
+            None => Err(format!("missing '{end}'")),
+        }
+    }
+
+    /// Skips whitespace, comments and processing instructions
+    fn skip_misc(&mut self) -> Result<(), String> {
+        loop {
+            self.skip_whitespace();
+            if self.rest().starts_with("<?") {
+                self.skip_past("?>")?;
+            } else if self.rest().starts_with("<!--") {
+                self.skip_past("-->")?;
+            } else {
+                return Ok(());
+            }
+        }
+    }
+
+    fn skip_prolog(&mut self) -> Result<(), String> {
+        if self.rest().starts_with('\u{feff}') {
+            self.position += '\u{feff}'.len_utf8();
+        }
+        self.skip_misc()?;
+        if self.rest().starts_with("<!DOCTYPE") {
+            self.skip_past(">")?;
+            self.skip_misc()?;
+        }
+        Ok(())
+    }
+
+    /// Reads a name, including its namespace prefix
+    fn name(&mut self) -> Result<&'a str, String> {
+        let rest = self.rest();
+        let length = rest
+            .find(|c: char| c.is_whitespace() || matches!(c, '>' | '/' | '='))
+            .unwrap_or(rest.len());
+        if length == 0 {
+            return Err("expected a name".to_string());
+        }
+        self.position += length;
+        Ok(&rest[..length])
+    }
+
+    fn expect(&mut self, text: &str) -> Result<(), String> {
+        if self.rest().starts_with(text) {
+            self.position += text.len();
+            Ok(())
+        } else {
+            Err(format!("expected '{text}'"))
+        }
+    }
+
+    fn element(&mut self, depth: usize) -> Result<Element, String> {
+        if depth > MAX_DEPTH {
+            return Err("elements are too deeply nested".to_string());
+        }
+        self.expect("<")?;
+        let qualified_name = self.name()?;
+        let mut element = Element::new(local_name(qualified_name));
+
+        // Attributes
+        loop {
+            self.skip_whitespace();
+            if self.rest().starts_with("/>") {
+                self.position += 2;
+                return Ok(element);
+            }
+            if self.rest().starts_with('>') {
+                self.position += 1;
+                break;
+            }
+            let name = local_name(self.name()?).to_string();
+            self.skip_whitespace();
+            self.expect("=")?;
+            self.skip_whitespace();
+            let quote = self
+                .rest()
+                .chars()
+                .next()
+                .filter(|c| *c == '"' || *c == '\'')
+                .ok_or("expected a quoted attribute value")?;
+            self.position += 1;
+            let length = self
+                .rest()
+                .find(quote)
+                .ok_or("unterminated attribute value")?;
+            let value = unescape(&self.rest()[..length])?;
+            self.position += length + 1;
+            element.attributes.push((name, value));
+        }
+
+        // Content
+        loop {
+            let rest = self.rest();
+            if rest.is_empty() {
+                return Err(format!("missing closing tag for '{}'", element.name));
+            } else if rest.starts_with("</") {
+                self.position += 2;
+                let name = self.name()?;
+                if name != qualified_name {
+                    return Err(format!(
+                        "expected closing tag for '{qualified_name}' but found '{name}'"
+                    ));
+                }
+                self.skip_whitespace();
+                self.expect(">")?;
+                // Drop the whitespace used to indent children
+                if !element.children.is_empty() && element.text.trim().is_empty() {
+                    element.text.clear();
+                }
+                return Ok(element);
+            } else if rest.starts_with("<!--") {
+                self.skip_past("-->")?;
+            } else if rest.starts_with("<![CDATA[") {
+                self.position += "<![CDATA[".len();
+                let length = self.rest().find("]]>").ok_or("unterminated CDATA")?;
+                element.text.push_str(&self.rest()[..length]);
+                self.position += length + "]]>".len();
+            } else if rest.starts_with("<?") {
+                self.skip_past("?>")?;
+            } else if rest.starts_with('<') {
+                let child = self.element(depth + 1)?;
+                element.children.push(child);
+            } else {
+                let length = rest.find('<').unwrap_or(rest.len());
+                element.text.push_str(&unescape(&rest[..length])?);
+                self.position += length;
+            }
+        }
+    }
+}
+
+/// Removes the namespace prefix from the name
+fn local_name(name: &str) -> &str {
+    name.rsplit(':').next().unwrap_or(name)
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&apos;")
+}
+
+fn unescape(text: &str) -> Result<String, String> {
+    let mut unescaped = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find('&') {
+        unescaped.push_str(&rest[..start]);
+        let end = rest[start..]
+            .find(';')
+            .ok_or("unterminated entity reference")?
+            + start;
+        let entity = &rest[start + 1..end];
+        let character = match entity {
+            "amp" => Some('&'),
+            "lt" => Some('<'),
+            "gt" => Some('>'),
+            "quot" => Some('"'),
+            "apos" => Some('\''),
+            _ => {
+                let code = if let Some(hex) = entity.strip_prefix("#x") {
+                    u32::from_str_radix(hex, 16).ok()
+                } else if let Some(decimal) = entity.strip_prefix('#') {
+                    decimal.parse().ok()
+                } else {
+                    None
+                };
+                code.and_then(char::from_u32)
+            }
+        };
+        unescaped.push(character.ok_or_else(|| format!("unknown entity '&{entity};'"))?);
+        rest = &rest[end + 1..];
+    }
+    unescaped.push_str(rest);
+    Ok(unescaped)
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[test]
+    fn test_parse_xml() {
+        let xml = r#"<?xml version="1.0"?>
+            <!-- A comment -->
+            <gpx:root version='1.1' a="x &amp; y">
+                <item id="1"/>
+                <item id="2">Hello &lt;world&gt; &#65;&#x42;</item>
+                <note><![CDATA[<b>bold</b>]]></note>
+            </gpx:root>"#;
+        let root = parse_xml(xml).unwrap();
+        assert_eq!(root.name, "root");
+        assert_eq!(root.attribute("version"), Some("1.1"));
+        assert_eq!(root.attribute("a"), Some("x & y"));
+        let items: Vec<&Element> = root.children_named("item").collect();
+        assert_eq!(items.len(), 2);
+        assert_eq!(items[0].attribute("id"), Some("1"));
+        assert_eq!(items[1].text, "Hello <world> AB");
+        assert_eq!(root.child_text("note"), Some("<b>bold</b>"));
+        assert_eq!(root.child_text("missing"), None);
+    }
+
+    #[rstest]
+    #[case("")]
+    #[case("<a>")]
+    #[case("<a></b>")]
+    #[case("<a:x></b:x>")]
+    #[case("<a:x></x>")]
+    #[case("<a b=c/>")]
+    #[case("<a>&unknown;</a>")]
+    #[case("<a/><b/>")]
+    fn test_parse_xml_invalid(#[case] xml: &str) {
+        assert!(parse_xml(xml).is_err());
+    }
+
+    #[test]
+    fn test_parse_xml_too_deep() {
+        let xml = "<a>".repeat(100_000);
+        assert_eq!(
+            parse_xml(&xml),
+            Err(InvalidXml("elements are too deeply nested".to_string()))
+        );
+        let xml = format!("{}{}", "<a>".repeat(100), "</a>".repeat(100));
+        assert!(parse_xml(&xml).is_ok());
+    }
+
+    #[test]
+    fn test_write_xml() {
+        let element = Element::new("root")
+            .with_attribute("name", "a \"quoted\" & <value>")
+            .with_child(Element::new("empty"))
+            .with_child(Element::new("text").with_text("1 < 2"));
+        let xml = element.to_document();
+        assert_eq!(
+            xml,
+            "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<root name=\"a &quot;quoted&quot; &amp; &lt;value&gt;\">\n  <empty/>\n  <text>1 &lt; 2</text>\n</root>\n"
+        );
+        assert_eq!(parse_xml(&xml).unwrap(), element);
+    }
+}
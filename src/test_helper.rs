@@ -32,3 +32,15 @@ macro_rules! assert_tensor_eq {
         }
     };
 }
+
+/// Asserts that a value serializes to the given JSON and deserializes back to the same value
+#[cfg(all(test, feature = "serde"))]
+pub fn assert_serde_round_trip<T>(value: &T, json: &str)
+where
+    T: serde::Serialize + serde::de::DeserializeOwned + PartialEq + std::fmt::Debug,
+{
+    let actual = serde_json::to_string(value).unwrap();
+    assert_eq!(actual, json);
+    let deserialized: T = serde_json::from_str(&actual).unwrap();
+    assert_eq!(&deserialized, value);
+}
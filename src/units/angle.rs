@@ -1,13 +1,27 @@
-use super::quantity::{Quantity, Unit};
+use crate::math::{Float, consts::PI};
+
+use super::{
+    format::UnitNames,
+    parse::UnitAliases,
+    quantity::{AdditiveUnit, Quantity, Unit},
+};
 
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Angle {
     Radians = 1,
     Degrees = 2,
+    Gradians = 3,
+    /// NATO mils, with 6400 in a circle
+    NatoMils = 4,
+    /// Warsaw Pact mils, with 6000 in a circle
+    WarsawPactMils = 5,
+    ArcMinutes = 6,
+    ArcSeconds = 7,
 }
 
 impl Angle {
-    pub fn degrees(value: f32) -> Quantity<Angle> {
+    pub fn degrees(value: Float) -> Quantity<Angle> {
         Quantity {
             amount: value,
             units: Angle::Degrees,
@@ -16,18 +30,125 @@ impl Angle {
 }
 
 impl Unit for Angle {
-    fn multiplier_to_base(&self) -> f32 {
+    fn multiplier_to_base(&self) -> Float {
         match self {
             Angle::Radians => 1.0,
-            Angle::Degrees => 0.017453292,
+            Angle::Degrees => PI / 180.0,
+            Angle::Gradians => PI / 200.0,
+            Angle::NatoMils => PI / 3200.0,
+            Angle::WarsawPactMils => PI / 3000.0,
+            Angle::ArcMinutes => PI / 10800.0,
+            Angle::ArcSeconds => PI / 648000.0,
         }
     }
 }
 
+impl AdditiveUnit for Angle {}
+
+impl UnitAliases for Angle {
+    fn all() -> &'static [Self] {
+        &[
+            Angle::Radians,
+            Angle::Degrees,
+            Angle::Gradians,
+            Angle::NatoMils,
+            Angle::WarsawPactMils,
+            Angle::ArcMinutes,
+            Angle::ArcSeconds,
+        ]
+    }
+
+    fn aliases(&self) -> &'static [&'static str] {
+        match self {
+            Angle::Radians => &["rad", "rads", "radian", "radians"],
+            Angle::Degrees => &["°", "deg", "degs", "degree", "degrees"],
+            Angle::Gradians => &["gon", "gons", "grad", "grads", "gradian", "gradians"],
+            Angle::NatoMils => &["mil", "mils", "nato mil", "nato mils"],
+            Angle::WarsawPactMils => &[
+                "mil (wp)",
+                "wp mil",
+                "wp mils",
+                "warsaw pact mil",
+                "warsaw pact mils",
+            ],
+            Angle::ArcMinutes => &[
+                "′",
+                "'",
+                "arcmin",
+                "arcmins",
+                "arcminute",
+                "arcminutes",
+                "moa",
+            ],
+            Angle::ArcSeconds => &[
+                "″",
+                "\"",
+                "''",
+                "arcsec",
+                "arcsecs",
+                "arcsecond",
+                "arcseconds",
+            ],
+        }
+    }
+}
+
+impl UnitNames for Angle {
+    fn symbol(&self) -> &'static str {
+        match self {
+            Angle::Radians => "rad",
+            Angle::Degrees => "°",
+            Angle::Gradians => "gon",
+            Angle::NatoMils => "mil",
+            Angle::WarsawPactMils => "mil (WP)",
+            Angle::ArcMinutes => "′",
+            Angle::ArcSeconds => "″",
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        match self {
+            Angle::Radians => "radian",
+            Angle::Degrees => "degree",
+            Angle::Gradians => "gradian",
+            Angle::NatoMils => "NATO mil",
+            Angle::WarsawPactMils => "Warsaw Pact mil",
+            Angle::ArcMinutes => "arcminute",
+            Angle::ArcSeconds => "arcsecond",
+        }
+    }
+
+    fn plural_name(&self) -> &'static str {
+        match self {
+            Angle::Radians => "radians",
+            Angle::Degrees => "degrees",
+            Angle::Gradians => "gradians",
+            Angle::NatoMils => "NATO mils",
+            Angle::WarsawPactMils => "Warsaw Pact mils",
+            Angle::ArcMinutes => "arcminutes",
+            Angle::ArcSeconds => "arcseconds",
+        }
+    }
+
+    fn symbol_separator(&self) -> &'static str {
+        match self {
+            Angle::Degrees | Angle::ArcMinutes | Angle::ArcSeconds => "",
+            _ => " ",
+        }
+    }
+}
+
+impl std::fmt::Display for Angle {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.symbol())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::{
         assert_approx_eq,
+        math::Float,
         units::{
             angle::Angle,
             quantity::{Convertable, Quantity},
@@ -43,11 +164,20 @@ mod tests {
     #[case(180.0, Angle::Degrees, Angle::Radians, 3.14159)]
     #[case(360.0, Angle::Degrees, Angle::Radians, 6.28319)]
     #[case(90.0, Angle::Degrees, Angle::Radians, 1.5708)]
+    #[case(90.0, Angle::Degrees, Angle::Gradians, 100.0)]
+    #[case(400.0, Angle::Gradians, Angle::Degrees, 360.0)]
+    #[case(360.0, Angle::Degrees, Angle::NatoMils, 6400.0)]
+    #[case(1600.0, Angle::NatoMils, Angle::Degrees, 90.0)]
+    #[case(360.0, Angle::Degrees, Angle::WarsawPactMils, 6000.0)]
+    #[case(6400.0, Angle::NatoMils, Angle::WarsawPactMils, 6000.0)]
+    #[case(1.5, Angle::Degrees, Angle::ArcMinutes, 90.0)]
+    #[case(1.0, Angle::ArcMinutes, Angle::ArcSeconds, 60.0)]
+    #[case(3600.0, Angle::ArcSeconds, Angle::Degrees, 1.0)]
     fn can_convert_between_angle_units(
-        #[case] amount: f32,
+        #[case] amount: Float,
         #[case] units: Angle,
         #[case] to_units: Angle,
-        #[case] expected_amount: f32,
+        #[case] expected_amount: Float,
     ) {
         let actual = Quantity { amount, units }.convert(to_units);
         assert_approx_eq!(actual.amount, expected_amount);
@@ -0,0 +1,243 @@
+use crate::math::Float;
+
+use super::{
+    distance::Distance,
+    format::UnitNames,
+    parse::UnitAliases,
+    quantity::{AdditiveUnit, Convertable, Quantity, Unit},
+};
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Area {
+    SquareMeters = 1,
+    SquareKilometers = 2,
+    SquareCentimeters = 3,
+    Hectares = 4,
+    SquareInches = 5,
+    SquareFeet = 6,
+    SquareYards = 7,
+    SquareMiles = 8,
+    Acres = 9,
+}
+
+impl Unit for Area {
+    fn multiplier_to_base(&self) -> Float {
+        match self {
+            Area::SquareMeters => 1.0,
+            Area::SquareKilometers => 1_000_000.0,
+            Area::SquareCentimeters => 0.0001,
+            Area::Hectares => 10_000.0,
+            Area::SquareInches => 0.00064516,
+            Area::SquareFeet => 0.09290304,
+            Area::SquareYards => 0.83612736,
+            Area::SquareMiles => 2_589_988.0,
+            Area::Acres => 4046.8564,
+        }
+    }
+}
+
+impl AdditiveUnit for Area {}
+
+/// Distance * Distance = Area (in square meters)
+impl std::ops::Mul<Quantity<Distance>> for Quantity<Distance> {
+    type Output = Quantity<Area>;
+
+    fn mul(self, rhs: Quantity<Distance>) -> Quantity<Area> {
+        let meters1 = self.convert(Distance::Meters).amount;
+        let meters2 = rhs.convert(Distance::Meters).amount;
+        Quantity::new(meters1 * meters2, Area::SquareMeters)
+    }
+}
+
+/// Area / Distance = Distance (in meters)
+impl std::ops::Div<Quantity<Distance>> for Quantity<Area> {
+    type Output = Quantity<Distance>;
+
+    fn div(self, rhs: Quantity<Distance>) -> Quantity<Distance> {
+        let square_meters = self.convert(Area::SquareMeters).amount;
+        let meters = rhs.convert(Distance::Meters).amount;
+        Quantity::new(square_meters / meters, Distance::Meters)
+    }
+}
+
+impl UnitAliases for Area {
+    fn all() -> &'static [Self] {
+        &[
+            Area::SquareMeters,
+            Area::SquareKilometers,
+            Area::SquareCentimeters,
+            Area::Hectares,
+            Area::SquareInches,
+            Area::SquareFeet,
+            Area::SquareYards,
+            Area::SquareMiles,
+            Area::Acres,
+        ]
+    }
+
+    fn aliases(&self) -> &'static [&'static str] {
+        match self {
+            Area::SquareMeters => &[
+                "m²",
+                "sq m",
+                "square meter",
+                "square meters",
+                "square metre",
+                "square metres",
+            ],
+            Area::SquareKilometers => &[
+                "km²",
+                "sq km",
+                "square kilometer",
+                "square kilometers",
+                "square kilometre",
+                "square kilometres",
+            ],
+            Area::SquareCentimeters => &[
+                "cm²",
+                "sq cm",
+                "square centimeter",
+                "square centimeters",
+                "square centimetre",
+                "square centimetres",
+            ],
+            Area::Hectares => &["ha", "hectare", "hectares"],
+            Area::SquareInches => &["in²", "sq in", "square inch", "square inches"],
+            Area::SquareFeet => &["ft²", "sq ft", "square foot", "square feet"],
+            Area::SquareYards => &["yd²", "sq yd", "square yard", "square yards"],
+            Area::SquareMiles => &["mi²", "sq mi", "square mile", "square miles"],
+            Area::Acres => &["ac", "acre", "acres"],
+        }
+    }
+}
+
+impl UnitNames for Area {
+    fn symbol(&self) -> &'static str {
+        match self {
+            Area::SquareMeters => "m²",
+            Area::SquareKilometers => "km²",
+            Area::SquareCentimeters => "cm²",
+            Area::Hectares => "ha",
+            Area::SquareInches => "in²",
+            Area::SquareFeet => "ft²",
+            Area::SquareYards => "yd²",
+            Area::SquareMiles => "mi²",
+            Area::Acres => "ac",
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        match self {
+            Area::SquareMeters => "square meter",
+            Area::SquareKilometers => "square kilometer",
+            Area::SquareCentimeters => "square centimeter",
+            Area::Hectares => "hectare",
+            Area::SquareInches => "square inch",
+            Area::SquareFeet => "square foot",
+            Area::SquareYards => "square yard",
+            Area::SquareMiles => "square mile",
+            Area::Acres => "acre",
+        }
+    }
+
+    fn plural_name(&self) -> &'static str {
+        match self {
+            Area::SquareMeters => "square meters",
+            Area::SquareKilometers => "square kilometers",
+            Area::SquareCentimeters => "square centimeters",
+            Area::Hectares => "hectares",
+            Area::SquareInches => "square inches",
+            Area::SquareFeet => "square feet",
+            Area::SquareYards => "square yards",
+            Area::SquareMiles => "square miles",
+            Area::Acres => "acres",
+        }
+    }
+}
+
+impl std::fmt::Display for Area {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.symbol())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use crate::{
+        assert_approx_eq,
+        math::Float,
+        units::{
+            area::Area,
+            distance::Distance,
+            quantity::{Convertable, Quantity},
+        },
+    };
+
+    #[rstest]
+    #[case(1.0, Area::SquareMeters, Area::SquareMeters, 1.0)]
+    #[case(1.0, Area::SquareKilometers, Area::SquareMeters, 1_000_000.0)]
+    #[case(1.0, Area::SquareMeters, Area::SquareCentimeters, 10_000.0)]
+    #[case(1.0, Area::Hectares, Area::SquareMeters, 10_000.0)]
+    #[case(1.0, Area::SquareFeet, Area::SquareInches, 144.0)]
+    #[case(1.0, Area::SquareYards, Area::SquareFeet, 9.0)]
+    #[case(1.0, Area::SquareMiles, Area::Acres, 640.0)]
+    #[case(1.0, Area::Acres, Area::Hectares, 0.404686)]
+    #[case(1.0, Area::SquareMiles, Area::SquareKilometers, 2.589988)]
+    fn can_convert_between_area_units(
+        #[case] amount: Float,
+        #[case] units: Area,
+        #[case] to_units: Area,
+        #[case] expected_amount: Float,
+    ) {
+        let actual = Quantity { amount, units }.convert(to_units);
+        assert!(
+            (actual.amount - expected_amount).abs() <= 0.01,
+            "quantities not equal - expected: {} {:?}, actual: {} {:?}",
+            actual.amount,
+            actual.units,
+            expected_amount,
+            to_units
+        );
+
+        assert_eq!(actual.units, to_units);
+    }
+
+    #[rstest]
+    #[case(
+        Quantity::new(2.0, Distance::Meters),
+        Quantity::new(3.0, Distance::Meters),
+        6.0
+    )]
+    #[case(
+        Quantity::new(1.0, Distance::Kilometers),
+        Quantity::new(10.0, Distance::Meters),
+        10_000.0
+    )]
+    #[case(
+        Quantity::new(1.0, Distance::Feet),
+        Quantity::new(1.0, Distance::Yards),
+        0.278709
+    )]
+    fn can_multiply_distances(
+        #[case] distance1: Quantity<Distance>,
+        #[case] distance2: Quantity<Distance>,
+        #[case] expected_square_meters: Float,
+    ) {
+        let area = distance1 * distance2;
+        assert_approx_eq!(area.amount, expected_square_meters, 0.0001);
+        assert_eq!(area.units, Area::SquareMeters);
+    }
+
+    #[test]
+    fn can_divide_area_by_distance() {
+        let area = Quantity::new(1.0, Area::Hectares);
+        let distance = Quantity::new(50.0, Distance::Meters);
+
+        let length = area / distance;
+        assert_approx_eq!(length.amount, 200.0, 0.001);
+        assert_eq!(length.units, Distance::Meters);
+    }
+}
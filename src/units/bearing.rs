@@ -1,4 +1,11 @@
+use crate::math::{
+    Float,
+    trigonometry::{atan2_degrees, cos_degrees, sin_degrees},
+    utils::is_approximately_zero,
+};
+
 #[derive(Debug, Eq, PartialEq, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum CompassDirection {
     North,
     NorthEast,
@@ -22,7 +29,7 @@ const ALL_COMPASS_DIRECTIONS: [CompassDirection; 8] = [
 ];
 
 impl CompassDirection {
-    fn azimuth(&self) -> f32 {
+    fn azimuth(&self) -> Float {
         match self {
             CompassDirection::North => 0.0,
             CompassDirection::NorthEast => 45.0,
@@ -36,12 +43,270 @@ impl CompassDirection {
     }
 }
 
+/// The number of points on a compass rose
+#[derive(Debug, Eq, PartialEq, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum CompassRose {
+    FourPoint,
+    EightPoint,
+    SixteenPoint,
+    ThirtyTwoPoint,
+}
+
+impl CompassRose {
+    pub fn point_count(&self) -> usize {
+        match self {
+            CompassRose::FourPoint => 4,
+            CompassRose::EightPoint => 8,
+            CompassRose::SixteenPoint => 16,
+            CompassRose::ThirtyTwoPoint => 32,
+        }
+    }
+
+    /// Returns the points of the compass rose, clockwise from north
+    pub fn points(&self) -> impl Iterator<Item = CompassPoint> {
+        let step = ALL_COMPASS_POINTS.len() / self.point_count();
+        ALL_COMPASS_POINTS.into_iter().step_by(step)
+    }
+}
+
+/// A point on the 32-point compass rose, spaced 11.25° apart
+#[derive(Debug, Eq, PartialEq, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum CompassPoint {
+    North,
+    NorthByEast,
+    NorthNorthEast,
+    NorthEastByNorth,
+    NorthEast,
+    NorthEastByEast,
+    EastNorthEast,
+    EastByNorth,
+    East,
+    EastBySouth,
+    EastSouthEast,
+    SouthEastByEast,
+    SouthEast,
+    SouthEastBySouth,
+    SouthSouthEast,
+    SouthByEast,
+    South,
+    SouthByWest,
+    SouthSouthWest,
+    SouthWestBySouth,
+    SouthWest,
+    SouthWestByWest,
+    WestSouthWest,
+    WestBySouth,
+    West,
+    WestByNorth,
+    WestNorthWest,
+    NorthWestByWest,
+    NorthWest,
+    NorthWestByNorth,
+    NorthNorthWest,
+    NorthByWest,
+}
+
+pub const ALL_COMPASS_POINTS: [CompassPoint; 32] = [
+    CompassPoint::North,
+    CompassPoint::NorthByEast,
+    CompassPoint::NorthNorthEast,
+    CompassPoint::NorthEastByNorth,
+    CompassPoint::NorthEast,
+    CompassPoint::NorthEastByEast,
+    CompassPoint::EastNorthEast,
+    CompassPoint::EastByNorth,
+    CompassPoint::East,
+    CompassPoint::EastBySouth,
+    CompassPoint::EastSouthEast,
+    CompassPoint::SouthEastByEast,
+    CompassPoint::SouthEast,
+    CompassPoint::SouthEastBySouth,
+    CompassPoint::SouthSouthEast,
+    CompassPoint::SouthByEast,
+    CompassPoint::South,
+    CompassPoint::SouthByWest,
+    CompassPoint::SouthSouthWest,
+    CompassPoint::SouthWestBySouth,
+    CompassPoint::SouthWest,
+    CompassPoint::SouthWestByWest,
+    CompassPoint::WestSouthWest,
+    CompassPoint::WestBySouth,
+    CompassPoint::West,
+    CompassPoint::WestByNorth,
+    CompassPoint::WestNorthWest,
+    CompassPoint::NorthWestByWest,
+    CompassPoint::NorthWest,
+    CompassPoint::NorthWestByNorth,
+    CompassPoint::NorthNorthWest,
+    CompassPoint::NorthByWest,
+];
+
+impl CompassPoint {
+    pub fn azimuth(&self) -> Float {
+        *self as usize as Float * 11.25
+    }
+
+    pub fn bearing(&self) -> Bearing {
+        Bearing::new(self.azimuth())
+    }
+
+    /// Returns the English abbreviation of the point (ex. "NNE" or "NbE")
+    pub fn abbreviation(&self) -> &'static str {
+        EnglishCompassNames.abbreviation(*self)
+    }
+
+    /// Returns the English name of the point (ex. "North-northeast" or "North by east")
+    pub fn name(&self) -> &'static str {
+        EnglishCompassNames.name(*self)
+    }
+
+    /// Finds the point with the given abbreviation or name, ignoring case, spaces and hyphens
+    pub fn parse<N: CompassNames>(text: &str, names: &N) -> Option<CompassPoint> {
+        let normalized = normalize_name(text);
+        if normalized.is_empty() {
+            return None;
+        }
+        ALL_COMPASS_POINTS.into_iter().find(|point| {
+            normalize_name(names.abbreviation(*point)) == normalized
+                || normalize_name(names.name(*point)) == normalized
+        })
+    }
+}
+
+impl From<CompassDirection> for CompassPoint {
+    fn from(direction: CompassDirection) -> Self {
+        ALL_COMPASS_POINTS[direction as usize * 4]
+    }
+}
+
+impl std::str::FromStr for CompassPoint {
+    type Err = UnknownCompassPoint;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        CompassPoint::parse(s, &EnglishCompassNames)
+            .ok_or_else(|| UnknownCompassPoint(s.to_string()))
+    }
+}
+
+impl std::fmt::Display for CompassPoint {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.abbreviation())
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct UnknownCompassPoint(pub String);
+
+impl std::fmt::Display for UnknownCompassPoint {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "unknown compass point: '{}'", self.0)
+    }
+}
+
+impl std::error::Error for UnknownCompassPoint {}
+
+/// The names of compass points in a language. Implement this to display and parse localized names.
+pub trait CompassNames {
+    /// Returns the short form of the point (ex. "NNE")
+    fn abbreviation(&self, point: CompassPoint) -> &str;
+    /// Returns the full name of the point (ex. "North-northeast")
+    fn name(&self, point: CompassPoint) -> &str;
+}
+
+pub struct EnglishCompassNames;
+
+impl CompassNames for EnglishCompassNames {
+    fn abbreviation(&self, point: CompassPoint) -> &'static str {
+        match point {
+            CompassPoint::North => "N",
+            CompassPoint::NorthByEast => "NbE",
+            CompassPoint::NorthNorthEast => "NNE",
+            CompassPoint::NorthEastByNorth => "NEbN",
+            CompassPoint::NorthEast => "NE",
+            CompassPoint::NorthEastByEast => "NEbE",
+            CompassPoint::EastNorthEast => "ENE",
+            CompassPoint::EastByNorth => "EbN",
+            CompassPoint::East => "E",
+            CompassPoint::EastBySouth => "EbS",
+            CompassPoint::EastSouthEast => "ESE",
+            CompassPoint::SouthEastByEast => "SEbE",
+            CompassPoint::SouthEast => "SE",
+            CompassPoint::SouthEastBySouth => "SEbS",
+            CompassPoint::SouthSouthEast => "SSE",
+            CompassPoint::SouthByEast => "SbE",
+            CompassPoint::South => "S",
+            CompassPoint::SouthByWest => "SbW",
+            CompassPoint::SouthSouthWest => "SSW",
+            CompassPoint::SouthWestBySouth => "SWbS",
+            CompassPoint::SouthWest => "SW",
+            CompassPoint::SouthWestByWest => "SWbW",
+            CompassPoint::WestSouthWest => "WSW",
+            CompassPoint::WestBySouth => "WbS",
+            CompassPoint::West => "W",
+            CompassPoint::WestByNorth => "WbN",
+            CompassPoint::WestNorthWest => "WNW",
+            CompassPoint::NorthWestByWest => "NWbW",
+            CompassPoint::NorthWest => "NW",
+            CompassPoint::NorthWestByNorth => "NWbN",
+            CompassPoint::NorthNorthWest => "NNW",
+            CompassPoint::NorthByWest => "NbW",
+        }
+    }
+
+    fn name(&self, point: CompassPoint) -> &'static str {
+        match point {
+            CompassPoint::North => "North",
+            CompassPoint::NorthByEast => "North by east",
+            CompassPoint::NorthNorthEast => "North-northeast",
+            CompassPoint::NorthEastByNorth => "Northeast by north",
+            CompassPoint::NorthEast => "Northeast",
+            CompassPoint::NorthEastByEast => "Northeast by east",
+            CompassPoint::EastNorthEast => "East-northeast",
+            CompassPoint::EastByNorth => "East by north",
+            CompassPoint::East => "East",
+            CompassPoint::EastBySouth => "East by south",
+            CompassPoint::EastSouthEast => "East-southeast",
+            CompassPoint::SouthEastByEast => "Southeast by east",
+            CompassPoint::SouthEast => "Southeast",
+            CompassPoint::SouthEastBySouth => "Southeast by south",
+            CompassPoint::SouthSouthEast => "South-southeast",
+            CompassPoint::SouthByEast => "South by east",
+            CompassPoint::South => "South",
+            CompassPoint::SouthByWest => "South by west",
+            CompassPoint::SouthSouthWest => "South-southwest",
+            CompassPoint::SouthWestBySouth => "Southwest by south",
+            CompassPoint::SouthWest => "Southwest",
+            CompassPoint::SouthWestByWest => "Southwest by west",
+            CompassPoint::WestSouthWest => "West-southwest",
+            CompassPoint::WestBySouth => "West by south",
+            CompassPoint::West => "West",
+            CompassPoint::WestByNorth => "West by north",
+            CompassPoint::WestNorthWest => "West-northwest",
+            CompassPoint::NorthWestByWest => "Northwest by west",
+            CompassPoint::NorthWest => "Northwest",
+            CompassPoint::NorthWestByNorth => "Northwest by north",
+            CompassPoint::NorthNorthWest => "North-northwest",
+            CompassPoint::NorthByWest => "North by west",
+        }
+    }
+}
+
+fn normalize_name(text: &str) -> String {
+    text.chars()
+        .filter(|c| !c.is_whitespace() && *c != '-')
+        .flat_map(|c| c.to_lowercase())
+        .collect()
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub struct Bearing {
-    pub degrees: f32,
+    pub degrees: Float,
 }
 
 impl Bearing {
-    pub fn new(value: f32) -> Self {
+    pub fn new(value: Float) -> Self {
         let normalized = if value.is_nan() || !value.is_finite() {
             0.0
         } else {
@@ -61,7 +326,7 @@ impl Bearing {
             .unwrap_or(&CompassDirection::North)
     }
 
-    pub fn with_declination(&self, declination: f32) -> Bearing {
+    pub fn with_declination(&self, declination: Float) -> Bearing {
         Bearing::new(self.degrees + declination)
     }
 
@@ -73,23 +338,120 @@ impl Bearing {
         Bearing::new(direction.azimuth())
     }
 
-    pub fn get_bearing(degrees: f32) -> f32 {
+    /// Returns the nearest point of the compass rose
+    pub fn compass_point(&self, rose: CompassRose) -> CompassPoint {
+        let count = rose.point_count();
+        let index = (self.degrees / (360.0 / count as Float)).round() as usize % count;
+        ALL_COMPASS_POINTS[index * (ALL_COMPASS_POINTS.len() / count)]
+    }
+
+    pub fn get_bearing(degrees: Float) -> Float {
         if degrees.is_nan() || !degrees.is_finite() {
             0.0
         } else {
             normalize_angle(degrees)
         }
     }
+
+    /// Returns the signed shortest angle to turn from this bearing to the other, in degrees (-180, 180].
+    /// Positive values are clockwise.
+    pub fn delta(&self, other: &Bearing) -> Float {
+        let delta = normalize_angle(other.degrees - self.degrees);
+        if delta > 180.0 { delta - 360.0 } else { delta }
+    }
+
+    /// Returns the circular mean of the bearings, or None if there are no bearings or they cancel out
+    /// (ex. 0° and 180°)
+    pub fn mean(bearings: &[Bearing]) -> Option<Bearing> {
+        Bearing::weighted_mean(&with_equal_weights(bearings))
+    }
+
+    /// Returns the circular mean of the bearings, where each bearing is paired with a non-negative weight.
+    /// Returns None if any weight is negative or not finite, the total weight is zero or the bearings
+    /// cancel out.
+    pub fn weighted_mean(bearings: &[(Bearing, Float)]) -> Option<Bearing> {
+        if bearings
+            .iter()
+            .any(|(_, weight)| !weight.is_finite() || *weight < 0.0)
+        {
+            return None;
+        }
+        let (x, y, total_weight) = resultant(bearings);
+        if is_approximately_zero(total_weight) || is_approximately_zero(x.hypot(y) / total_weight) {
+            return None;
+        }
+        Some(Bearing::new(atan2_degrees(y, x)))
+    }
+
+    /// Returns the circular variance of the bearings, from 0 (all equal) to 1 (evenly spread), or None
+    /// if there are no bearings
+    pub fn circular_variance(bearings: &[Bearing]) -> Option<Float> {
+        mean_resultant_length(bearings).map(|length| 1.0 - length)
+    }
+
+    /// Returns the circular standard deviation of the bearings in degrees, or None if there are no
+    /// bearings. Bearings which cancel out have an infinite standard deviation.
+    pub fn circular_standard_deviation(bearings: &[Bearing]) -> Option<Float> {
+        mean_resultant_length(bearings).map(|length| {
+            if is_approximately_zero(length) {
+                Float::INFINITY
+            } else {
+                (-2.0 * length.ln()).sqrt().to_degrees()
+            }
+        })
+    }
+}
+
+fn with_equal_weights(bearings: &[Bearing]) -> Vec<(Bearing, Float)> {
+    bearings.iter().map(|bearing| (*bearing, 1.0)).collect()
+}
+
+/// Sums the bearings as weighted unit vectors, returning the x (north), y (east) and total weight
+fn resultant(bearings: &[(Bearing, Float)]) -> (Float, Float, Float) {
+    bearings
+        .iter()
+        .fold((0.0, 0.0, 0.0), |(x, y, total), (bearing, weight)| {
+            (
+                x + weight * cos_degrees(bearing.degrees),
+                y + weight * sin_degrees(bearing.degrees),
+                total + weight,
+            )
+        })
+}
+
+fn mean_resultant_length(bearings: &[Bearing]) -> Option<Float> {
+    if bearings.is_empty() {
+        return None;
+    }
+    let (x, y, total_weight) = resultant(&with_equal_weights(bearings));
+    // Rounding can push the length slightly above 1 when all bearings are equal
+    Some((x.hypot(y) / total_weight).min(1.0))
+}
+
+/// Bearings are serialized as their degrees (ex. `45.0`) and normalized when deserialized
+#[cfg(feature = "serde")]
+impl serde::Serialize for Bearing {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.degrees.serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Bearing {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Float::deserialize(deserializer).map(Bearing::new)
+    }
 }
 
 // TODO: Extract to math
-fn normalize_angle(angle: f32) -> f32 {
+fn normalize_angle(angle: Float) -> Float {
     ((angle % 360.0) + 360.0) % 360.0
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::assert_approx_eq;
     use rstest::rstest;
 
     #[rstest]
@@ -103,10 +465,10 @@ mod tests {
     #[case(315.0, CompassDirection::NorthWest)]
     #[case(10.0, CompassDirection::North)]
     #[case(350.0, CompassDirection::North)]
-    #[case(f32::NAN, CompassDirection::North)]
-    #[case(f32::NEG_INFINITY, CompassDirection::North)]
-    #[case(f32::INFINITY, CompassDirection::North)]
-    fn test_direction(#[case] azimuth: f32, #[case] expected: CompassDirection) {
+    #[case(Float::NAN, CompassDirection::North)]
+    #[case(Float::NEG_INFINITY, CompassDirection::North)]
+    #[case(Float::INFINITY, CompassDirection::North)]
+    fn test_direction(#[case] azimuth: Float, #[case] expected: CompassDirection) {
         let bearing = Bearing::new(azimuth);
         assert_eq!(expected, bearing.direction());
     }
@@ -132,10 +494,10 @@ mod tests {
     #[case(710.0, 350.0)]
     #[case(360.0, 0.0)]
     #[case(-710.0, 10.0)]
-    #[case(f32::NAN, 0.0)]
-    #[case(f32::NEG_INFINITY, 0.0)]
-    #[case(f32::INFINITY, 0.0)]
-    fn test_value(#[case] azimuth: f32, #[case] expected: f32) {
+    #[case(Float::NAN, 0.0)]
+    #[case(Float::NEG_INFINITY, 0.0)]
+    #[case(Float::INFINITY, 0.0)]
+    fn test_value(#[case] azimuth: Float, #[case] expected: Float) {
         let bearing = Bearing::new(azimuth);
         assert!(
             (expected - bearing.degrees).abs() < 0.01,
@@ -150,7 +512,7 @@ mod tests {
     #[case(180.0, 0.0)]
     #[case(90.0, 270.0)]
     #[case(270.0, 90.0)]
-    fn test_inverse(#[case] azimuth: f32, #[case] expected: f32) {
+    fn test_inverse(#[case] azimuth: Float, #[case] expected: Float) {
         let bearing = Bearing::new(azimuth);
         assert!(
             (expected - bearing.inverse().degrees).abs() < 0.01,
@@ -171,4 +533,218 @@ mod tests {
             dec.degrees
         );
     }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_round_trip() {
+        use crate::test_helper::assert_serde_round_trip;
+
+        assert_serde_round_trip(&Bearing::new(45.5), "45.5");
+        assert_serde_round_trip(&CompassDirection::NorthEast, "\"NorthEast\"");
+
+        let bearing: Bearing = serde_json::from_str("-90.0").unwrap();
+        assert_eq!(bearing, Bearing::new(270.0));
+    }
+
+    #[rstest]
+    #[case(0.0, 90.0, 90.0)]
+    #[case(90.0, 0.0, -90.0)]
+    #[case(350.0, 10.0, 20.0)]
+    #[case(10.0, 350.0, -20.0)]
+    #[case(0.0, 180.0, 180.0)]
+    #[case(180.0, 0.0, 180.0)]
+    #[case(45.0, 45.0, 0.0)]
+    #[case(270.0, 89.0, 179.0)]
+    fn test_delta(#[case] from: Float, #[case] to: Float, #[case] expected: Float) {
+        let actual = Bearing::new(from).delta(&Bearing::new(to));
+        assert_approx_eq!(actual, expected, 0.0001);
+    }
+
+    #[rstest]
+    #[case(vec![350.0, 10.0], Some(0.0))]
+    #[case(vec![355.0, 5.0, 15.0], Some(5.0))]
+    #[case(vec![90.0, 180.0], Some(135.0))]
+    #[case(vec![270.0], Some(270.0))]
+    #[case(vec![0.0, 180.0], None)]
+    #[case(vec![0.0, 90.0, 180.0, 270.0], None)]
+    #[case(vec![], None)]
+    fn test_mean(#[case] degrees: Vec<Float>, #[case] expected: Option<Float>) {
+        let bearings: Vec<Bearing> = degrees.into_iter().map(Bearing::new).collect();
+        let actual = Bearing::mean(&bearings);
+        match (actual, expected) {
+            (Some(actual), Some(expected)) => {
+                assert_approx_eq!(Bearing::new(expected).delta(&actual), 0.0, 0.001);
+            }
+            (actual, expected) => assert_eq!(actual.map(|b| b.degrees), expected),
+        }
+    }
+
+    #[test]
+    fn test_weighted_mean() {
+        let bearings = [(Bearing::new(350.0), 3.0), (Bearing::new(20.0), 1.0)];
+        let actual = Bearing::weighted_mean(&bearings).unwrap();
+        assert_approx_eq!(Bearing::new(357.4).delta(&actual), 0.0, 0.1);
+
+        let ignored = [(Bearing::new(90.0), 1.0), (Bearing::new(180.0), 0.0)];
+        let actual = Bearing::weighted_mean(&ignored).unwrap();
+        assert_approx_eq!(actual.degrees, 90.0, 0.001);
+
+        assert_eq!(Bearing::weighted_mean(&[(Bearing::new(90.0), 0.0)]), None);
+    }
+
+    #[rstest]
+    #[case(-1.0)]
+    #[case(Float::NAN)]
+    #[case(Float::INFINITY)]
+    fn test_weighted_mean_invalid_weight(#[case] weight: Float) {
+        let bearings = [(Bearing::new(90.0), 2.0), (Bearing::new(0.0), weight)];
+        assert_eq!(Bearing::weighted_mean(&bearings), None);
+    }
+
+    #[rstest]
+    #[case(vec![10.0, 10.0, 10.0], 0.0, 0.0)]
+    #[case(vec![350.0, 10.0], 0.0152, 10.0)]
+    #[case(vec![0.0, 90.0], 0.2929, 47.7)]
+    #[case(vec![0.0, 180.0], 1.0, Float::INFINITY)]
+    fn test_circular_spread(
+        #[case] degrees: Vec<Float>,
+        #[case] expected_variance: Float,
+        #[case] expected_deviation: Float,
+    ) {
+        let bearings: Vec<Bearing> = degrees.into_iter().map(Bearing::new).collect();
+        let variance = Bearing::circular_variance(&bearings).unwrap();
+        let deviation = Bearing::circular_standard_deviation(&bearings).unwrap();
+        assert_approx_eq!(variance, expected_variance, 0.0001);
+        if expected_deviation.is_infinite() {
+            assert!(
+                deviation > 1000.0,
+                "expected a very large deviation: {}",
+                deviation
+            );
+        } else {
+            assert_approx_eq!(deviation, expected_deviation, 0.1);
+        }
+    }
+
+    #[test]
+    fn test_circular_spread_of_nothing() {
+        assert_eq!(Bearing::circular_variance(&[]), None);
+        assert_eq!(Bearing::circular_standard_deviation(&[]), None);
+    }
+
+    #[rstest]
+    #[case(0.0, CompassRose::FourPoint, CompassPoint::North)]
+    #[case(44.0, CompassRose::FourPoint, CompassPoint::North)]
+    #[case(46.0, CompassRose::FourPoint, CompassPoint::East)]
+    #[case(22.0, CompassRose::EightPoint, CompassPoint::North)]
+    #[case(23.0, CompassRose::EightPoint, CompassPoint::NorthEast)]
+    #[case(22.5, CompassRose::SixteenPoint, CompassPoint::NorthNorthEast)]
+    #[case(250.0, CompassRose::SixteenPoint, CompassPoint::WestSouthWest)]
+    #[case(355.0, CompassRose::SixteenPoint, CompassPoint::North)]
+    #[case(11.25, CompassRose::ThirtyTwoPoint, CompassPoint::NorthByEast)]
+    #[case(95.0, CompassRose::ThirtyTwoPoint, CompassPoint::East)]
+    #[case(100.0, CompassRose::ThirtyTwoPoint, CompassPoint::EastBySouth)]
+    #[case(354.0, CompassRose::ThirtyTwoPoint, CompassPoint::NorthByWest)]
+    #[case(358.0, CompassRose::ThirtyTwoPoint, CompassPoint::North)]
+    fn test_compass_point(
+        #[case] degrees: Float,
+        #[case] rose: CompassRose,
+        #[case] expected: CompassPoint,
+    ) {
+        assert_eq!(Bearing::new(degrees).compass_point(rose), expected);
+    }
+
+    #[rstest]
+    #[case(CompassRose::FourPoint, 4)]
+    #[case(CompassRose::EightPoint, 8)]
+    #[case(CompassRose::SixteenPoint, 16)]
+    #[case(CompassRose::ThirtyTwoPoint, 32)]
+    fn test_compass_rose_points(#[case] rose: CompassRose, #[case] count: usize) {
+        let points: Vec<CompassPoint> = rose.points().collect();
+        assert_eq!(points.len(), count);
+        for point in points {
+            assert_eq!(point.bearing().compass_point(rose), point);
+        }
+    }
+
+    #[rstest]
+    #[case(CompassPoint::NorthNorthEast, "NNE", "North-northeast", 22.5)]
+    #[case(CompassPoint::NorthEastByEast, "NEbE", "Northeast by east", 56.25)]
+    #[case(CompassPoint::SouthWest, "SW", "Southwest", 225.0)]
+    #[case(CompassPoint::NorthByWest, "NbW", "North by west", 348.75)]
+    fn test_compass_point_names(
+        #[case] point: CompassPoint,
+        #[case] abbreviation: &str,
+        #[case] name: &str,
+        #[case] azimuth: Float,
+    ) {
+        assert_eq!(point.abbreviation(), abbreviation);
+        assert_eq!(point.name(), name);
+        assert_eq!(point.to_string(), abbreviation);
+        assert_approx_eq!(point.azimuth(), azimuth);
+    }
+
+    #[rstest]
+    #[case("nne", Some(CompassPoint::NorthNorthEast))]
+    #[case("North-Northeast", Some(CompassPoint::NorthNorthEast))]
+    #[case("north north east", Some(CompassPoint::NorthNorthEast))]
+    #[case("SbW", Some(CompassPoint::SouthByWest))]
+    #[case("south by west", Some(CompassPoint::SouthByWest))]
+    #[case(" W ", Some(CompassPoint::West))]
+    #[case("up", None)]
+    #[case("", None)]
+    fn test_parse_compass_point(#[case] text: &str, #[case] expected: Option<CompassPoint>) {
+        assert_eq!(text.parse::<CompassPoint>().ok(), expected);
+    }
+
+    #[test]
+    fn compass_point_names_round_trip() {
+        for point in ALL_COMPASS_POINTS {
+            assert_eq!(point.abbreviation().parse::<CompassPoint>(), Ok(point));
+            assert_eq!(point.name().parse::<CompassPoint>(), Ok(point));
+            let bearing = point.name().parse::<CompassPoint>().unwrap().bearing();
+            assert_approx_eq!(bearing.degrees, point.azimuth());
+        }
+    }
+
+    #[test]
+    fn test_localized_compass_names() {
+        // French abbreviations use O (ouest) for west
+        struct FrenchAbbreviations {
+            abbreviations: Vec<String>,
+        }
+
+        impl CompassNames for FrenchAbbreviations {
+            fn abbreviation(&self, point: CompassPoint) -> &str {
+                &self.abbreviations[point as usize]
+            }
+
+            fn name(&self, point: CompassPoint) -> &str {
+                EnglishCompassNames.name(point)
+            }
+        }
+
+        let names = FrenchAbbreviations {
+            abbreviations: ALL_COMPASS_POINTS
+                .iter()
+                .map(|point| point.abbreviation().replace('W', "O"))
+                .collect(),
+        };
+
+        let point = Bearing::new(290.0).compass_point(CompassRose::SixteenPoint);
+        assert_eq!(names.abbreviation(point), "ONO");
+        assert_eq!(
+            CompassPoint::parse("ono", &names),
+            Some(CompassPoint::WestNorthWest)
+        );
+        assert_eq!(CompassPoint::parse("WNW", &names), None);
+    }
+
+    #[test]
+    fn test_compass_point_from_direction() {
+        for direction in ALL_COMPASS_DIRECTIONS {
+            let point = CompassPoint::from(direction);
+            assert_approx_eq!(point.azimuth(), direction.azimuth());
+        }
+    }
 }
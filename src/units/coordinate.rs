@@ -1,4 +1,252 @@
+use crate::{
+    geology::{
+        datum::{Datum, transform_datum},
+        ecef::{ecef_to_enu, ecef_to_geodetic, enu_to_ecef, geodetic_to_ecef},
+        ellipsoid::Ellipsoid,
+        geodesy::{
+            haversine_distance, spherical_bearing, spherical_midpoint, vincenty_direct,
+            vincenty_inverse,
+        },
+    },
+    math::{Float, linear_algebra::Vector},
+};
+
+use super::{
+    bearing::Bearing,
+    distance::Distance,
+    quantity::{Convertable, Quantity},
+};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Coordinate {
-    pub latitude: f32,
-    pub longitude: f32,
+    pub latitude: Float,
+    pub longitude: Float,
+}
+
+impl Coordinate {
+    pub fn new(latitude: Float, longitude: Float) -> Self {
+        Coordinate {
+            latitude,
+            longitude,
+        }
+    }
+
+    /// Returns the distance to the other coordinate on the WGS84 ellipsoid (Vincenty). Nearly antipodal
+    /// points, where Vincenty's formula does not converge, fall back to the great-circle distance.
+    pub fn distance_to(&self, other: &Coordinate) -> Quantity<Distance> {
+        let meters = match vincenty_inverse(self, other, &Ellipsoid::WGS84) {
+            Some(geodesic) => geodesic.distance,
+            None => haversine_distance(self, other),
+        };
+        Quantity::new(meters, Distance::Meters)
+    }
+
+    /// Returns the great-circle distance to the other coordinate, treating the Earth as a sphere. This
+    /// is faster but less accurate (up to about 0.5%) than `distance_to`.
+    pub fn haversine_distance_to(&self, other: &Coordinate) -> Quantity<Distance> {
+        Quantity::new(haversine_distance(self, other), Distance::Meters)
+    }
+
+    /// Returns the bearing to start out on when traveling along the shortest path to the other coordinate
+    pub fn bearing_to(&self, other: &Coordinate) -> Bearing {
+        match vincenty_inverse(self, other, &Ellipsoid::WGS84) {
+            Some(geodesic) => Bearing::new(geodesic.initial_bearing),
+            None => Bearing::new(spherical_bearing(self, other)),
+        }
+    }
+
+    /// Returns the bearing when arriving at the other coordinate along the shortest path
+    pub fn final_bearing_to(&self, other: &Coordinate) -> Bearing {
+        match vincenty_inverse(self, other, &Ellipsoid::WGS84) {
+            Some(geodesic) => Bearing::new(geodesic.final_bearing),
+            None => Bearing::new(spherical_bearing(other, self)).inverse(),
+        }
+    }
+
+    /// Returns the coordinate reached by traveling the distance along the shortest path starting at
+    /// the bearing, on the WGS84 ellipsoid
+    pub fn destination(&self, distance: &Quantity<Distance>, bearing: &Bearing) -> Coordinate {
+        let meters = distance.convert(Distance::Meters).amount;
+        vincenty_direct(self, meters, bearing.degrees, &Ellipsoid::WGS84).0
+    }
+
+    /// Returns the point halfway along the great circle between this and the other coordinate
+    pub fn midpoint(&self, other: &Coordinate) -> Coordinate {
+        spherical_midpoint(self, other)
+    }
+
+    /// Returns the Earth-centered Earth-fixed (ECEF) position in meters of the coordinate at the
+    /// altitude above the WGS84 ellipsoid
+    pub fn to_ecef(&self, altitude: &Quantity<Distance>) -> Vector {
+        let meters = altitude.convert(Distance::Meters).amount;
+        geodetic_to_ecef(self, meters, &Ellipsoid::WGS84)
+    }
+
+    /// Returns the coordinate and altitude above the WGS84 ellipsoid of an Earth-centered Earth-fixed
+    /// position in meters
+    pub fn from_ecef(point: &Vector) -> (Coordinate, Quantity<Distance>) {
+        let (coordinate, altitude) = ecef_to_geodetic(point, &Ellipsoid::WGS84);
+        (coordinate, Quantity::new(altitude, Distance::Meters))
+    }
+
+    /// Returns the position in meters east, north and up of the origin. Altitudes are above the WGS84
+    /// ellipsoid.
+    pub fn to_enu(
+        &self,
+        altitude: &Quantity<Distance>,
+        origin: &Coordinate,
+        origin_altitude: &Quantity<Distance>,
+    ) -> Vector {
+        let origin_meters = origin_altitude.convert(Distance::Meters).amount;
+        ecef_to_enu(
+            &self.to_ecef(altitude),
+            origin,
+            origin_meters,
+            &Ellipsoid::WGS84,
+        )
+    }
+
+    /// Returns the coordinate and altitude of a position in meters east, north and up of the origin
+    pub fn from_enu(
+        point: &Vector,
+        origin: &Coordinate,
+        origin_altitude: &Quantity<Distance>,
+    ) -> (Coordinate, Quantity<Distance>) {
+        let origin_meters = origin_altitude.convert(Distance::Meters).amount;
+        Coordinate::from_ecef(&enu_to_ecef(
+            point,
+            origin,
+            origin_meters,
+            &Ellipsoid::WGS84,
+        ))
+    }
+
+    /// Converts the coordinate from one datum to another. Coordinates are otherwise treated as WGS84.
+    pub fn convert_datum(&self, from: &Datum, to: &Datum) -> Coordinate {
+        transform_datum(self, 0.0, from, to).0
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::excessive_precision)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::assert_approx_eq;
+
+    #[rstest]
+    #[case(Coordinate::new(-37.951033, 144.424868), Coordinate::new(-37.652821, 143.926496), 54.972)]
+    #[case(Coordinate::new(51.5007, -0.1246), Coordinate::new(40.6892, -74.0445), 5589.857)]
+    #[case(Coordinate::new(0.0, 0.0), Coordinate::new(0.5, 179.7), 19950.31)]
+    fn test_distance_to(
+        #[case] start: Coordinate,
+        #[case] end: Coordinate,
+        #[case] expected_kilometers: Float,
+    ) {
+        let actual = start.distance_to(&end).convert(Distance::Kilometers);
+        assert_approx_eq!(actual.amount, expected_kilometers, 0.05);
+        assert_eq!(actual.units, Distance::Kilometers);
+    }
+
+    #[test]
+    fn test_haversine_distance_to() {
+        let start = Coordinate::new(51.5007, -0.1246);
+        let end = Coordinate::new(40.6892, -74.0445);
+        let actual = start.haversine_distance_to(&end).convert(Distance::Miles);
+        assert_approx_eq!(actual.amount, 3464.0, 1.0);
+    }
+
+    #[rstest]
+    #[case(Coordinate::new(-37.951033, 144.424868), Coordinate::new(-37.652821, 143.926496), 306.868, 307.174)]
+    #[case(Coordinate::new(0.0, 0.0), Coordinate::new(0.0, 10.0), 90.0, 90.0)]
+    #[case(Coordinate::new(10.0, 0.0), Coordinate::new(0.0, 0.0), 180.0, 180.0)]
+    fn test_bearings(
+        #[case] start: Coordinate,
+        #[case] end: Coordinate,
+        #[case] expected_initial: Float,
+        #[case] expected_final: Float,
+    ) {
+        assert_approx_eq!(start.bearing_to(&end).degrees, expected_initial, 0.01);
+        assert_approx_eq!(start.final_bearing_to(&end).degrees, expected_final, 0.01);
+    }
+
+    #[test]
+    fn test_destination() {
+        let start = Coordinate::new(-37.951033, 144.424868);
+        let distance = Quantity::new(54.972271, Distance::Kilometers);
+        let actual = start.destination(&distance, &Bearing::new(306.868159));
+        assert_approx_eq!(actual.latitude, -37.652821, 0.0001);
+        assert_approx_eq!(actual.longitude, 143.926496, 0.0001);
+
+        let distance = Quantity::new(60.0, Distance::NauticalMiles);
+        let actual = Coordinate::new(0.0, 0.0).destination(&distance, &Bearing::new(0.0));
+        assert_approx_eq!(actual.latitude, 1.00475, 0.001);
+        assert_approx_eq!(actual.longitude, 0.0, 0.0001);
+    }
+
+    #[test]
+    fn test_midpoint() {
+        let start = Coordinate::new(0.0, 0.0);
+        let end = Coordinate::new(0.0, 90.0);
+        let actual = start.midpoint(&end);
+        assert_approx_eq!(actual.latitude, 0.0, 0.0001);
+        assert_approx_eq!(actual.longitude, 45.0, 0.0001);
+    }
+
+    #[test]
+    fn test_ecef() {
+        let coordinate = Coordinate::new(45.0, 45.0);
+        let point = coordinate.to_ecef(&Quantity::new(1.0, Distance::Kilometers));
+        assert_approx_eq!(point.x, 3194919.145, 1.0);
+        assert_approx_eq!(point.y, 3194919.145, 1.0);
+        assert_approx_eq!(point.z, 4488055.516, 1.0);
+
+        let (actual, altitude) = Coordinate::from_ecef(&point);
+        assert_approx_eq!(actual.latitude, 45.0, 1e-5);
+        assert_approx_eq!(actual.longitude, 45.0, 1e-5);
+        assert_approx_eq!(altitude.amount, 1000.0, 1.0);
+        assert_eq!(altitude.units, Distance::Meters);
+    }
+
+    #[test]
+    fn test_enu() {
+        let origin = Coordinate::new(45.0, 45.0);
+        let origin_altitude = Quantity::new(1000.0, Distance::Meters);
+        let coordinate = Coordinate::new(45.001, 45.002);
+        let enu = coordinate.to_enu(
+            &Quantity::new(1100.0, Distance::Meters),
+            &origin,
+            &origin_altitude,
+        );
+        assert_approx_eq!(enu.x, 157.718, 1.0);
+        assert_approx_eq!(enu.y, 111.153, 1.0);
+        assert_approx_eq!(enu.z, 99.997, 1.0);
+
+        let (actual, altitude) = Coordinate::from_enu(&enu, &origin, &origin_altitude);
+        assert_approx_eq!(actual.latitude, 45.001, 1e-5);
+        assert_approx_eq!(actual.longitude, 45.002, 1e-5);
+        assert_approx_eq!(altitude.amount, 1100.0, 1.0);
+    }
+
+    #[test]
+    fn test_convert_datum() {
+        let actual =
+            Coordinate::new(51.47788, -0.00147).convert_datum(&Datum::WGS84, &Datum::OSGB36);
+        assert_approx_eq!(actual.latitude, 51.47736, 1e-5);
+        assert_approx_eq!(actual.longitude, 0.00015, 1e-5);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_round_trip() {
+        use crate::test_helper::assert_serde_round_trip;
+
+        let coordinate = Coordinate {
+            latitude: 42.5,
+            longitude: -71.25,
+        };
+        assert_serde_round_trip(&coordinate, r#"{"latitude":42.5,"longitude":-71.25}"#);
+    }
 }
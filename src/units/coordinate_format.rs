@@ -0,0 +1,439 @@
+use crate::{
+    geology::{
+        osgb::OsgbCoordinate,
+        utm::{MgrsCoordinate, UtmCoordinate},
+    },
+    math::Float,
+};
+
+use super::{
+    angle::Angle,
+    coordinate::Coordinate,
+    dms::{format_ddm, format_dms, parse_dms},
+};
+
+/// A way of writing a location
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum CoordinateFormat {
+    /// Signed decimal degrees (ex. "40.446195, -79.948862")
+    DecimalDegrees,
+    /// Degrees, minutes and seconds (ex. "40° 26′ 46.3″ N, 79° 56′ 55.9″ W")
+    DegreesMinutesSeconds,
+    /// Degrees and decimal minutes (ex. "40° 26.772′ N, 79° 56.932′ W")
+    DegreesDecimalMinutes,
+    /// Universal Transverse Mercator (ex. "17T 589090 4477606")
+    Utm,
+    /// Military Grid Reference System (ex. "17TNE8909077606")
+    Mgrs,
+    /// United States National Grid, which is MGRS with spaces (ex. "17T NE 89090 77606")
+    Usng,
+    /// The Ordnance Survey National Grid of Great Britain (ex. "TG 51409 13177")
+    Osgb,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum ParseCoordinateError {
+    /// The text was empty
+    Empty,
+    /// The text could not be read as a coordinate in the format
+    Malformed(String),
+    /// The latitude was not between -90 and 90 degrees
+    LatitudeOutOfRange(String),
+    /// The longitude was not between -180 and 180 degrees
+    LongitudeOutOfRange(String),
+    /// The UTM zone was not between 1 and 60
+    InvalidZone(String),
+    /// The latitude band was not a letter from C to X (excluding I and O) or did not contain the location
+    InvalidBand(String),
+    /// The 100 km grid square letters do not exist in the zone or grid
+    InvalidGridSquare(String),
+    /// The easting or northing was outside of the grid
+    OutsideGrid(String),
+}
+
+impl std::fmt::Display for ParseCoordinateError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParseCoordinateError::Empty => write!(f, "no coordinate was provided"),
+            ParseCoordinateError::Malformed(text) => write!(f, "invalid coordinate: '{}'", text),
+            ParseCoordinateError::LatitudeOutOfRange(text) => {
+                write!(f, "latitude must be between -90 and 90 degrees: '{}'", text)
+            }
+            ParseCoordinateError::LongitudeOutOfRange(text) => {
+                write!(
+                    f,
+                    "longitude must be between -180 and 180 degrees: '{}'",
+                    text
+                )
+            }
+            ParseCoordinateError::InvalidZone(text) => {
+                write!(f, "zone must be between 1 and 60: '{}'", text)
+            }
+            ParseCoordinateError::InvalidBand(text) => {
+                write!(f, "invalid latitude band: '{}'", text)
+            }
+            ParseCoordinateError::InvalidGridSquare(text) => {
+                write!(f, "invalid grid square: '{}'", text)
+            }
+            ParseCoordinateError::OutsideGrid(text) => {
+                write!(f, "location is outside of the grid: '{}'", text)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ParseCoordinateError {}
+
+/// Formats the coordinate. Returns None if the location can't be written in the format (ex. UTM near
+/// the poles or British National Grid outside of Great Britain).
+pub fn format_coordinate(coordinate: &Coordinate, format: CoordinateFormat) -> Option<String> {
+    let latitude = Angle::degrees(coordinate.latitude.abs());
+    let longitude = Angle::degrees(coordinate.longitude.abs());
+    let north_south = if coordinate.latitude < 0.0 { "S" } else { "N" };
+    let east_west = if coordinate.longitude < 0.0 { "W" } else { "E" };
+    let text = match format {
+        CoordinateFormat::DecimalDegrees => {
+            format!("{:.6}, {:.6}", coordinate.latitude, coordinate.longitude)
+        }
+        CoordinateFormat::DegreesMinutesSeconds => format!(
+            "{} {}, {} {}",
+            format_dms(&latitude, 1),
+            north_south,
+            format_dms(&longitude, 1),
+            east_west
+        ),
+        CoordinateFormat::DegreesDecimalMinutes => format!(
+            "{} {}, {} {}",
+            format_ddm(&latitude, 3),
+            north_south,
+            format_ddm(&longitude, 3),
+            east_west
+        ),
+        CoordinateFormat::Utm => UtmCoordinate::from_coordinate(coordinate)?.to_string(),
+        CoordinateFormat::Mgrs => MgrsCoordinate::from_coordinate(coordinate)?.format(5),
+        CoordinateFormat::Usng => MgrsCoordinate::from_coordinate(coordinate)?.format_usng(5),
+        CoordinateFormat::Osgb => OsgbCoordinate::from_coordinate(coordinate)?.format(10),
+    };
+    Some(text)
+}
+
+/// Parses a coordinate written in any of the supported formats, detecting the format from the text.
+/// British National Grid references must start with their grid letters to be detected.
+pub fn parse_coordinate(text: &str) -> Result<Coordinate, ParseCoordinateError> {
+    let formats: &[CoordinateFormat] = match detect_grid(text) {
+        Some(format) => &[format, CoordinateFormat::DecimalDegrees],
+        None => &[CoordinateFormat::DecimalDegrees],
+    };
+
+    let mut errors = Vec::new();
+    for format in formats {
+        match parse_coordinate_as(text, *format) {
+            Ok(coordinate) => return Ok(coordinate),
+            Err(error) => errors.push(error),
+        }
+    }
+
+    // Prefer the latitude and longitude error unless the text didn't look like latitude and longitude
+    match errors.pop() {
+        Some(ParseCoordinateError::Malformed(_)) | None if !errors.is_empty() => {
+            Err(errors.remove(0))
+        }
+        Some(error) => Err(error),
+        None => Err(ParseCoordinateError::Empty),
+    }
+}
+
+/// Parses a coordinate written in the format. Latitude and longitude formats accept decimal degrees,
+/// degrees and minutes or degrees, minutes and seconds, either signed or with hemisphere letters
+/// before or after each angle (ex. "40.4462, -79.9489" or "N 40° 26.772′ W 79° 56.932′").
+pub fn parse_coordinate_as(
+    text: &str,
+    format: CoordinateFormat,
+) -> Result<Coordinate, ParseCoordinateError> {
+    let text = text.trim();
+    if text.is_empty() {
+        return Err(ParseCoordinateError::Empty);
+    }
+
+    match format {
+        CoordinateFormat::DecimalDegrees
+        | CoordinateFormat::DegreesMinutesSeconds
+        | CoordinateFormat::DegreesDecimalMinutes => parse_latitude_longitude(text),
+        CoordinateFormat::Utm => Ok(text.parse::<UtmCoordinate>()?.to_coordinate()),
+        CoordinateFormat::Mgrs | CoordinateFormat::Usng => text
+            .parse::<MgrsCoordinate>()?
+            .to_coordinate()
+            .ok_or_else(|| ParseCoordinateError::InvalidGridSquare(text.to_string())),
+        CoordinateFormat::Osgb => Ok(text.parse::<OsgbCoordinate>()?.to_coordinate()),
+    }
+}
+
+/// Returns the grid format the text looks like, if any
+fn detect_grid(text: &str) -> Option<CoordinateFormat> {
+    let compact: Vec<char> = text
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .map(|c| c.to_ascii_uppercase())
+        .collect();
+
+    if compact.len() > 2 && compact[..2].iter().all(|c| c.is_ascii_alphabetic()) {
+        return Some(CoordinateFormat::Osgb);
+    }
+
+    let digits = compact.iter().take_while(|c| c.is_ascii_digit()).count();
+    let is_zone =
+        (1..=2).contains(&digits) && compact.get(digits).is_some_and(|c| c.is_ascii_alphabetic());
+    if !is_zone {
+        return None;
+    }
+
+    let has_square = compact[digits + 1..]
+        .iter()
+        .take(2)
+        .filter(|c| c.is_ascii_alphabetic())
+        .count()
+        == 2;
+    if has_square {
+        Some(CoordinateFormat::Mgrs)
+    } else {
+        Some(CoordinateFormat::Utm)
+    }
+}
+
+fn parse_latitude_longitude(text: &str) -> Result<Coordinate, ParseCoordinateError> {
+    let malformed = || ParseCoordinateError::Malformed(text.to_string());
+    let normalized = text.to_uppercase();
+    let hemispheres = find_hemispheres(&normalized);
+
+    let (latitude, longitude) = match hemispheres.as_slice() {
+        [] => {
+            let (first, second) = split_signed(&normalized).ok_or_else(malformed)?;
+            let latitude = parse_dms(&first).map_err(|_| malformed())?;
+            let longitude = parse_dms(&second).map_err(|_| malformed())?;
+            (latitude.amount, longitude.amount)
+        }
+        [(first_index, first), (second_index, second)] => {
+            let is_prefix = normalized[..*first_index].trim().is_empty();
+            let split = if is_prefix {
+                *second_index
+            } else {
+                first_index + first.len_utf8()
+            };
+            let first_amount =
+                parse_hemisphere_angle(&normalized[..split], *first).ok_or_else(malformed)?;
+            let second_amount =
+                parse_hemisphere_angle(&normalized[split..], *second).ok_or_else(malformed)?;
+            match (first, second) {
+                ('N' | 'S', 'E' | 'W') => (first_amount, second_amount),
+                ('E' | 'W', 'N' | 'S') => (second_amount, first_amount),
+                _ => return Err(malformed()),
+            }
+        }
+        _ => return Err(malformed()),
+    };
+
+    if !(-90.0..=90.0).contains(&latitude) {
+        return Err(ParseCoordinateError::LatitudeOutOfRange(text.to_string()));
+    }
+    if !(-180.0..=180.0).contains(&longitude) {
+        return Err(ParseCoordinateError::LongitudeOutOfRange(text.to_string()));
+    }
+    Ok(Coordinate::new(latitude, longitude))
+}
+
+/// Returns the positions of the standalone hemisphere letters (N, S, E and W) in the uppercase text
+fn find_hemispheres(text: &str) -> Vec<(usize, char)> {
+    let chars: Vec<(usize, char)> = text.char_indices().collect();
+    let is_letter = |i: Option<&(usize, char)>| i.is_some_and(|(_, c)| c.is_alphabetic());
+    chars
+        .iter()
+        .enumerate()
+        .filter(|(i, (_, c))| {
+            matches!(c, 'N' | 'S' | 'E' | 'W')
+                && !is_letter(i.checked_sub(1).and_then(|i| chars.get(i)))
+                && !is_letter(chars.get(i + 1))
+        })
+        .map(|(_, (index, c))| (*index, *c))
+        .collect()
+}
+
+/// Splits signed angles without hemisphere letters at a comma, or evenly between their components
+fn split_signed(text: &str) -> Option<(String, String)> {
+    let parts: Vec<&str> = text.split(',').map(|part| part.trim()).collect();
+    if let [first, second] = parts.as_slice() {
+        return Some((first.to_string(), second.to_string()));
+    }
+    if parts.len() != 1 {
+        return None;
+    }
+
+    let components: Vec<&str> = text.split_whitespace().collect();
+    if components.is_empty() || !components.len().is_multiple_of(2) {
+        return None;
+    }
+    let half = components.len() / 2;
+    Some((components[..half].join(" "), components[half..].join(" ")))
+}
+
+/// Parses an unsigned angle with a hemisphere letter before or after it, returning signed degrees
+fn parse_hemisphere_angle(text: &str, hemisphere: char) -> Option<Float> {
+    let text = text.trim().trim_matches(',').trim();
+    let angle = text
+        .strip_prefix(hemisphere)
+        .or_else(|| text.strip_suffix(hemisphere))?
+        .trim()
+        .trim_matches(',');
+    let amount = parse_dms(angle).ok()?.amount;
+    if amount < 0.0 {
+        return None;
+    }
+    let sign = if matches!(hemisphere, 'S' | 'W') {
+        -1.0
+    } else {
+        1.0
+    };
+    Some(sign * amount)
+}
+
+impl std::str::FromStr for Coordinate {
+    type Err = ParseCoordinateError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_coordinate(s)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::assert_approx_eq;
+
+    #[rstest]
+    #[case(
+        CoordinateFormat::DegreesMinutesSeconds,
+        "1° 15′ 0.0″ N, 79° 32′ 48.8″ W"
+    )]
+    #[case(CoordinateFormat::DegreesDecimalMinutes, "1° 15.000′ N, 79° 32.813′ W")]
+    #[case(CoordinateFormat::Utm, "17N 661675 138207")]
+    #[case(CoordinateFormat::Mgrs, "17NPB6167538207")]
+    #[case(CoordinateFormat::Usng, "17N PB 61675 38207")]
+    fn test_format_coordinate(#[case] format: CoordinateFormat, #[case] expected: &str) {
+        let coordinate = Coordinate::new(1.25, -79.546875);
+        let actual = format_coordinate(&coordinate, format).unwrap();
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_format_coordinate_decimal_degrees() {
+        let coordinate = Coordinate::new(40.25, -79.5);
+        assert_eq!(
+            format_coordinate(&coordinate, CoordinateFormat::DecimalDegrees).unwrap(),
+            "40.250000, -79.500000"
+        );
+    }
+
+    #[test]
+    fn test_format_coordinate_southern_and_eastern() {
+        let coordinate = Coordinate::new(-33.8568, 151.2153);
+        assert_eq!(
+            format_coordinate(&coordinate, CoordinateFormat::DegreesMinutesSeconds).unwrap(),
+            "33° 51′ 24.5″ S, 151° 12′ 55.1″ E"
+        );
+    }
+
+    #[rstest]
+    #[case(Coordinate::new(85.0, 0.0), CoordinateFormat::Utm)]
+    #[case(Coordinate::new(-85.0, 0.0), CoordinateFormat::Mgrs)]
+    #[case(Coordinate::new(40.0, -80.0), CoordinateFormat::Osgb)]
+    fn test_format_coordinate_unsupported(
+        #[case] coordinate: Coordinate,
+        #[case] format: CoordinateFormat,
+    ) {
+        assert_eq!(format_coordinate(&coordinate, format), None);
+    }
+
+    #[rstest]
+    #[case("40.446195, -79.948862", 40.4462, -79.9489)]
+    #[case("40.446195 -79.948862", 40.4462, -79.9489)]
+    #[case("40° 26′ 46.3″ N, 79° 56′ 55.9″ W", 40.4462, -79.9489)]
+    #[case("40°26'46.3\"N 79°56'55.9\"W", 40.4462, -79.9489)]
+    #[case("N 40° 26.772′ W 79° 56.932′", 40.4462, -79.9489)]
+    #[case("n40 26.772 w79 56.932", 40.4462, -79.9489)]
+    #[case("79° 56.932′ W, 40° 26.772′ N", 40.4462, -79.9489)]
+    #[case("40 26 46.3 -79 56 55.9", 40.4462, -79.9489)]
+    #[case("-33.8568, 151.2153", -33.8568, 151.2153)]
+    #[case("33.8568 S 151.2153 E", -33.8568, 151.2153)]
+    #[case("17T 589136 4477813", 40.4462, -79.94889)]
+    #[case("17TNE8913677813", 40.4462, -79.94889)]
+    #[case("17T NE 89136 77813", 40.4462, -79.94889)]
+    #[case("TQ 38876 77344", 51.478015, -0.001594)]
+    fn test_parse_coordinate(
+        #[case] text: &str,
+        #[case] latitude: Float,
+        #[case] longitude: Float,
+    ) {
+        let coordinate = parse_coordinate(text).unwrap();
+        assert_approx_eq!(coordinate.latitude, latitude, 1e-4);
+        assert_approx_eq!(coordinate.longitude, longitude, 1e-4);
+
+        let parsed: Coordinate = text.parse().unwrap();
+        assert_eq!(parsed, coordinate);
+    }
+
+    #[rstest]
+    #[case("", ParseCoordinateError::Empty)]
+    #[case("40.4", ParseCoordinateError::Malformed("40.4".to_string()))]
+    #[case("40.4, -79.9, 12", ParseCoordinateError::Malformed("40.4, -79.9, 12".to_string()))]
+    #[case("40 N 79 N", ParseCoordinateError::Malformed("40 N 79 N".to_string()))]
+    #[case("-40 N 79 W", ParseCoordinateError::Malformed("-40 N 79 W".to_string()))]
+    #[case("40° 61′ N, 79° W", ParseCoordinateError::Malformed("40° 61′ N, 79° W".to_string()))]
+    #[case("91, 0", ParseCoordinateError::LatitudeOutOfRange("91, 0".to_string()))]
+    #[case("0, -181", ParseCoordinateError::LongitudeOutOfRange("0, -181".to_string()))]
+    #[case("95 N, 10 E", ParseCoordinateError::LatitudeOutOfRange("95 N, 10 E".to_string()))]
+    #[case("61T 589090 4477606", ParseCoordinateError::InvalidZone("61T 589090 4477606".to_string()))]
+    #[case("17TIE8909077606", ParseCoordinateError::InvalidGridSquare("17TIE8909077606".to_string()))]
+    #[case("XX 12345 12345", ParseCoordinateError::InvalidGridSquare("XX 12345 12345".to_string()))]
+    fn test_parse_coordinate_errors(#[case] text: &str, #[case] expected: ParseCoordinateError) {
+        assert_eq!(parse_coordinate(text), Err(expected));
+    }
+
+    #[rstest]
+    #[case("651409, 313177", CoordinateFormat::Osgb, 52.657977, 1.716038)]
+    #[case("17TNE8913677813", CoordinateFormat::Usng, 40.4462, -79.94889)]
+    #[case("40.446195, -79.948862", CoordinateFormat::DegreesMinutesSeconds, 40.4462, -79.9489)]
+    fn test_parse_coordinate_as(
+        #[case] text: &str,
+        #[case] format: CoordinateFormat,
+        #[case] latitude: Float,
+        #[case] longitude: Float,
+    ) {
+        let coordinate = parse_coordinate_as(text, format).unwrap();
+        assert_approx_eq!(coordinate.latitude, latitude, 1e-4);
+        assert_approx_eq!(coordinate.longitude, longitude, 1e-4);
+    }
+
+    #[rstest]
+    #[case(CoordinateFormat::DecimalDegrees)]
+    #[case(CoordinateFormat::DegreesMinutesSeconds)]
+    #[case(CoordinateFormat::DegreesDecimalMinutes)]
+    #[case(CoordinateFormat::Utm)]
+    #[case(CoordinateFormat::Mgrs)]
+    #[case(CoordinateFormat::Usng)]
+    #[case(CoordinateFormat::Osgb)]
+    fn test_round_trip(#[case] format: CoordinateFormat) {
+        let coordinate = Coordinate::new(51.5007, -0.1246);
+        let text = format_coordinate(&coordinate, format).unwrap();
+        let parsed = parse_coordinate_as(&text, format).unwrap();
+        assert_approx_eq!(parsed.latitude, coordinate.latitude, 1e-4);
+        assert_approx_eq!(parsed.longitude, coordinate.longitude, 1e-4);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_round_trip() {
+        crate::test_helper::assert_serde_round_trip(&CoordinateFormat::Mgrs, r#""Mgrs""#);
+    }
+}
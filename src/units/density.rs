@@ -0,0 +1,252 @@
+use crate::math::Float;
+
+use super::{
+    format::UnitNames,
+    parse::UnitAliases,
+    quantity::{AdditiveUnit, Convertable, Quantity, Unit},
+    volume::Volume,
+    weight::Weight,
+};
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Density {
+    KilogramsPerCubicMeter = 1,
+    GramsPerCubicCentimeter = 2,
+    PoundsPerCubicFoot = 3,
+    PoundsPerUSGallon = 4,
+}
+
+impl Unit for Density {
+    fn multiplier_to_base(&self) -> Float {
+        match self {
+            Density::KilogramsPerCubicMeter => 1.0,
+            Density::GramsPerCubicCentimeter => 1000.0,
+            Density::PoundsPerCubicFoot => 16.018463,
+            Density::PoundsPerUSGallon => 119.82643,
+        }
+    }
+}
+
+impl AdditiveUnit for Density {}
+
+/// Weight / Volume = Density (in kilograms per cubic meter)
+impl std::ops::Div<Quantity<Volume>> for Quantity<Weight> {
+    type Output = Quantity<Density>;
+
+    fn div(self, rhs: Quantity<Volume>) -> Quantity<Density> {
+        // Grams per liter are equivalent to kilograms per cubic meter
+        let grams = self.convert(Weight::Grams).amount;
+        let liters = rhs.convert(Volume::Liters).amount;
+        Quantity::new(grams / liters, Density::KilogramsPerCubicMeter)
+    }
+}
+
+/// Weight / Density = Volume (in liters)
+impl std::ops::Div<Quantity<Density>> for Quantity<Weight> {
+    type Output = Quantity<Volume>;
+
+    fn div(self, rhs: Quantity<Density>) -> Quantity<Volume> {
+        let grams = self.convert(Weight::Grams).amount;
+        let grams_per_liter = rhs.convert(Density::KilogramsPerCubicMeter).amount;
+        Quantity::new(grams / grams_per_liter, Volume::Liters)
+    }
+}
+
+/// Density * Volume = Weight (in grams)
+impl std::ops::Mul<Quantity<Volume>> for Quantity<Density> {
+    type Output = Quantity<Weight>;
+
+    fn mul(self, rhs: Quantity<Volume>) -> Quantity<Weight> {
+        let grams_per_liter = self.convert(Density::KilogramsPerCubicMeter).amount;
+        let liters = rhs.convert(Volume::Liters).amount;
+        Quantity::new(grams_per_liter * liters, Weight::Grams)
+    }
+}
+
+/// Volume * Density = Weight (in grams)
+impl std::ops::Mul<Quantity<Density>> for Quantity<Volume> {
+    type Output = Quantity<Weight>;
+
+    fn mul(self, rhs: Quantity<Density>) -> Quantity<Weight> {
+        rhs * self
+    }
+}
+
+impl UnitAliases for Density {
+    fn all() -> &'static [Self] {
+        &[
+            Density::KilogramsPerCubicMeter,
+            Density::GramsPerCubicCentimeter,
+            Density::PoundsPerCubicFoot,
+            Density::PoundsPerUSGallon,
+        ]
+    }
+
+    fn aliases(&self) -> &'static [&'static str] {
+        match self {
+            Density::KilogramsPerCubicMeter => &[
+                "kg/m³",
+                "g/l",
+                "kilograms per cubic meter",
+                "kilograms per cubic metre",
+            ],
+            Density::GramsPerCubicCentimeter => &[
+                "g/cm³",
+                "g/cc",
+                "g/ml",
+                "grams per cubic centimeter",
+                "grams per cubic centimetre",
+            ],
+            Density::PoundsPerCubicFoot => &["lb/ft³", "pcf", "pounds per cubic foot"],
+            Density::PoundsPerUSGallon => {
+                &["lb/gal", "ppg", "pounds per gallon", "pounds per us gallon"]
+            }
+        }
+    }
+}
+
+impl UnitNames for Density {
+    fn symbol(&self) -> &'static str {
+        match self {
+            Density::KilogramsPerCubicMeter => "kg/m³",
+            Density::GramsPerCubicCentimeter => "g/cm³",
+            Density::PoundsPerCubicFoot => "lb/ft³",
+            Density::PoundsPerUSGallon => "lb/gal",
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        match self {
+            Density::KilogramsPerCubicMeter => "kilogram per cubic meter",
+            Density::GramsPerCubicCentimeter => "gram per cubic centimeter",
+            Density::PoundsPerCubicFoot => "pound per cubic foot",
+            Density::PoundsPerUSGallon => "pound per US gallon",
+        }
+    }
+
+    fn plural_name(&self) -> &'static str {
+        match self {
+            Density::KilogramsPerCubicMeter => "kilograms per cubic meter",
+            Density::GramsPerCubicCentimeter => "grams per cubic centimeter",
+            Density::PoundsPerCubicFoot => "pounds per cubic foot",
+            Density::PoundsPerUSGallon => "pounds per US gallon",
+        }
+    }
+}
+
+impl std::fmt::Display for Density {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.symbol())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use crate::{
+        assert_approx_eq,
+        math::Float,
+        units::{
+            density::Density,
+            quantity::{Convertable, Quantity},
+            volume::Volume,
+            weight::Weight,
+        },
+    };
+
+    #[rstest]
+    #[case(
+        1.0,
+        Density::GramsPerCubicCentimeter,
+        Density::KilogramsPerCubicMeter,
+        1000.0
+    )]
+    #[case(
+        1.0,
+        Density::PoundsPerCubicFoot,
+        Density::KilogramsPerCubicMeter,
+        16.018463
+    )]
+    #[case(
+        1.0,
+        Density::PoundsPerUSGallon,
+        Density::GramsPerCubicCentimeter,
+        0.119826
+    )]
+    #[case(
+        62.4,
+        Density::PoundsPerCubicFoot,
+        Density::PoundsPerUSGallon,
+        8.341564
+    )]
+    fn can_convert_between_density_units(
+        #[case] amount: Float,
+        #[case] units: Density,
+        #[case] to_units: Density,
+        #[case] expected_amount: Float,
+    ) {
+        let actual = Quantity { amount, units }.convert(to_units);
+        assert!(
+            (actual.amount - expected_amount).abs() <= 0.001,
+            "quantities not equal - expected: {} {:?}, actual: {} {:?}",
+            actual.amount,
+            actual.units,
+            expected_amount,
+            to_units
+        );
+
+        assert_eq!(actual.units, to_units);
+    }
+
+    #[rstest]
+    #[case(
+        Quantity::new(1.0, Weight::Kilograms),
+        Quantity::new(1.0, Volume::Liters),
+        1000.0
+    )]
+    #[case(
+        Quantity::new(500.0, Weight::Grams),
+        Quantity::new(250.0, Volume::Milliliter),
+        2000.0
+    )]
+    #[case(
+        Quantity::new(8.34, Weight::Pounds),
+        Quantity::new(1.0, Volume::USGallons),
+        999.35
+    )]
+    fn can_divide_weight_by_volume(
+        #[case] weight: Quantity<Weight>,
+        #[case] volume: Quantity<Volume>,
+        #[case] expected_kilograms_per_cubic_meter: Float,
+    ) {
+        let density = weight / volume;
+        assert_approx_eq!(density.amount, expected_kilograms_per_cubic_meter, 0.01);
+        assert_eq!(density.units, Density::KilogramsPerCubicMeter);
+    }
+
+    #[test]
+    fn can_multiply_density_by_volume() {
+        let density = Quantity::new(1.0, Density::GramsPerCubicCentimeter);
+        let volume = Quantity::new(2.0, Volume::Liters);
+
+        let weight = density * volume;
+        assert_approx_eq!(weight.amount, 2000.0, 0.01);
+        assert_eq!(weight.units, Weight::Grams);
+
+        let weight = volume * density;
+        assert_approx_eq!(weight.amount, 2000.0, 0.01);
+        assert_eq!(weight.units, Weight::Grams);
+    }
+
+    #[test]
+    fn can_divide_weight_by_density() {
+        let weight = Quantity::new(3.0, Weight::Kilograms);
+        let density = Quantity::new(1.5, Density::GramsPerCubicCentimeter);
+
+        let volume = weight / density;
+        assert_approx_eq!(volume.amount, 2.0, 0.0001);
+        assert_eq!(volume.units, Volume::Liters);
+    }
+}
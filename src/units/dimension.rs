@@ -0,0 +1,498 @@
+//! Compile-time dimensional analysis. A `Measure` holds a value in SI base units with its dimension
+//! as a type parameter, so formulas that combine incompatible dimensions fail to compile.
+
+use std::marker::PhantomData;
+
+use crate::math::Float;
+
+use super::{
+    area, density,
+    distance::Distance,
+    pressure,
+    quantity::{Quantity, Unit},
+    speed,
+    temperature::TemperatureDifference,
+    time, volume,
+    weight::Weight,
+};
+
+/// A type-level integer, used as the exponent of a base dimension.
+pub trait Integer {
+    const VALUE: i32;
+}
+
+/// Type-level zero
+pub struct Z0;
+
+/// Type-level successor (N + 1), only wraps `Z0` or another `P`
+pub struct P<N>(PhantomData<N>);
+
+/// Type-level predecessor (N - 1), only wraps `Z0` or another `N`
+pub struct N<N>(PhantomData<N>);
+
+pub type P1 = P<Z0>;
+pub type P2 = P<P1>;
+pub type P3 = P<P2>;
+pub type N1 = N<Z0>;
+pub type N2 = N<N1>;
+pub type N3 = N<N2>;
+
+impl Integer for Z0 {
+    const VALUE: i32 = 0;
+}
+
+impl<A: Integer> Integer for P<A> {
+    const VALUE: i32 = A::VALUE + 1;
+}
+
+impl<A: Integer> Integer for N<A> {
+    const VALUE: i32 = A::VALUE - 1;
+}
+
+/// Type-level addition
+pub trait AddInteger<Rhs> {
+    type Output: Integer;
+}
+
+/// Type-level negation
+pub trait NegateInteger {
+    type Output: Integer;
+}
+
+pub type Sum<A, B> = <A as AddInteger<B>>::Output;
+pub type Negated<A> = <A as NegateInteger>::Output;
+pub type Difference<A, B> = Sum<A, Negated<B>>;
+
+impl<B: Integer> AddInteger<B> for Z0 {
+    type Output = B;
+}
+
+impl<A: Integer> AddInteger<Z0> for P<A> {
+    type Output = P<A>;
+}
+
+impl<A: Integer> AddInteger<Z0> for N<A> {
+    type Output = N<A>;
+}
+
+// (a + 1) + (b + 1) = ((a + 1) + b) + 1
+impl<A: Integer, B: Integer> AddInteger<P<B>> for P<A>
+where
+    P<A>: AddInteger<B>,
+{
+    type Output = P<Sum<P<A>, B>>;
+}
+
+// (a - 1) + (b - 1) = ((a - 1) + b) - 1
+impl<A: Integer, B: Integer> AddInteger<N<B>> for N<A>
+where
+    N<A>: AddInteger<B>,
+{
+    type Output = N<Sum<N<A>, B>>;
+}
+
+// (a + 1) + (b - 1) = a + b
+impl<A: Integer, B: Integer> AddInteger<N<B>> for P<A>
+where
+    A: AddInteger<B>,
+{
+    type Output = Sum<A, B>;
+}
+
+// (a - 1) + (b + 1) = a + b
+impl<A: Integer, B: Integer> AddInteger<P<B>> for N<A>
+where
+    A: AddInteger<B>,
+{
+    type Output = Sum<A, B>;
+}
+
+impl NegateInteger for Z0 {
+    type Output = Z0;
+}
+
+impl<A: NegateInteger> NegateInteger for P<A> {
+    type Output = N<Negated<A>>;
+}
+
+impl<A: NegateInteger> NegateInteger for N<A> {
+    type Output = P<Negated<A>>;
+}
+
+/// A physical dimension, expressed as the exponents of length, mass, time and temperature.
+pub struct Dimension<L, M, T, K>(PhantomData<(L, M, T, K)>);
+
+pub type Dimensionless = Dimension<Z0, Z0, Z0, Z0>;
+pub type Length = Dimension<P1, Z0, Z0, Z0>;
+pub type Mass = Dimension<Z0, P1, Z0, Z0>;
+pub type Time = Dimension<Z0, Z0, P1, Z0>;
+pub type Temperature = Dimension<Z0, Z0, Z0, P1>;
+pub type Area = Dimension<P2, Z0, Z0, Z0>;
+pub type Volume = Dimension<P3, Z0, Z0, Z0>;
+pub type Speed = Dimension<P1, Z0, N1, Z0>;
+pub type Acceleration = Dimension<P1, Z0, N2, Z0>;
+pub type Force = Dimension<P1, P1, N2, Z0>;
+pub type Pressure = Dimension<N1, P1, N2, Z0>;
+pub type Energy = Dimension<P2, P1, N2, Z0>;
+pub type Density = Dimension<N3, P1, Z0, Z0>;
+
+/// Dimensions which can be multiplied and divided to produce a new dimension
+pub trait DimensionExponents {
+    type Length: Integer;
+    type Mass: Integer;
+    type Time: Integer;
+    type Temperature: Integer;
+}
+
+impl<L: Integer, M: Integer, T: Integer, K: Integer> DimensionExponents for Dimension<L, M, T, K> {
+    type Length = L;
+    type Mass = M;
+    type Time = T;
+    type Temperature = K;
+}
+
+/// The dimension resulting from multiplying two dimensions
+pub type Product<A, B> = Dimension<
+    Sum<<A as DimensionExponents>::Length, <B as DimensionExponents>::Length>,
+    Sum<<A as DimensionExponents>::Mass, <B as DimensionExponents>::Mass>,
+    Sum<<A as DimensionExponents>::Time, <B as DimensionExponents>::Time>,
+    Sum<<A as DimensionExponents>::Temperature, <B as DimensionExponents>::Temperature>,
+>;
+
+/// The dimension resulting from dividing two dimensions
+pub type Quotient<A, B> = Dimension<
+    Difference<<A as DimensionExponents>::Length, <B as DimensionExponents>::Length>,
+    Difference<<A as DimensionExponents>::Mass, <B as DimensionExponents>::Mass>,
+    Difference<<A as DimensionExponents>::Time, <B as DimensionExponents>::Time>,
+    Difference<<A as DimensionExponents>::Temperature, <B as DimensionExponents>::Temperature>,
+>;
+
+/// A value in SI base units (meters, kilograms, seconds, kelvin) with a compile-time dimension.
+///
+/// ```compile_fail
+/// use trail_sense_sol::units::dimension::{self, Measure};
+/// let _ = Measure::<dimension::Length>::new(1.0) + Measure::<dimension::Time>::new(1.0);
+/// ```
+pub struct Measure<D> {
+    pub value: Float,
+    dimension: PhantomData<D>,
+}
+
+impl<D> Measure<D> {
+    /// Creates a measure from a value in SI base units
+    pub fn new(value: Float) -> Self {
+        Measure {
+            value,
+            dimension: PhantomData,
+        }
+    }
+}
+
+impl<D: DimensionExponents> Measure<D> {
+    /// Returns the exponents of length, mass, time and temperature of the measure
+    pub fn exponents(&self) -> [i32; 4] {
+        [
+            D::Length::VALUE,
+            D::Mass::VALUE,
+            D::Time::VALUE,
+            D::Temperature::VALUE,
+        ]
+    }
+}
+
+impl<D> Clone for Measure<D> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<D> Copy for Measure<D> {}
+
+impl<D> std::fmt::Debug for Measure<D> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Measure")
+            .field("value", &self.value)
+            .finish()
+    }
+}
+
+impl<D> PartialEq for Measure<D> {
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+}
+
+impl<D> PartialOrd for Measure<D> {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        self.value.partial_cmp(&other.value)
+    }
+}
+
+impl<D> std::ops::Add for Measure<D> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Measure::new(self.value + rhs.value)
+    }
+}
+
+impl<D> std::ops::Sub for Measure<D> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        Measure::new(self.value - rhs.value)
+    }
+}
+
+impl<D> std::ops::Neg for Measure<D> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Measure::new(-self.value)
+    }
+}
+
+impl<D> std::ops::Mul<Float> for Measure<D> {
+    type Output = Self;
+
+    fn mul(self, rhs: Float) -> Self {
+        Measure::new(self.value * rhs)
+    }
+}
+
+impl<D> std::ops::Mul<Measure<D>> for Float {
+    type Output = Measure<D>;
+
+    fn mul(self, rhs: Measure<D>) -> Measure<D> {
+        Measure::new(self * rhs.value)
+    }
+}
+
+impl<D> std::ops::Div<Float> for Measure<D> {
+    type Output = Self;
+
+    fn div(self, rhs: Float) -> Self {
+        Measure::new(self.value / rhs)
+    }
+}
+
+impl<A, B> std::ops::Mul<Measure<B>> for Measure<A>
+where
+    A: DimensionExponents,
+    B: DimensionExponents,
+    A::Length: AddInteger<B::Length>,
+    A::Mass: AddInteger<B::Mass>,
+    A::Time: AddInteger<B::Time>,
+    A::Temperature: AddInteger<B::Temperature>,
+{
+    type Output = Measure<Product<A, B>>;
+
+    fn mul(self, rhs: Measure<B>) -> Self::Output {
+        Measure::new(self.value * rhs.value)
+    }
+}
+
+impl<A, B> std::ops::Div<Measure<B>> for Measure<A>
+where
+    A: DimensionExponents,
+    B: DimensionExponents,
+    B::Length: NegateInteger,
+    B::Mass: NegateInteger,
+    B::Time: NegateInteger,
+    B::Temperature: NegateInteger,
+    A::Length: AddInteger<Negated<B::Length>>,
+    A::Mass: AddInteger<Negated<B::Mass>>,
+    A::Time: AddInteger<Negated<B::Time>>,
+    A::Temperature: AddInteger<Negated<B::Temperature>>,
+{
+    type Output = Measure<Quotient<A, B>>;
+
+    fn div(self, rhs: Measure<B>) -> Self::Output {
+        Measure::new(self.value / rhs.value)
+    }
+}
+
+/// A unit with a known physical dimension, allowing conversion between `Quantity` and `Measure`.
+pub trait DimensionedUnit: Unit {
+    type Dimension;
+
+    /// Returns the multiplier to convert the unit's base unit to the SI unit of the dimension.
+    fn base_to_si() -> Float {
+        1.0
+    }
+}
+
+impl<U: DimensionedUnit> From<Quantity<U>> for Measure<U::Dimension> {
+    fn from(quantity: Quantity<U>) -> Self {
+        let base = (quantity.amount + quantity.units.offset_from_base())
+            * quantity.units.multiplier_to_base();
+        Measure::new(base * U::base_to_si())
+    }
+}
+
+impl<D> Measure<D> {
+    /// Converts the measure to a quantity in the given units
+    pub fn to_quantity<U: DimensionedUnit<Dimension = D>>(&self, units: U) -> Quantity<U> {
+        let base = self.value / U::base_to_si();
+        Quantity::new(
+            base / units.multiplier_to_base() - units.offset_from_base(),
+            units,
+        )
+    }
+}
+
+impl DimensionedUnit for Distance {
+    type Dimension = Length;
+}
+
+impl DimensionedUnit for time::Time {
+    type Dimension = Time;
+}
+
+impl DimensionedUnit for Weight {
+    type Dimension = Mass;
+
+    fn base_to_si() -> Float {
+        // Grams to kilograms
+        0.001
+    }
+}
+
+impl DimensionedUnit for volume::Volume {
+    type Dimension = Volume;
+
+    fn base_to_si() -> Float {
+        // Liters to cubic meters
+        0.001
+    }
+}
+
+impl DimensionedUnit for area::Area {
+    type Dimension = Area;
+}
+
+impl DimensionedUnit for speed::Speed {
+    type Dimension = Speed;
+}
+
+impl DimensionedUnit for density::Density {
+    type Dimension = Density;
+}
+
+impl DimensionedUnit for pressure::Pressure {
+    type Dimension = Pressure;
+}
+
+impl DimensionedUnit for TemperatureDifference {
+    type Dimension = Temperature;
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::assert_approx_eq;
+    use crate::units::{
+        density::Density as DensityUnit, pressure::Pressure as PressureUnit,
+        speed::Speed as SpeedUnit, time::Time as TimeUnit, volume::Volume as VolumeUnit,
+    };
+
+    #[rstest]
+    #[case(Z0::VALUE, 0)]
+    #[case(P3::VALUE, 3)]
+    #[case(N3::VALUE, -3)]
+    #[case(<Sum<P2, P1> as Integer>::VALUE, 3)]
+    #[case(<Sum<P2, N3> as Integer>::VALUE, -1)]
+    #[case(<Sum<N2, N1> as Integer>::VALUE, -3)]
+    #[case(<Sum<N1, P1> as Integer>::VALUE, 0)]
+    #[case(<Difference<P1, P3> as Integer>::VALUE, -2)]
+    #[case(<Difference<N1, N3> as Integer>::VALUE, 2)]
+    fn test_type_level_integers(#[case] actual: i32, #[case] expected: i32) {
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_derived_dimensions() {
+        let length = Measure::<Length>::new(10.0);
+        let time = Measure::<Time>::new(2.0);
+        let mass = Measure::<Mass>::new(3.0);
+
+        let speed: Measure<Speed> = length / time;
+        assert_approx_eq!(speed.value, 5.0);
+        assert_eq!(speed.exponents(), [1, 0, -1, 0]);
+
+        let acceleration: Measure<Acceleration> = speed / time;
+        let force: Measure<Force> = mass * acceleration;
+        assert_approx_eq!(force.value, 7.5);
+        assert_eq!(force.exponents(), [1, 1, -2, 0]);
+
+        let area: Measure<Area> = length * length;
+        let pressure: Measure<Pressure> = force / area;
+        assert_approx_eq!(pressure.value, 0.075);
+
+        let energy: Measure<Energy> = force * length;
+        assert_approx_eq!(energy.value, 75.0);
+
+        let ratio: Measure<Dimensionless> = length / length;
+        assert_approx_eq!(ratio.value, 1.0);
+        assert_eq!(ratio.exponents(), [0, 0, 0, 0]);
+    }
+
+    #[test]
+    fn test_same_dimension_arithmetic() {
+        let a = Measure::<Length>::new(3.0);
+        let b = Measure::<Length>::new(1.0);
+        assert_approx_eq!((a + b).value, 4.0);
+        assert_approx_eq!((a - b).value, 2.0);
+        assert_approx_eq!((-a).value, -3.0);
+        assert_approx_eq!((a * 2.0).value, 6.0);
+        assert_approx_eq!((2.0 * a).value, 6.0);
+        assert_approx_eq!((a / 2.0).value, 1.5);
+        assert!(a > b);
+    }
+
+    #[rstest]
+    #[case(Quantity::new(2.0, Distance::Kilometers), 2000.0)]
+    #[case(Quantity::new(1.0, Distance::Feet), 0.3048)]
+    fn test_distance_to_length(#[case] quantity: Quantity<Distance>, #[case] expected: Float) {
+        let measure: Measure<Length> = quantity.into();
+        assert_approx_eq!(measure.value, expected, 0.0001);
+    }
+
+    #[test]
+    fn test_quantity_round_trip() {
+        let weight: Measure<Mass> = Quantity::new(500.0, Weight::Grams).into();
+        assert_approx_eq!(weight.value, 0.5);
+        assert_approx_eq!(weight.to_quantity(Weight::Pounds).amount, 1.10231, 0.0001);
+
+        let volume: Measure<Volume> = Quantity::new(2.0, VolumeUnit::Liters).into();
+        assert_approx_eq!(volume.value, 0.002);
+        assert_approx_eq!(
+            volume.to_quantity(VolumeUnit::Milliliter).amount,
+            2000.0,
+            0.01
+        );
+
+        let pressure: Measure<Pressure> = Quantity::new(1013.25, PressureUnit::Hectopascals).into();
+        assert_approx_eq!(pressure.value, 101325.0, 0.1);
+        assert_approx_eq!(
+            pressure.to_quantity(PressureUnit::Atmospheres).amount,
+            1.0,
+            0.0001
+        );
+    }
+
+    #[test]
+    fn test_derived_measure_to_quantity() {
+        let distance: Measure<Length> = Quantity::new(10.0, Distance::Kilometers).into();
+        let time: Measure<Time> = Quantity::new(2.0, TimeUnit::Hours).into();
+        let speed = (distance / time).to_quantity(SpeedUnit::KilometersPerHour);
+        assert_approx_eq!(speed.amount, 5.0, 0.0001);
+
+        let mass: Measure<Mass> = Quantity::new(1.0, Weight::Kilograms).into();
+        let volume: Measure<Volume> = Quantity::new(1.0, VolumeUnit::Liters).into();
+        let density = (mass / volume).to_quantity(DensityUnit::GramsPerCubicCentimeter);
+        assert_approx_eq!(density.amount, 1.0, 0.0001);
+    }
+}
@@ -1,6 +1,13 @@
-use super::quantity::Unit;
+use crate::math::Float;
+
+use super::{
+    format::UnitNames,
+    parse::UnitAliases,
+    quantity::{AdditiveUnit, Unit},
+};
 
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Distance {
     Centimeters = 1,
     Inches = 2,
@@ -14,7 +21,7 @@ pub enum Distance {
 }
 
 impl Unit for Distance {
-    fn multiplier_to_base(&self) -> f32 {
+    fn multiplier_to_base(&self) -> Float {
         match self {
             Distance::Meters => 1.0,
             Distance::Kilometers => 1000.0,
@@ -29,8 +36,103 @@ impl Unit for Distance {
     }
 }
 
+impl AdditiveUnit for Distance {}
+
+impl UnitAliases for Distance {
+    fn all() -> &'static [Self] {
+        &[
+            Distance::Centimeters,
+            Distance::Inches,
+            Distance::Miles,
+            Distance::Yards,
+            Distance::Feet,
+            Distance::Kilometers,
+            Distance::Meters,
+            Distance::NauticalMiles,
+            Distance::Millimeters,
+        ]
+    }
+
+    fn aliases(&self) -> &'static [&'static str] {
+        match self {
+            Distance::Centimeters => &[
+                "cm",
+                "centimeter",
+                "centimeters",
+                "centimetre",
+                "centimetres",
+            ],
+            Distance::Inches => &["in", "inch", "inches", "\"", "″", "”"],
+            Distance::Miles => &["mi", "mile", "miles"],
+            Distance::Yards => &["yd", "yds", "yard", "yards"],
+            Distance::Feet => &["ft", "foot", "feet", "'", "′", "’"],
+            Distance::Kilometers => &["km", "kilometer", "kilometers", "kilometre", "kilometres"],
+            Distance::Meters => &["m", "meter", "meters", "metre", "metres"],
+            Distance::NauticalMiles => &["nmi", "nautical mile", "nautical miles"],
+            Distance::Millimeters => &[
+                "mm",
+                "millimeter",
+                "millimeters",
+                "millimetre",
+                "millimetres",
+            ],
+        }
+    }
+}
+
+impl UnitNames for Distance {
+    fn symbol(&self) -> &'static str {
+        match self {
+            Distance::Centimeters => "cm",
+            Distance::Inches => "in",
+            Distance::Miles => "mi",
+            Distance::Yards => "yd",
+            Distance::Feet => "ft",
+            Distance::Kilometers => "km",
+            Distance::Meters => "m",
+            Distance::NauticalMiles => "nmi",
+            Distance::Millimeters => "mm",
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        match self {
+            Distance::Centimeters => "centimeter",
+            Distance::Inches => "inch",
+            Distance::Miles => "mile",
+            Distance::Yards => "yard",
+            Distance::Feet => "foot",
+            Distance::Kilometers => "kilometer",
+            Distance::Meters => "meter",
+            Distance::NauticalMiles => "nautical mile",
+            Distance::Millimeters => "millimeter",
+        }
+    }
+
+    fn plural_name(&self) -> &'static str {
+        match self {
+            Distance::Centimeters => "centimeters",
+            Distance::Inches => "inches",
+            Distance::Miles => "miles",
+            Distance::Yards => "yards",
+            Distance::Feet => "feet",
+            Distance::Kilometers => "kilometers",
+            Distance::Meters => "meters",
+            Distance::NauticalMiles => "nautical miles",
+            Distance::Millimeters => "millimeters",
+        }
+    }
+}
+
+impl std::fmt::Display for Distance {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.symbol())
+    }
+}
+
 #[cfg(test)]
 mod tests {
+    use crate::math::Float;
     use crate::units::{
         distance::Distance,
         quantity::{Convertable, Quantity},
@@ -62,10 +164,10 @@ mod tests {
     #[case(1.0, Distance::Yards, Distance::Feet, 3.0)]
     #[case(1.0, Distance::Miles, Distance::Feet, 5280.0)]
     fn can_convert_between_distance_units(
-        #[case] amount: f32,
+        #[case] amount: Float,
         #[case] units: Distance,
         #[case] to_units: Distance,
-        #[case] expected_amount: f32,
+        #[case] expected_amount: Float,
     ) {
         let actual = Quantity { amount, units }.convert(to_units);
         assert!(
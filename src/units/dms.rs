@@ -0,0 +1,215 @@
+use crate::math::Float;
+
+use super::{
+    angle::Angle,
+    parse::{ParseQuantityError, parse_unit, read_number, read_unit},
+    quantity::{Convertable, Quantity},
+};
+
+/// The most decimal places shown, which keeps the smallest step within a whole number
+const MAX_DECIMALS: usize = 9;
+
+/// Formats an angle as degrees, minutes and seconds (ex. "12° 34′ 56.7″"). The seconds are rounded to
+/// the given number of decimal places (at most 9) and carried into the minutes and degrees, so 60″ is
+/// never shown. Angles which aren't finite are formatted as a plain number (ex. "NaN").
+pub fn format_dms(angle: &Quantity<Angle>, second_decimals: usize) -> String {
+    let second_decimals = second_decimals.min(MAX_DECIMALS);
+    let Some((sign, total, scale)) = to_parts(angle, 3600, second_decimals) else {
+        return angle.amount.to_string();
+    };
+    let seconds = total % (60 * scale);
+    let minutes = (total / (60 * scale)) % 60;
+    let degrees = total / (3600 * scale);
+    format!(
+        "{}{}° {}′ {}″",
+        sign,
+        degrees,
+        minutes,
+        format_fixed(seconds, scale, second_decimals)
+    )
+}
+
+/// Formats an angle as degrees and decimal minutes (ex. "12° 34.945′"). The minutes are rounded to the
+/// given number of decimal places (at most 9) and carried into the degrees, so 60′ is never shown.
+/// Angles which aren't finite are formatted as a plain number (ex. "NaN").
+pub fn format_ddm(angle: &Quantity<Angle>, minute_decimals: usize) -> String {
+    let minute_decimals = minute_decimals.min(MAX_DECIMALS);
+    let Some((sign, total, scale)) = to_parts(angle, 60, minute_decimals) else {
+        return angle.amount.to_string();
+    };
+    let minutes = total % (60 * scale);
+    let degrees = total / (60 * scale);
+    format!(
+        "{}{}° {}′",
+        sign,
+        degrees,
+        format_fixed(minutes, scale, minute_decimals)
+    )
+}
+
+/// Parses an angle written in degrees, minutes and seconds (ex. "12° 34′ 56.7″", "-12 34 56.7" or
+/// "12:34:56.7"), degrees and decimal minutes (ex. "12° 34.945′") or decimal degrees (ex. "12.58°").
+/// Components without a symbol are read in order as degrees, minutes and seconds. The result is in degrees.
+pub fn parse_dms(text: &str) -> Result<Quantity<Angle>, ParseQuantityError> {
+    let text = text.trim().replace('−', "-");
+    if text.is_empty() {
+        return Err(ParseQuantityError::Empty);
+    }
+
+    let (sign, mut remaining) = match text.strip_prefix('-') {
+        Some(rest) => (-1.0, rest),
+        None => (1.0, text.strip_prefix('+').unwrap_or(&text)),
+    };
+
+    // Degrees, minutes and seconds
+    let mut components: [Float; 3] = [0.0; 3];
+    let mut next = 0;
+    loop {
+        remaining = remaining.trim_start_matches(|c: char| c.is_whitespace() || c == ':');
+        if remaining.is_empty() {
+            break;
+        }
+
+        let (amount, rest) = read_number(remaining)?;
+        let (unit_text, rest) = read_unit(rest);
+        let position = match unit_text {
+            "" | ":" => next,
+            _ => match parse_unit::<Angle>(unit_text) {
+                Some(Angle::Degrees) => 0,
+                Some(Angle::ArcMinutes) => 1,
+                Some(Angle::ArcSeconds) => 2,
+                _ => return Err(ParseQuantityError::UnknownUnit(unit_text.to_string())),
+            },
+        };
+
+        let is_out_of_range = position > 0 && amount >= 60.0;
+        if position < next || position > 2 || is_out_of_range {
+            return Err(ParseQuantityError::InvalidAngle(text.to_string()));
+        }
+
+        components[position] = amount;
+        next = position + 1;
+        remaining = rest;
+    }
+
+    if next == 0 {
+        return Err(ParseQuantityError::InvalidNumber(text.to_string()));
+    }
+
+    let degrees = components[0] + components[1] / 60.0 + components[2] / 3600.0;
+    Ok(Quantity::new(sign * degrees, Angle::Degrees))
+}
+
+/// Returns the sign, the absolute angle as a whole number of the smallest displayed step and the
+/// number of steps per displayed unit. Returns None if the angle isn't finite.
+fn to_parts(
+    angle: &Quantity<Angle>,
+    units_per_degree: u64,
+    decimals: usize,
+) -> Option<(&'static str, u64, u64)> {
+    let degrees = angle.convert(Angle::Degrees).amount;
+    if !degrees.is_finite() {
+        return None;
+    }
+    let scale = 10u64.pow(decimals as u32);
+    let total = (degrees.abs() * (units_per_degree * scale) as Float).round() as u64;
+    let sign = if degrees < 0.0 && total > 0 { "-" } else { "" };
+    Some((sign, total, scale))
+}
+
+fn format_fixed(value: u64, scale: u64, decimals: usize) -> String {
+    if decimals == 0 {
+        value.to_string()
+    } else {
+        format!(
+            "{}.{:0width$}",
+            value / scale,
+            value % scale,
+            width = decimals
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::{assert_approx_eq, math::consts::PI};
+
+    #[rstest]
+    #[case(12.5822, 1, "12° 34′ 55.9″")]
+    #[case(12.5822, 0, "12° 34′ 56″")]
+    #[case(-12.5, 0, "-12° 30′ 0″")]
+    #[case(10.99999, 0, "11° 0′ 0″")]
+    #[case(10.9999, 2, "10° 59′ 59.64″")]
+    #[case(0.0, 1, "0° 0′ 0.0″")]
+    #[case(-0.00001, 0, "0° 0′ 0″")]
+    #[case(-0.001, 1, "-0° 0′ 3.6″")]
+    #[case(359.99999, 1, "360° 0′ 0.0″")]
+    #[case(0.0, 25, "0° 0′ 0.000000000″")]
+    #[case(Float::NAN, 1, "NaN")]
+    #[case(Float::INFINITY, 1, "inf")]
+    fn test_format_dms(#[case] degrees: Float, #[case] decimals: usize, #[case] expected: &str) {
+        assert_eq!(format_dms(&Angle::degrees(degrees), decimals), expected);
+    }
+
+    #[test]
+    fn test_format_dms_converts_units() {
+        let angle = Quantity::new(PI, Angle::Radians);
+        assert_eq!(format_dms(&angle, 0), "180° 0′ 0″");
+    }
+
+    #[rstest]
+    #[case(12.5822, 3, "12° 34.932′")]
+    #[case(12.5822, 0, "12° 35′")]
+    #[case(-0.0, 20, "0° 0.000000000′")]
+    #[case(Float::NAN, 3, "NaN")]
+    #[case(-45.25, 1, "-45° 15.0′")]
+    #[case(44.999999, 2, "45° 0.00′")]
+    fn test_format_ddm(#[case] degrees: Float, #[case] decimals: usize, #[case] expected: &str) {
+        assert_eq!(format_ddm(&Angle::degrees(degrees), decimals), expected);
+    }
+
+    #[rstest]
+    #[case("12° 34′ 55.9″", 12.58219)]
+    #[case("12°34'55.9\"", 12.58219)]
+    #[case("-12° 30′", -12.5)]
+    #[case("− 12 30 0", -12.5)]
+    #[case("12:34:55.9", 12.58219)]
+    #[case("12 34.932", 12.5822)]
+    #[case("12° 34.932′", 12.5822)]
+    #[case("12.5822°", 12.5822)]
+    #[case("12.5822", 12.5822)]
+    #[case("30′", 0.5)]
+    #[case("12 deg 30 arcmin", 12.5)]
+    #[case("+0° 0′ 36″", 0.01)]
+    fn test_parse_dms(#[case] text: &str, #[case] expected: Float) {
+        let actual = parse_dms(text).unwrap();
+        assert_approx_eq!(actual.amount, expected, 0.0001);
+        assert_eq!(actual.units, Angle::Degrees);
+    }
+
+    #[rstest]
+    #[case("", ParseQuantityError::Empty)]
+    #[case("-", ParseQuantityError::InvalidNumber("-".to_string()))]
+    #[case("12° 60′", ParseQuantityError::InvalidAngle("12° 60′".to_string()))]
+    #[case("12 30 75", ParseQuantityError::InvalidAngle("12 30 75".to_string()))]
+    #[case("30′ 12°", ParseQuantityError::InvalidAngle("30′ 12°".to_string()))]
+    #[case("1 2 3 4", ParseQuantityError::InvalidAngle("1 2 3 4".to_string()))]
+    #[case("12 rad", ParseQuantityError::UnknownUnit("rad".to_string()))]
+    fn test_parse_dms_errors(#[case] text: &str, #[case] expected: ParseQuantityError) {
+        assert_eq!(parse_dms(text), Err(expected));
+    }
+
+    #[rstest]
+    #[case(12.5822, 1)]
+    #[case(-77.03653, 2)]
+    #[case(0.5, 0)]
+    fn test_dms_round_trip(#[case] degrees: Float, #[case] decimals: usize) {
+        let formatted = format_dms(&Angle::degrees(degrees), decimals);
+        let parsed = parse_dms(&formatted).unwrap();
+        let tolerance = 0.5 / 3600.0 / (10.0 as Float).powi(decimals as i32);
+        assert_approx_eq!(parsed.amount, degrees, tolerance + 1e-5);
+    }
+}
@@ -0,0 +1,350 @@
+use crate::math::Float;
+
+use super::quantity::{Convertable, Quantity, Unit};
+
+/// A unit with human-readable symbols and names.
+pub trait UnitNames: Unit {
+    /// Returns the short symbol of the unit (ex. "km")
+    fn symbol(&self) -> &'static str;
+    /// Returns the singular name of the unit (ex. "kilometer")
+    fn name(&self) -> &'static str;
+    /// Returns the plural name of the unit (ex. "kilometers")
+    fn plural_name(&self) -> &'static str;
+    /// Returns the text placed between an amount and the symbol of the unit
+    fn symbol_separator(&self) -> &'static str {
+        " "
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Precision {
+    DecimalPlaces(u32),
+    SignificantFigures(u32),
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum UnitStyle {
+    /// Unit symbols (ex. "5 km")
+    Short,
+    /// Unit names, pluralized when needed (ex. "5 kilometers")
+    Long,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct QuantityFormatter {
+    pub precision: Precision,
+    pub style: UnitStyle,
+    /// Removes zeros after the decimal point which don't change the value (ex. "1.50" becomes "1.5")
+    pub strip_trailing_zeros: bool,
+}
+
+impl Default for QuantityFormatter {
+    fn default() -> Self {
+        QuantityFormatter {
+            precision: Precision::DecimalPlaces(2),
+            style: UnitStyle::Short,
+            strip_trailing_zeros: false,
+        }
+    }
+}
+
+impl QuantityFormatter {
+    pub fn new(precision: Precision, style: UnitStyle) -> Self {
+        QuantityFormatter {
+            precision,
+            style,
+            strip_trailing_zeros: false,
+        }
+    }
+
+    /// Formats a number using the precision rules of the formatter
+    pub fn format_amount(&self, amount: Float) -> String {
+        let text = match self.precision {
+            Precision::DecimalPlaces(places) => format!("{:.*}", places as usize, amount),
+            Precision::SignificantFigures(figures) => format_significant(amount, figures),
+        };
+
+        let text = if self.strip_trailing_zeros && text.contains('.') {
+            text.trim_end_matches('0').trim_end_matches('.').to_string()
+        } else {
+            text
+        };
+
+        // Avoid displaying "-0" when a small negative value rounds to zero
+        if text.starts_with('-') && text[1..].chars().all(|c| c == '0' || c == '.') {
+            text[1..].to_string()
+        } else {
+            text
+        }
+    }
+
+    /// Formats a quantity in its current units (ex. "5.25 km")
+    pub fn format<U: UnitNames>(&self, quantity: &Quantity<U>) -> String {
+        let amount = self.format_amount(quantity.amount);
+        self.with_units(amount, quantity.units)
+    }
+
+    /// Formats a quantity split across multiple units, ordered from largest to smallest (ex. "5 ft 11 in").
+    /// Every unit except the last is shown as a whole number, and components which are zero are omitted.
+    pub fn format_compound<U: UnitNames>(&self, quantity: &Quantity<U>, units: &[U]) -> String {
+        if units.is_empty() {
+            return self.format(quantity);
+        }
+
+        let is_negative = quantity.amount < 0.0;
+        let mut remaining = Quantity::new(quantity.amount.abs(), quantity.units);
+        let last = units.len() - 1;
+        let mut amounts = Vec::with_capacity(units.len());
+        for (i, unit) in units.iter().enumerate() {
+            let amount = remaining.convert(*unit).amount;
+            if i == last {
+                amounts.push(self.round(amount));
+            } else {
+                let whole = amount.floor();
+                amounts.push(whole);
+                remaining = Quantity::new(amount - whole, *unit);
+            }
+        }
+
+        // Carry values which rounded up to a whole unit of the previous (larger) unit
+        for i in (1..units.len()).rev() {
+            let size_of_previous = Quantity::new(1.0, units[i - 1]).convert(units[i]).amount;
+            if amounts[i] >= size_of_previous - size_of_previous * 1e-5 {
+                amounts[i] = (amounts[i] - size_of_previous).max(0.0);
+                amounts[i - 1] += 1.0;
+            }
+        }
+
+        let mut parts = Vec::new();
+        for (i, (amount, unit)) in amounts.iter().zip(units).enumerate() {
+            let text = if i == last {
+                self.format_amount(*amount)
+            } else {
+                format!("{}", amount)
+            };
+            if text.parse::<Float>().is_ok_and(|value| value != 0.0) {
+                parts.push(self.with_units(text, *unit));
+            }
+        }
+
+        // Small negative values which round to zero are shown without a sign
+        let is_zero = parts.is_empty();
+        if is_zero {
+            parts.push(self.with_units(self.format_amount(0.0), units[last]));
+        }
+
+        let formatted = parts.join(" ");
+        if is_negative && !is_zero {
+            format!("-{}", formatted)
+        } else {
+            formatted
+        }
+    }
+
+    fn round(&self, amount: Float) -> Float {
+        self.format_amount(amount).parse().unwrap_or(amount)
+    }
+
+    fn with_units<U: UnitNames>(&self, amount: String, units: U) -> String {
+        match self.style {
+            UnitStyle::Short => format!("{}{}{}", amount, units.symbol_separator(), units.symbol()),
+            UnitStyle::Long => {
+                let is_singular = amount == "1" || amount == "-1";
+                let name = if is_singular {
+                    units.name()
+                } else {
+                    units.plural_name()
+                };
+                format!("{} {}", amount, name)
+            }
+        }
+    }
+}
+
+fn format_significant(amount: Float, figures: u32) -> String {
+    if amount == 0.0 || !amount.is_finite() || figures == 0 {
+        return format!("{}", amount);
+    }
+
+    let decimals = |value: Float| (figures as i32 - 1 - value.abs().log10().floor() as i32).max(0);
+    let magnitude = figures as i32 - 1 - amount.abs().log10().floor() as i32;
+    let scale = Float::powi(10.0, magnitude);
+    let rounded = (amount * scale).round() / scale;
+    format!("{:.*}", decimals(rounded) as usize, rounded)
+}
+
+impl<U: UnitNames> std::fmt::Display for Quantity<U> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match f.precision() {
+            Some(precision) => write!(f, "{:.*}", precision, self.amount)?,
+            None => write!(f, "{}", self.amount)?,
+        }
+        write!(
+            f,
+            "{}{}",
+            self.units.symbol_separator(),
+            self.units.symbol()
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::units::{
+        angle::Angle,
+        area::Area,
+        density::Density,
+        distance::Distance,
+        parse::{UnitAliases, parse_unit},
+        pressure::Pressure,
+        speed::Speed,
+        temperature::{Temperature, TemperatureDifference},
+        time::Time,
+        volume::Volume,
+        weight::Weight,
+    };
+
+    #[rstest]
+    #[case(Precision::DecimalPlaces(2), false, 1.5, "1.50")]
+    #[case(Precision::DecimalPlaces(2), true, 1.5, "1.5")]
+    #[case(Precision::DecimalPlaces(2), true, 2.0, "2")]
+    #[case(Precision::DecimalPlaces(0), false, 2.5001, "3")]
+    #[case(Precision::DecimalPlaces(1), false, -0.01, "0.0")]
+    #[case(Precision::SignificantFigures(3), false, 12345.0, "12300")]
+    #[case(Precision::SignificantFigures(3), false, 1.23456, "1.23")]
+    #[case(Precision::SignificantFigures(2), false, 0.012345, "0.012")]
+    #[case(Precision::SignificantFigures(2), false, 9.96, "10")]
+    #[case(Precision::SignificantFigures(3), false, -0.5, "-0.500")]
+    #[case(Precision::SignificantFigures(3), true, -0.5, "-0.5")]
+    #[case(Precision::SignificantFigures(3), false, 0.0, "0")]
+    fn test_format_amount(
+        #[case] precision: Precision,
+        #[case] strip_trailing_zeros: bool,
+        #[case] amount: Float,
+        #[case] expected: &str,
+    ) {
+        let formatter = QuantityFormatter {
+            precision,
+            style: UnitStyle::Short,
+            strip_trailing_zeros,
+        };
+        assert_eq!(formatter.format_amount(amount), expected);
+    }
+
+    #[rstest]
+    #[case(
+        Quantity::new(5.25, Distance::NauticalMiles),
+        UnitStyle::Short,
+        "5.25 nmi"
+    )]
+    #[case(
+        Quantity::new(5.25, Distance::NauticalMiles),
+        UnitStyle::Long,
+        "5.25 nautical miles"
+    )]
+    #[case(Quantity::new(1.0, Distance::Feet), UnitStyle::Long, "1 foot")]
+    #[case(Quantity::new(2.0, Distance::Feet), UnitStyle::Long, "2 feet")]
+    #[case(Quantity::new(-1.0, Distance::Inches), UnitStyle::Long, "-1 inch")]
+    #[case(Quantity::new(45.0, Angle::Degrees), UnitStyle::Short, "45°")]
+    #[case(Quantity::new(-4.0, Temperature::Fahrenheit), UnitStyle::Short, "-4 °F")]
+    fn test_format(
+        #[case] quantity: Quantity<impl UnitNames>,
+        #[case] style: UnitStyle,
+        #[case] expected: &str,
+    ) {
+        let mut formatter = QuantityFormatter::new(Precision::DecimalPlaces(2), style);
+        formatter.strip_trailing_zeros = true;
+        assert_eq!(formatter.format(&quantity), expected);
+    }
+
+    #[rstest]
+    #[case(Quantity::new(71.0, Distance::Inches), &[Distance::Feet, Distance::Inches], "5 ft 11 in")]
+    #[case(Quantity::new(1.80, Distance::Meters), &[Distance::Feet, Distance::Inches], "5 ft 10.87 in")]
+    #[case(Quantity::new(6.0, Distance::Feet), &[Distance::Feet, Distance::Inches], "6 ft")]
+    #[case(Quantity::new(5.9999, Distance::Feet), &[Distance::Feet, Distance::Inches], "6 ft")]
+    #[case(Quantity::new(0.5, Distance::Feet), &[Distance::Feet, Distance::Inches], "6 in")]
+    #[case(Quantity::new(0.0, Distance::Feet), &[Distance::Feet, Distance::Inches], "0 in")]
+    #[case(Quantity::new(-71.0, Distance::Inches), &[Distance::Feet, Distance::Inches], "-5 ft 11 in")]
+    #[case(Quantity::new(-0.0001, Distance::Feet), &[Distance::Feet, Distance::Inches], "0 in")]
+    fn test_format_compound_distance(
+        #[case] quantity: Quantity<Distance>,
+        #[case] units: &[Distance],
+        #[case] expected: &str,
+    ) {
+        let formatter = QuantityFormatter {
+            strip_trailing_zeros: true,
+            ..Default::default()
+        };
+        assert_eq!(formatter.format_compound(&quantity, units), expected);
+    }
+
+    #[test]
+    fn test_format_compound_other_units() {
+        let formatter = QuantityFormatter::new(Precision::DecimalPlaces(0), UnitStyle::Short);
+        let time = Quantity::new(83.0, Time::Minutes);
+        assert_eq!(
+            formatter.format_compound(&time, &[Time::Hours, Time::Minutes]),
+            "1 h 23 min"
+        );
+
+        let weight = Quantity::new(3.25, Weight::Pounds);
+        assert_eq!(
+            formatter.format_compound(&weight, &[Weight::Pounds, Weight::Ounces]),
+            "3 lb 4 oz"
+        );
+
+        let formatter = QuantityFormatter::new(Precision::DecimalPlaces(0), UnitStyle::Long);
+        assert_eq!(
+            formatter.format_compound(&time, &[Time::Hours, Time::Minutes]),
+            "1 hour 23 minutes"
+        );
+    }
+
+    #[test]
+    fn test_display() {
+        let quantity = Quantity::new(12.345, Distance::Kilometers);
+        assert_eq!(format!("{}", quantity), "12.345 km");
+        assert_eq!(format!("{:.1}", quantity), "12.3 km");
+        assert_eq!(format!("{}", Distance::Kilometers), "km");
+        assert_eq!(format!("{:.0}", Quantity::new(90.0, Angle::Degrees)), "90°");
+    }
+
+    #[test]
+    fn names_are_defined() {
+        fn check<U: UnitNames + UnitAliases + std::fmt::Debug>() {
+            for unit in U::all() {
+                assert!(!unit.symbol().is_empty(), "{:?} has no symbol", unit);
+                assert!(!unit.name().is_empty(), "{:?} has no name", unit);
+                assert!(!unit.plural_name().is_empty(), "{:?} has no plural", unit);
+                // Formatted quantities can be parsed again
+                assert_eq!(
+                    parse_unit::<U>(unit.symbol()),
+                    Some(*unit),
+                    "symbol '{}' can't be parsed",
+                    unit.symbol()
+                );
+                assert_eq!(
+                    parse_unit::<U>(unit.plural_name()),
+                    Some(*unit),
+                    "name '{}' can't be parsed",
+                    unit.plural_name()
+                );
+            }
+        }
+
+        check::<Angle>();
+        check::<Area>();
+        check::<Density>();
+        check::<Distance>();
+        check::<Pressure>();
+        check::<Speed>();
+        check::<Temperature>();
+        check::<TemperatureDifference>();
+        check::<Time>();
+        check::<Volume>();
+        check::<Weight>();
+    }
+}
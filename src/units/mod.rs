@@ -1,9 +1,18 @@
 pub mod angle;
+pub mod area;
 pub mod bearing;
 pub mod coordinate;
+pub mod coordinate_format;
+pub mod density;
+pub mod dimension;
 pub mod distance;
+pub mod dms;
+pub mod format;
+pub mod parse;
 pub mod pressure;
 pub mod quantity;
+pub mod speed;
+pub mod system;
 pub mod temperature;
 pub mod time;
 pub mod volume;
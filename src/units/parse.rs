@@ -0,0 +1,436 @@
+use std::str::FromStr;
+
+use crate::math::Float;
+
+use super::quantity::{Convertable, Quantity, Unit};
+
+/// A unit which can be recognized in text by its symbols and names.
+pub trait UnitAliases: Unit + 'static {
+    /// Returns every unit of this kind
+    fn all() -> &'static [Self];
+    /// Returns the lowercase symbols and names of the unit (whitespace is ignored when matching, unless
+    /// it separates units)
+    fn aliases(&self) -> &'static [&'static str];
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum ParseQuantityError {
+    /// The text was empty
+    Empty,
+    /// A number was expected but the text could not be read as one
+    InvalidNumber(String),
+    /// A number was not followed by a unit
+    MissingUnit,
+    /// The unit was not recognized
+    UnknownUnit(String),
+    /// The units can't be combined into a single quantity (ex. temperatures)
+    UnsupportedCompound,
+    /// The degrees, minutes or seconds of an angle were out of order or out of range
+    InvalidAngle(String),
+}
+
+impl std::fmt::Display for ParseQuantityError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParseQuantityError::Empty => write!(f, "no quantity was provided"),
+            ParseQuantityError::InvalidNumber(text) => write!(f, "invalid number: '{}'", text),
+            ParseQuantityError::MissingUnit => write!(f, "a unit is required after each number"),
+            ParseQuantityError::UnknownUnit(text) => write!(f, "unknown unit: '{}'", text),
+            ParseQuantityError::UnsupportedCompound => {
+                write!(f, "these units can't be combined into a single quantity")
+            }
+            ParseQuantityError::InvalidAngle(text) => write!(f, "invalid angle: '{}'", text),
+        }
+    }
+}
+
+impl std::error::Error for ParseQuantityError {}
+
+/// Finds the unit matching a symbol or name, ignoring case and whitespace. Text made up of separate
+/// units (ex. "m m") is not a unit.
+pub fn parse_unit<U: UnitAliases>(text: &str) -> Option<U> {
+    let normalized = normalize_unit(text);
+    if normalized.is_empty() {
+        return None;
+    }
+
+    let mut words = text.split_whitespace();
+    let is_multiple_units = words.clone().count() > 1
+        && words.all(|word| parse_unit::<U>(word).is_some())
+        && find_alias::<U>(&text.split_whitespace().collect::<Vec<_>>().join(" ")).is_none();
+    if is_multiple_units {
+        return None;
+    }
+
+    U::all().iter().copied().find(|unit| {
+        unit.aliases()
+            .iter()
+            .any(|alias| normalize_unit(alias) == normalized)
+    })
+}
+
+/// Parses a quantity such as "12.5 km", "-4 °F", "5'11\"" or "3 lb 4 oz". Compound quantities are
+/// returned in the units of the first component.
+pub fn parse_quantity<U: UnitAliases>(text: &str) -> Result<Quantity<U>, ParseQuantityError> {
+    let text = text.trim().replace('−', "-");
+    if text.is_empty() {
+        return Err(ParseQuantityError::Empty);
+    }
+
+    let (sign, mut remaining) = match text.strip_prefix('-') {
+        Some(rest) => (-1.0, rest),
+        None => (1.0, text.strip_prefix('+').unwrap_or(&text)),
+    };
+
+    let mut components: Vec<Quantity<U>> = Vec::new();
+    loop {
+        remaining = remaining.trim_start();
+        if remaining.is_empty() {
+            break;
+        }
+
+        let (amount, rest) = read_number(remaining)?;
+        let (unit_text, rest) = read_unit(rest);
+        if unit_text.is_empty() {
+            return Err(ParseQuantityError::MissingUnit);
+        }
+        let units = parse_unit::<U>(unit_text)
+            .ok_or_else(|| ParseQuantityError::UnknownUnit(unit_text.to_string()))?;
+        components.push(Quantity::new(amount, units));
+        remaining = rest;
+    }
+
+    let first = match components.first() {
+        Some(first) => *first,
+        None => return Err(ParseQuantityError::InvalidNumber(text.to_string())),
+    };
+
+    if components.len() > 1
+        && components
+            .iter()
+            .any(|component| component.units.offset_from_base() != 0.0)
+    {
+        return Err(ParseQuantityError::UnsupportedCompound);
+    }
+
+    let total = components
+        .iter()
+        .map(|component| component.convert(first.units).amount)
+        .sum::<Float>();
+
+    Ok(Quantity::new(sign * total, first.units))
+}
+
+impl<U: UnitAliases> FromStr for Quantity<U> {
+    type Err = ParseQuantityError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_quantity(s)
+    }
+}
+
+/// Finds the unit with an alias matching the text exactly, ignoring case
+fn find_alias<U: UnitAliases>(text: &str) -> Option<U> {
+    let text = text.to_lowercase();
+    U::all()
+        .iter()
+        .copied()
+        .find(|unit| unit.aliases().contains(&text.as_str()))
+}
+
+fn normalize_unit(text: &str) -> String {
+    text.chars()
+        .filter(|c| !c.is_whitespace())
+        .flat_map(|c| c.to_lowercase())
+        .collect()
+}
+
+pub(super) fn read_number(text: &str) -> Result<(Float, &str), ParseQuantityError> {
+    let digits_end = |start: usize| {
+        text[start..]
+            .char_indices()
+            .find(|(_, c)| !c.is_ascii_digit() && *c != '.')
+            .map_or(text.len(), |(i, _)| start + i)
+    };
+    let mut end = digits_end(0);
+    let number = &text[..end];
+    if !number.chars().any(|c| c.is_ascii_digit()) {
+        return Err(ParseQuantityError::InvalidNumber(text.to_string()));
+    }
+
+    // Exponents (ex. "1e3")
+    let rest = &text[end..];
+    if let Some(exponent) = rest.strip_prefix(['e', 'E']) {
+        let sign = if exponent.starts_with(['-', '+']) {
+            1
+        } else {
+            0
+        };
+        if exponent[sign..].starts_with(|c: char| c.is_ascii_digit()) {
+            end = digits_end(end + 1 + sign);
+        }
+    }
+
+    // Digit group or decimal commas (ex. "1,000" or "12,5") are ambiguous
+    let rest = &text[end..];
+    if rest.starts_with(',') && rest[1..].starts_with(|c: char| c.is_ascii_digit()) {
+        return Err(ParseQuantityError::InvalidNumber(
+            text[..digits_end(end + 1)].to_string(),
+        ));
+    }
+
+    let number = &text[..end];
+    number
+        .parse::<Float>()
+        .map(|amount| (amount, &text[end..]))
+        .map_err(|_| ParseQuantityError::InvalidNumber(number.to_string()))
+}
+
+pub(super) fn read_unit(text: &str) -> (&str, &str) {
+    let mut chars = text.char_indices().peekable();
+    let mut end = text.len();
+    while let Some((i, c)) = chars.next() {
+        let starts_number = c.is_ascii_digit()
+            || (c == '.' && chars.peek().is_some_and(|(_, next)| next.is_ascii_digit()));
+        if starts_number {
+            end = i;
+            break;
+        }
+    }
+
+    let unit = text[..end].trim().trim_end_matches(',').trim_end();
+    // Allow abbreviations written with a trailing period (ex. "ft.")
+    let unit = match unit.strip_suffix('.') {
+        Some(stripped) if !stripped.is_empty() => stripped,
+        _ => unit,
+    };
+    (unit, &text[end..])
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::{
+        assert_approx_eq,
+        units::{
+            angle::Angle,
+            area::Area,
+            density::Density,
+            distance::Distance,
+            pressure::Pressure,
+            speed::Speed,
+            temperature::{Temperature, TemperatureDifference},
+            time::Time,
+            volume::Volume,
+            weight::Weight,
+        },
+    };
+
+    #[rstest]
+    #[case("12.5 km", 12.5, Distance::Kilometers)]
+    #[case("12.5km", 12.5, Distance::Kilometers)]
+    #[case("  3 Meters ", 3.0, Distance::Meters)]
+    #[case("-2 ft", -2.0, Distance::Feet)]
+    #[case("+2 ft.", 2.0, Distance::Feet)]
+    #[case(".5 mi", 0.5, Distance::Miles)]
+    #[case("5'11\"", 5.916667, Distance::Feet)]
+    #[case("5′ 11″", 5.916667, Distance::Feet)]
+    #[case("5 ft 11 in", 5.916667, Distance::Feet)]
+    #[case("-5 ft 6 in", -5.5, Distance::Feet)]
+    #[case("1 km 500 m", 1.5, Distance::Kilometers)]
+    #[case("2 nautical miles", 2.0, Distance::NauticalMiles)]
+    #[case("1e3 m", 1000.0, Distance::Meters)]
+    #[case("2.5E-1 km", 0.25, Distance::Kilometers)]
+    #[case("5 ft, 11 in", 5.916667, Distance::Feet)]
+    fn can_parse_distance(
+        #[case] text: &str,
+        #[case] expected_amount: Float,
+        #[case] expected_units: Distance,
+    ) {
+        let actual: Quantity<Distance> = text.parse().unwrap();
+        assert_approx_eq!(actual.amount, expected_amount, 0.0001);
+        assert_eq!(actual.units, expected_units);
+    }
+
+    #[rstest]
+    #[case("29.92 inHg", 29.92, Pressure::InchesHg)]
+    #[case("29.92 in Hg", 29.92, Pressure::InchesHg)]
+    #[case("1013.25 hPa", 1013.25, Pressure::Hectopascals)]
+    #[case("1013 mb", 1013.0, Pressure::Millibars)]
+    #[case("14.7 psi", 14.7, Pressure::PoundsPerSquareInch)]
+    #[case("760 mm Hg", 760.0, Pressure::MillimetersHg)]
+    #[case("1 atm", 1.0, Pressure::Atmospheres)]
+    fn can_parse_pressure(
+        #[case] text: &str,
+        #[case] expected_amount: Float,
+        #[case] expected_units: Pressure,
+    ) {
+        let actual: Quantity<Pressure> = text.parse().unwrap();
+        assert_approx_eq!(actual.amount, expected_amount, 0.0001);
+        assert_eq!(actual.units, expected_units);
+    }
+
+    #[rstest]
+    #[case("-4 °F", -4.0, Temperature::Fahrenheit)]
+    #[case("-4°F", -4.0, Temperature::Fahrenheit)]
+    #[case("− 4 ° F", -4.0, Temperature::Fahrenheit)]
+    #[case("21 C", 21.0, Temperature::Celsius)]
+    #[case("21 degrees celsius", 21.0, Temperature::Celsius)]
+    fn can_parse_temperature(
+        #[case] text: &str,
+        #[case] expected_amount: Float,
+        #[case] expected_units: Temperature,
+    ) {
+        let actual: Quantity<Temperature> = text.parse().unwrap();
+        assert_approx_eq!(actual.amount, expected_amount, 0.0001);
+        assert_eq!(actual.units, expected_units);
+    }
+
+    #[rstest]
+    #[case("3 lb 4 oz", 3.25, Weight::Pounds)]
+    #[case("3lbs", 3.0, Weight::Pounds)]
+    #[case("250 g", 250.0, Weight::Grams)]
+    #[case("1 kg 250 g", 1.25, Weight::Kilograms)]
+    fn can_parse_weight(
+        #[case] text: &str,
+        #[case] expected_amount: Float,
+        #[case] expected_units: Weight,
+    ) {
+        let actual: Quantity<Weight> = text.parse().unwrap();
+        assert_approx_eq!(actual.amount, expected_amount, 0.0001);
+        assert_eq!(actual.units, expected_units);
+    }
+
+    #[rstest]
+    #[case("2 L", 2.0, Volume::Liters)]
+    #[case("250 mL", 250.0, Volume::Milliliter)]
+    #[case("1 cup", 1.0, Volume::USCups)]
+    #[case("8 fl oz", 8.0, Volume::USOunces)]
+    #[case("2 tbsp", 2.0, Volume::USTablespoons)]
+    #[case("1 imp gal", 1.0, Volume::ImperialGallons)]
+    #[case("1 gal 2 qt", 1.5, Volume::USGallons)]
+    fn can_parse_volume(
+        #[case] text: &str,
+        #[case] expected_amount: Float,
+        #[case] expected_units: Volume,
+    ) {
+        let actual: Quantity<Volume> = text.parse().unwrap();
+        assert_approx_eq!(actual.amount, expected_amount, 0.0001);
+        assert_eq!(actual.units, expected_units);
+    }
+
+    #[rstest]
+    #[case("1 h 30 min", 1.5, Time::Hours)]
+    #[case("1h30m", 1.5, Time::Hours)]
+    #[case("45 s", 45.0, Time::Seconds)]
+    #[case("2 days", 2.0, Time::Days)]
+    #[case("500 ms", 500.0, Time::Milliseconds)]
+    fn can_parse_time(
+        #[case] text: &str,
+        #[case] expected_amount: Float,
+        #[case] expected_units: Time,
+    ) {
+        let actual: Quantity<Time> = text.parse().unwrap();
+        assert_approx_eq!(actual.amount, expected_amount, 0.0001);
+        assert_eq!(actual.units, expected_units);
+    }
+
+    #[rstest]
+    #[case("45°", 45.0, Angle::Degrees)]
+    #[case("-12.5 deg", -12.5, Angle::Degrees)]
+    #[case("1.5 rad", 1.5, Angle::Radians)]
+    #[case("100 gon", 100.0, Angle::Gradians)]
+    #[case("1600 mils", 1600.0, Angle::NatoMils)]
+    #[case("1500 mil (WP)", 1500.0, Angle::WarsawPactMils)]
+    #[case("45′", 45.0, Angle::ArcMinutes)]
+    #[case("12° 30′", 12.5, Angle::Degrees)]
+    fn can_parse_angle(
+        #[case] text: &str,
+        #[case] expected_amount: Float,
+        #[case] expected_units: Angle,
+    ) {
+        let actual: Quantity<Angle> = text.parse().unwrap();
+        assert_approx_eq!(actual.amount, expected_amount, 0.0001);
+        assert_eq!(actual.units, expected_units);
+    }
+
+    #[test]
+    fn can_parse_derived_units() {
+        let speed: Quantity<Speed> = "60 mph".parse().unwrap();
+        assert_eq!(speed, Quantity::new(60.0, Speed::MilesPerHour));
+
+        let area: Quantity<Area> = "2.5 ha".parse().unwrap();
+        assert_eq!(area, Quantity::new(2.5, Area::Hectares));
+
+        let density: Quantity<Density> = "1 g/cm³".parse().unwrap();
+        assert_eq!(
+            density,
+            Quantity::new(1.0, Density::GramsPerCubicCentimeter)
+        );
+    }
+
+    #[rstest]
+    #[case("", ParseQuantityError::Empty)]
+    #[case("   ", ParseQuantityError::Empty)]
+    #[case("km", ParseQuantityError::InvalidNumber("km".to_string()))]
+    #[case("-", ParseQuantityError::InvalidNumber("-".to_string()))]
+    #[case("1.2.3 km", ParseQuantityError::InvalidNumber("1.2.3".to_string()))]
+    #[case("12", ParseQuantityError::MissingUnit)]
+    #[case("5 ft 11", ParseQuantityError::MissingUnit)]
+    #[case("12 furlongs", ParseQuantityError::UnknownUnit("furlongs".to_string()))]
+    #[case("1,000 m", ParseQuantityError::InvalidNumber("1,000".to_string()))]
+    #[case("12,5 km", ParseQuantityError::InvalidNumber("12,5".to_string()))]
+    #[case("5 m m", ParseQuantityError::UnknownUnit("m m".to_string()))]
+    fn can_report_distance_errors(#[case] text: &str, #[case] expected: ParseQuantityError) {
+        let actual = text.parse::<Quantity<Distance>>();
+        assert_eq!(actual, Err(expected));
+    }
+
+    #[test]
+    fn rejects_compound_temperatures() {
+        let actual = "10 °C 5 °F".parse::<Quantity<Temperature>>();
+        assert_eq!(actual, Err(ParseQuantityError::UnsupportedCompound));
+    }
+
+    #[rstest]
+    #[case("KM", Some(Distance::Kilometers))]
+    #[case("nautical  mile", Some(Distance::NauticalMiles))]
+    #[case("", None)]
+    #[case("parsec", None)]
+    #[case("m m", None)]
+    #[case("mm", Some(Distance::Millimeters))]
+    fn test_parse_unit(#[case] text: &str, #[case] expected: Option<Distance>) {
+        assert_eq!(parse_unit::<Distance>(text), expected);
+    }
+
+    #[test]
+    fn aliases_are_unique() {
+        fn check<U: UnitAliases + std::fmt::Debug>() {
+            for unit in U::all() {
+                assert!(!unit.aliases().is_empty(), "{:?} has no aliases", unit);
+                for alias in unit.aliases() {
+                    assert_eq!(
+                        parse_unit::<U>(alias),
+                        Some(*unit),
+                        "alias '{}' is ambiguous",
+                        alias
+                    );
+                }
+            }
+        }
+
+        check::<Angle>();
+        check::<Area>();
+        check::<Density>();
+        check::<Distance>();
+        check::<Pressure>();
+        check::<Speed>();
+        check::<Temperature>();
+        check::<TemperatureDifference>();
+        check::<Time>();
+        check::<Volume>();
+        check::<Weight>();
+    }
+}
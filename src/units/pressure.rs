@@ -1,6 +1,13 @@
-use super::quantity::Unit;
+use crate::math::Float;
+
+use super::{
+    format::UnitNames,
+    parse::UnitAliases,
+    quantity::{AdditiveUnit, Unit},
+};
 
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Pressure {
     Hectopascals = 1,
     Millibars = 2,
@@ -13,7 +20,7 @@ pub enum Pressure {
 }
 
 impl Unit for Pressure {
-    fn multiplier_to_base(&self) -> f32 {
+    fn multiplier_to_base(&self) -> Float {
         match self {
             Pressure::Pascals => 1.0,
             Pressure::Hectopascals => 100.0,
@@ -27,10 +34,93 @@ impl Unit for Pressure {
     }
 }
 
+impl AdditiveUnit for Pressure {}
+
+impl UnitAliases for Pressure {
+    fn all() -> &'static [Self] {
+        &[
+            Pressure::Hectopascals,
+            Pressure::Millibars,
+            Pressure::InchesHg,
+            Pressure::PoundsPerSquareInch,
+            Pressure::MillimetersHg,
+            Pressure::Pascals,
+            Pressure::Bars,
+            Pressure::Atmospheres,
+        ]
+    }
+
+    fn aliases(&self) -> &'static [&'static str] {
+        match self {
+            Pressure::Hectopascals => &["hpa", "hectopascal", "hectopascals"],
+            Pressure::Millibars => &["mbar", "mb", "millibar", "millibars"],
+            Pressure::InchesHg => &["inhg", "\"hg", "inches hg", "inches of mercury"],
+            Pressure::PoundsPerSquareInch => &["psi", "lb/in²", "pounds per square inch"],
+            Pressure::MillimetersHg => &[
+                "mmhg",
+                "torr",
+                "millimeters of mercury",
+                "millimetres of mercury",
+            ],
+            Pressure::Pascals => &["pa", "pascal", "pascals"],
+            Pressure::Bars => &["bar", "bars"],
+            Pressure::Atmospheres => &["atm", "atmosphere", "atmospheres"],
+        }
+    }
+}
+
+impl UnitNames for Pressure {
+    fn symbol(&self) -> &'static str {
+        match self {
+            Pressure::Hectopascals => "hPa",
+            Pressure::Millibars => "mbar",
+            Pressure::InchesHg => "inHg",
+            Pressure::PoundsPerSquareInch => "psi",
+            Pressure::MillimetersHg => "mmHg",
+            Pressure::Pascals => "Pa",
+            Pressure::Bars => "bar",
+            Pressure::Atmospheres => "atm",
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        match self {
+            Pressure::Hectopascals => "hectopascal",
+            Pressure::Millibars => "millibar",
+            Pressure::InchesHg => "inch of mercury",
+            Pressure::PoundsPerSquareInch => "pound per square inch",
+            Pressure::MillimetersHg => "millimeter of mercury",
+            Pressure::Pascals => "pascal",
+            Pressure::Bars => "bar",
+            Pressure::Atmospheres => "atmosphere",
+        }
+    }
+
+    fn plural_name(&self) -> &'static str {
+        match self {
+            Pressure::Hectopascals => "hectopascals",
+            Pressure::Millibars => "millibars",
+            Pressure::InchesHg => "inches of mercury",
+            Pressure::PoundsPerSquareInch => "pounds per square inch",
+            Pressure::MillimetersHg => "millimeters of mercury",
+            Pressure::Pascals => "pascals",
+            Pressure::Bars => "bars",
+            Pressure::Atmospheres => "atmospheres",
+        }
+    }
+}
+
+impl std::fmt::Display for Pressure {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.symbol())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use rstest::rstest;
 
+    use crate::math::Float;
     use crate::units::{
         pressure::Pressure,
         quantity::{Convertable, Quantity},
@@ -58,10 +148,10 @@ mod tests {
     #[case(1.0, Pressure::MillimetersHg, Pressure::InchesHg, 0.0393701)]
     #[case(1.0, Pressure::Atmospheres, Pressure::Bars, 1.01325)]
     fn can_convert_between_pressure_units(
-        #[case] amount: f32,
+        #[case] amount: Float,
         #[case] units: Pressure,
         #[case] to_units: Pressure,
-        #[case] expected_amount: f32,
+        #[case] expected_amount: Float,
     ) {
         let actual = Quantity { amount, units }.convert(to_units);
         assert!(
@@ -1,20 +1,27 @@
+use crate::math::Float;
+
 pub trait Unit: Copy + Clone + PartialEq + Eq {
     /// Returns the multiplier to convert the unit to the base unit.
-    fn multiplier_to_base(&self) -> f32;
+    fn multiplier_to_base(&self) -> Float;
     /// Returns the offset to convert the unit to the base unit (done before multiplication).
-    fn offset_from_base(&self) -> f32 {
+    fn offset_from_base(&self) -> Float {
         0.0
     }
 }
 
+/// A unit whose quantities can be added to and subtracted from each other. Units with an offset from
+/// the base unit, such as absolute temperatures, should not implement this.
+pub trait AdditiveUnit: Unit {}
+
 #[derive(Debug, PartialEq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Quantity<U: Unit> {
-    pub amount: f32,
+    pub amount: Float,
     pub units: U,
 }
 
 impl<U: Unit> Quantity<U> {
-    pub fn new(amount: f32, units: U) -> Self {
+    pub fn new(amount: Float, units: U) -> Self {
         Self { amount, units }
     }
 }
@@ -37,10 +44,10 @@ impl<U: Unit> Convertable<U> for Quantity<U> {
 }
 
 // Implement multiplication operator for Quantity
-impl<U: Unit> std::ops::Mul<f32> for Quantity<U> {
+impl<U: Unit> std::ops::Mul<Float> for Quantity<U> {
     type Output = Self;
 
-    fn mul(self, rhs: f32) -> Self {
+    fn mul(self, rhs: Float) -> Self {
         Quantity {
             amount: self.amount * rhs,
             units: self.units,
@@ -48,7 +55,7 @@ impl<U: Unit> std::ops::Mul<f32> for Quantity<U> {
     }
 }
 
-impl<U: Unit> std::ops::Mul<Quantity<U>> for f32 {
+impl<U: Unit> std::ops::Mul<Quantity<U>> for Float {
     type Output = Quantity<U>;
 
     fn mul(self, rhs: Quantity<U>) -> Quantity<U> {
@@ -59,10 +66,10 @@ impl<U: Unit> std::ops::Mul<Quantity<U>> for f32 {
     }
 }
 
-impl<U: Unit> std::ops::Div<f32> for Quantity<U> {
+impl<U: Unit> std::ops::Div<Float> for Quantity<U> {
     type Output = Self;
 
-    fn div(self, rhs: f32) -> Self {
+    fn div(self, rhs: Float) -> Self {
         Quantity {
             amount: self.amount / rhs,
             units: self.units,
@@ -70,7 +77,18 @@ impl<U: Unit> std::ops::Div<f32> for Quantity<U> {
     }
 }
 
-impl<U: Unit> std::ops::Add for Quantity<U> {
+/// Dividing two quantities of the same kind gives their unitless ratio. Units with an offset from zero
+/// (like temperatures) don't have a meaningful ratio.
+impl<U: AdditiveUnit> std::ops::Div for Quantity<U> {
+    type Output = Float;
+
+    fn div(self, rhs: Self) -> Float {
+        let converted = rhs.convert(self.units);
+        self.amount / converted.amount
+    }
+}
+
+impl<U: AdditiveUnit> std::ops::Add for Quantity<U> {
     type Output = Self;
 
     fn add(self, rhs: Self) -> Self {
@@ -82,7 +100,7 @@ impl<U: Unit> std::ops::Add for Quantity<U> {
     }
 }
 
-impl<U: Unit> std::ops::Sub for Quantity<U> {
+impl<U: AdditiveUnit> std::ops::Sub for Quantity<U> {
     type Output = Self;
 
     fn sub(self, rhs: Self) -> Self {
@@ -101,14 +119,20 @@ mod tests {
     #[derive(Debug, PartialEq, Eq, Clone, Copy)]
     enum TestUnit {
         Base,
+        Double,
     }
 
     impl Unit for TestUnit {
-        fn multiplier_to_base(&self) -> f32 {
-            return 1.0;
+        fn multiplier_to_base(&self) -> Float {
+            match self {
+                TestUnit::Base => 1.0,
+                TestUnit::Double => 2.0,
+            }
         }
     }
 
+    impl AdditiveUnit for TestUnit {}
+
     #[test]
     fn test_quantity_mul_f32() {
         let q = Quantity {
@@ -229,4 +253,73 @@ mod tests {
             }
         );
     }
+
+    #[test]
+    fn test_quantity_div_quantity() {
+        let q1 = Quantity {
+            amount: 30.0,
+            units: TestUnit::Base,
+        };
+        let q2 = Quantity {
+            amount: 5.0,
+            units: TestUnit::Double,
+        };
+        assert_eq!(q1 / q2, 3.0);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_quantity_serde_round_trip() {
+        use crate::test_helper::assert_serde_round_trip;
+        use crate::units::{distance::Distance, temperature::Temperature};
+
+        assert_serde_round_trip(
+            &Quantity::new(1.5, Distance::Kilometers),
+            r#"{"amount":1.5,"units":"Kilometers"}"#,
+        );
+        assert_serde_round_trip(
+            &Quantity::new(-4.0, Temperature::Fahrenheit),
+            r#"{"amount":-4.0,"units":"Fahrenheit"}"#,
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn units_serialize_by_name() {
+        use crate::test_helper::assert_serde_round_trip;
+        use crate::units::{
+            angle::Angle,
+            area::Area,
+            density::Density,
+            distance::Distance,
+            parse::UnitAliases,
+            pressure::Pressure,
+            speed::Speed,
+            temperature::{Temperature, TemperatureDifference},
+            time::Time,
+            volume::Volume,
+            weight::Weight,
+        };
+
+        fn check<U>()
+        where
+            U: UnitAliases + serde::Serialize + serde::de::DeserializeOwned + std::fmt::Debug,
+        {
+            for unit in U::all() {
+                assert_serde_round_trip(unit, &format!("\"{:?}\"", unit));
+            }
+        }
+
+        check::<Angle>();
+        check::<Area>();
+        check::<Density>();
+        check::<Distance>();
+        check::<Pressure>();
+        check::<Speed>();
+        check::<Temperature>();
+        check::<TemperatureDifference>();
+        check::<Time>();
+        check::<Volume>();
+        check::<Weight>();
+    }
 }
@@ -0,0 +1,236 @@
+use crate::math::Float;
+
+use super::{
+    distance::Distance,
+    format::UnitNames,
+    parse::UnitAliases,
+    quantity::{AdditiveUnit, Convertable, Quantity, Unit},
+    time::Time,
+};
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Speed {
+    MetersPerSecond = 1,
+    KilometersPerHour = 2,
+    MilesPerHour = 3,
+    Knots = 4,
+    FeetPerSecond = 5,
+}
+
+impl Unit for Speed {
+    fn multiplier_to_base(&self) -> Float {
+        match self {
+            Speed::MetersPerSecond => 1.0,
+            Speed::KilometersPerHour => 1.0 / 3.6,
+            Speed::MilesPerHour => 0.44704,
+            Speed::Knots => 1852.0 / 3600.0,
+            Speed::FeetPerSecond => 0.3048,
+        }
+    }
+}
+
+impl AdditiveUnit for Speed {}
+
+/// Distance / Time = Speed (in meters per second)
+impl std::ops::Div<Quantity<Time>> for Quantity<Distance> {
+    type Output = Quantity<Speed>;
+
+    fn div(self, rhs: Quantity<Time>) -> Quantity<Speed> {
+        let meters = self.convert(Distance::Meters).amount;
+        let seconds = rhs.convert(Time::Seconds).amount;
+        Quantity::new(meters / seconds, Speed::MetersPerSecond)
+    }
+}
+
+/// Distance / Speed = Time (in seconds)
+impl std::ops::Div<Quantity<Speed>> for Quantity<Distance> {
+    type Output = Quantity<Time>;
+
+    fn div(self, rhs: Quantity<Speed>) -> Quantity<Time> {
+        let meters = self.convert(Distance::Meters).amount;
+        let meters_per_second = rhs.convert(Speed::MetersPerSecond).amount;
+        Quantity::new(meters / meters_per_second, Time::Seconds)
+    }
+}
+
+/// Speed * Time = Distance (in meters)
+impl std::ops::Mul<Quantity<Time>> for Quantity<Speed> {
+    type Output = Quantity<Distance>;
+
+    fn mul(self, rhs: Quantity<Time>) -> Quantity<Distance> {
+        let meters_per_second = self.convert(Speed::MetersPerSecond).amount;
+        let seconds = rhs.convert(Time::Seconds).amount;
+        Quantity::new(meters_per_second * seconds, Distance::Meters)
+    }
+}
+
+/// Time * Speed = Distance (in meters)
+impl std::ops::Mul<Quantity<Speed>> for Quantity<Time> {
+    type Output = Quantity<Distance>;
+
+    fn mul(self, rhs: Quantity<Speed>) -> Quantity<Distance> {
+        rhs * self
+    }
+}
+
+impl UnitAliases for Speed {
+    fn all() -> &'static [Self] {
+        &[
+            Speed::MetersPerSecond,
+            Speed::KilometersPerHour,
+            Speed::MilesPerHour,
+            Speed::Knots,
+            Speed::FeetPerSecond,
+        ]
+    }
+
+    fn aliases(&self) -> &'static [&'static str] {
+        match self {
+            Speed::MetersPerSecond => &["m/s", "mps", "meters per second", "metres per second"],
+            Speed::KilometersPerHour => &[
+                "km/h",
+                "km/hr",
+                "kph",
+                "kmh",
+                "kilometers per hour",
+                "kilometres per hour",
+            ],
+            Speed::MilesPerHour => &["mph", "mi/h", "miles per hour"],
+            Speed::Knots => &["kn", "kt", "kts", "knot", "knots"],
+            Speed::FeetPerSecond => &["ft/s", "fps", "feet per second"],
+        }
+    }
+}
+
+impl UnitNames for Speed {
+    fn symbol(&self) -> &'static str {
+        match self {
+            Speed::MetersPerSecond => "m/s",
+            Speed::KilometersPerHour => "km/h",
+            Speed::MilesPerHour => "mph",
+            Speed::Knots => "kn",
+            Speed::FeetPerSecond => "ft/s",
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        match self {
+            Speed::MetersPerSecond => "meter per second",
+            Speed::KilometersPerHour => "kilometer per hour",
+            Speed::MilesPerHour => "mile per hour",
+            Speed::Knots => "knot",
+            Speed::FeetPerSecond => "foot per second",
+        }
+    }
+
+    fn plural_name(&self) -> &'static str {
+        match self {
+            Speed::MetersPerSecond => "meters per second",
+            Speed::KilometersPerHour => "kilometers per hour",
+            Speed::MilesPerHour => "miles per hour",
+            Speed::Knots => "knots",
+            Speed::FeetPerSecond => "feet per second",
+        }
+    }
+}
+
+impl std::fmt::Display for Speed {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.symbol())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use crate::{
+        assert_approx_eq,
+        math::Float,
+        units::{
+            distance::Distance,
+            quantity::{Convertable, Quantity},
+            speed::Speed,
+            time::Time,
+        },
+    };
+
+    #[rstest]
+    #[case(1.0, Speed::MetersPerSecond, Speed::MetersPerSecond, 1.0)]
+    #[case(1.0, Speed::MetersPerSecond, Speed::KilometersPerHour, 3.6)]
+    #[case(1.0, Speed::MetersPerSecond, Speed::MilesPerHour, 2.236936)]
+    #[case(1.0, Speed::MetersPerSecond, Speed::Knots, 1.943844)]
+    #[case(1.0, Speed::MetersPerSecond, Speed::FeetPerSecond, 3.28084)]
+    #[case(100.0, Speed::KilometersPerHour, Speed::MilesPerHour, 62.1371)]
+    #[case(10.0, Speed::Knots, Speed::KilometersPerHour, 18.52)]
+    #[case(60.0, Speed::MilesPerHour, Speed::FeetPerSecond, 88.0)]
+    fn can_convert_between_speed_units(
+        #[case] amount: Float,
+        #[case] units: Speed,
+        #[case] to_units: Speed,
+        #[case] expected_amount: Float,
+    ) {
+        let actual = Quantity { amount, units }.convert(to_units);
+        assert!(
+            (actual.amount - expected_amount).abs() <= 0.001,
+            "quantities not equal - expected: {} {:?}, actual: {} {:?}",
+            actual.amount,
+            actual.units,
+            expected_amount,
+            to_units
+        );
+
+        assert_eq!(actual.units, to_units);
+    }
+
+    #[rstest]
+    #[case(
+        Quantity::new(100.0, Distance::Meters),
+        Quantity::new(10.0, Time::Seconds),
+        10.0
+    )]
+    #[case(
+        Quantity::new(5.0, Distance::Kilometers),
+        Quantity::new(1.0, Time::Hours),
+        1.388_889
+    )]
+    #[case(
+        Quantity::new(1.0, Distance::Miles),
+        Quantity::new(1.0, Time::Minutes),
+        26.8224
+    )]
+    fn can_divide_distance_by_time(
+        #[case] distance: Quantity<Distance>,
+        #[case] time: Quantity<Time>,
+        #[case] expected_meters_per_second: Float,
+    ) {
+        let speed = distance / time;
+        assert_approx_eq!(speed.amount, expected_meters_per_second, 0.0001);
+        assert_eq!(speed.units, Speed::MetersPerSecond);
+    }
+
+    #[test]
+    fn can_multiply_speed_by_time() {
+        let speed = Quantity::new(3.6, Speed::KilometersPerHour);
+        let time = Quantity::new(2.0, Time::Minutes);
+
+        let distance = speed * time;
+        assert_approx_eq!(distance.amount, 120.0, 0.001);
+        assert_eq!(distance.units, Distance::Meters);
+
+        let distance = time * speed;
+        assert_approx_eq!(distance.amount, 120.0, 0.001);
+        assert_eq!(distance.units, Distance::Meters);
+    }
+
+    #[test]
+    fn can_divide_distance_by_speed() {
+        let distance = Quantity::new(10.0, Distance::Kilometers);
+        let speed = Quantity::new(5.0, Speed::KilometersPerHour);
+
+        let time = distance / speed;
+        assert_approx_eq!(time.convert(Time::Hours).amount, 2.0, 0.0001);
+        assert_eq!(time.units, Time::Seconds);
+    }
+}
@@ -0,0 +1,320 @@
+use crate::math::Float;
+
+use super::{
+    area::Area,
+    distance::Distance,
+    pressure::Pressure,
+    quantity::{Convertable, Quantity, Unit},
+    speed::Speed,
+    temperature::{Temperature, TemperatureDifference},
+    time::Time,
+    volume::Volume,
+    weight::Weight,
+};
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum UnitSystem {
+    Metric,
+    Imperial,
+    USCustomary,
+    Nautical,
+}
+
+/// A unit which belongs to one or more unit systems.
+pub trait SystemUnits: Unit + 'static {
+    /// Returns the units of the system, ordered from smallest to largest
+    fn units_in(system: UnitSystem) -> &'static [Self];
+}
+
+/// The default amount a unit must reach before it is chosen over a smaller unit
+pub const DEFAULT_UNIT_THRESHOLD: Float = 1.0;
+
+/// Converts a quantity to the most readable unit of a unit system: the largest unit where the amount
+/// is at least the unit's threshold (ex. 0.4 km becomes 400 m, 5280 ft becomes 1 mi). Units without
+/// a threshold use `DEFAULT_UNIT_THRESHOLD`.
+pub fn best_unit<U: SystemUnits>(
+    quantity: &Quantity<U>,
+    system: UnitSystem,
+    thresholds: Option<&[(U, Float)]>,
+) -> Quantity<U> {
+    let units = U::units_in(system);
+    let threshold = |unit: U| {
+        thresholds
+            .and_then(|thresholds| thresholds.iter().find(|(u, _)| *u == unit))
+            .map_or(DEFAULT_UNIT_THRESHOLD, |(_, threshold)| *threshold)
+    };
+
+    for unit in units.iter().rev() {
+        let converted = quantity.convert(*unit);
+        if converted.amount.abs() >= threshold(*unit) {
+            return converted;
+        }
+    }
+
+    match units.first() {
+        Some(smallest) => quantity.convert(*smallest),
+        None => *quantity,
+    }
+}
+
+impl SystemUnits for Distance {
+    fn units_in(system: UnitSystem) -> &'static [Self] {
+        match system {
+            UnitSystem::Metric => &[
+                Distance::Millimeters,
+                Distance::Centimeters,
+                Distance::Meters,
+                Distance::Kilometers,
+            ],
+            UnitSystem::Imperial | UnitSystem::USCustomary => {
+                &[Distance::Inches, Distance::Feet, Distance::Miles]
+            }
+            UnitSystem::Nautical => &[Distance::Meters, Distance::NauticalMiles],
+        }
+    }
+}
+
+impl SystemUnits for Volume {
+    fn units_in(system: UnitSystem) -> &'static [Self] {
+        match system {
+            UnitSystem::Metric | UnitSystem::Nautical => &[Volume::Milliliter, Volume::Liters],
+            UnitSystem::USCustomary => &[
+                Volume::USTeaspoons,
+                Volume::USTablespoons,
+                Volume::USCups,
+                Volume::USGallons,
+            ],
+            UnitSystem::Imperial => &[
+                Volume::ImperialTeaspoons,
+                Volume::ImperialTablespoons,
+                Volume::ImperialPints,
+                Volume::ImperialGallons,
+            ],
+        }
+    }
+}
+
+impl SystemUnits for Weight {
+    fn units_in(system: UnitSystem) -> &'static [Self] {
+        match system {
+            UnitSystem::Metric | UnitSystem::Nautical => &[Weight::Grams, Weight::Kilograms],
+            UnitSystem::Imperial | UnitSystem::USCustomary => &[Weight::Ounces, Weight::Pounds],
+        }
+    }
+}
+
+impl SystemUnits for Area {
+    fn units_in(system: UnitSystem) -> &'static [Self] {
+        match system {
+            UnitSystem::Metric | UnitSystem::Nautical => &[
+                Area::SquareCentimeters,
+                Area::SquareMeters,
+                Area::Hectares,
+                Area::SquareKilometers,
+            ],
+            UnitSystem::Imperial | UnitSystem::USCustomary => &[
+                Area::SquareInches,
+                Area::SquareFeet,
+                Area::Acres,
+                Area::SquareMiles,
+            ],
+        }
+    }
+}
+
+impl SystemUnits for Time {
+    fn units_in(_system: UnitSystem) -> &'static [Self] {
+        &[
+            Time::Milliseconds,
+            Time::Seconds,
+            Time::Minutes,
+            Time::Hours,
+            Time::Days,
+        ]
+    }
+}
+
+impl SystemUnits for Speed {
+    fn units_in(system: UnitSystem) -> &'static [Self] {
+        match system {
+            UnitSystem::Metric => &[Speed::KilometersPerHour],
+            UnitSystem::Imperial | UnitSystem::USCustomary => &[Speed::MilesPerHour],
+            UnitSystem::Nautical => &[Speed::Knots],
+        }
+    }
+}
+
+impl SystemUnits for Pressure {
+    fn units_in(system: UnitSystem) -> &'static [Self] {
+        match system {
+            UnitSystem::Metric | UnitSystem::Nautical => &[Pressure::Hectopascals],
+            UnitSystem::Imperial | UnitSystem::USCustomary => &[Pressure::InchesHg],
+        }
+    }
+}
+
+impl SystemUnits for Temperature {
+    fn units_in(system: UnitSystem) -> &'static [Self] {
+        match system {
+            UnitSystem::USCustomary => &[Temperature::Fahrenheit],
+            UnitSystem::Metric | UnitSystem::Imperial | UnitSystem::Nautical => {
+                &[Temperature::Celsius]
+            }
+        }
+    }
+}
+
+impl SystemUnits for TemperatureDifference {
+    fn units_in(system: UnitSystem) -> &'static [Self] {
+        match system {
+            UnitSystem::USCustomary => &[TemperatureDifference::Fahrenheit],
+            UnitSystem::Metric | UnitSystem::Imperial | UnitSystem::Nautical => {
+                &[TemperatureDifference::Celsius]
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::assert_approx_eq;
+
+    #[rstest]
+    #[case(0.4, Distance::Kilometers, UnitSystem::Metric, 400.0, Distance::Meters)]
+    #[case(
+        1500.0,
+        Distance::Meters,
+        UnitSystem::Metric,
+        1.5,
+        Distance::Kilometers
+    )]
+    #[case(0.05, Distance::Meters, UnitSystem::Metric, 5.0, Distance::Centimeters)]
+    #[case(
+        0.5,
+        Distance::Millimeters,
+        UnitSystem::Metric,
+        0.5,
+        Distance::Millimeters
+    )]
+    #[case(5280.0, Distance::Feet, UnitSystem::USCustomary, 1.0, Distance::Miles)]
+    #[case(2640.0, Distance::Feet, UnitSystem::Imperial, 2640.0, Distance::Feet)]
+    #[case(6.0, Distance::Inches, UnitSystem::USCustomary, 6.0, Distance::Inches)]
+    #[case(
+        1.0,
+        Distance::Kilometers,
+        UnitSystem::USCustomary,
+        3280.84,
+        Distance::Feet
+    )]
+    #[case(
+        3704.0,
+        Distance::Meters,
+        UnitSystem::Nautical,
+        2.0,
+        Distance::NauticalMiles
+    )]
+    #[case(-400.0, Distance::Meters, UnitSystem::Metric, -400.0, Distance::Meters)]
+    #[case(-2.0, Distance::Kilometers, UnitSystem::Metric, -2.0, Distance::Kilometers)]
+    fn test_best_distance_unit(
+        #[case] amount: Float,
+        #[case] units: Distance,
+        #[case] system: UnitSystem,
+        #[case] expected_amount: Float,
+        #[case] expected_units: Distance,
+    ) {
+        let actual = best_unit(&Quantity::new(amount, units), system, None);
+        assert_approx_eq!(actual.amount, expected_amount, 0.01);
+        assert_eq!(actual.units, expected_units);
+    }
+
+    #[rstest]
+    #[case(
+        2.0,
+        Volume::USTeaspoons,
+        UnitSystem::USCustomary,
+        2.0,
+        Volume::USTeaspoons
+    )]
+    #[case(
+        6.0,
+        Volume::USTeaspoons,
+        UnitSystem::USCustomary,
+        2.0,
+        Volume::USTablespoons
+    )]
+    #[case(
+        48.0,
+        Volume::USTeaspoons,
+        UnitSystem::USCustomary,
+        1.0,
+        Volume::USCups
+    )]
+    #[case(32.0, Volume::USCups, UnitSystem::USCustomary, 2.0, Volume::USGallons)]
+    #[case(0.25, Volume::Liters, UnitSystem::Metric, 250.0, Volume::Milliliter)]
+    #[case(
+        1.0,
+        Volume::ImperialGallons,
+        UnitSystem::Imperial,
+        1.0,
+        Volume::ImperialGallons
+    )]
+    fn test_best_volume_unit(
+        #[case] amount: Float,
+        #[case] units: Volume,
+        #[case] system: UnitSystem,
+        #[case] expected_amount: Float,
+        #[case] expected_units: Volume,
+    ) {
+        let actual = best_unit(&Quantity::new(amount, units), system, None);
+        assert_approx_eq!(actual.amount, expected_amount, 0.01);
+        assert_eq!(actual.units, expected_units);
+    }
+
+    #[rstest]
+    #[case(0.5, Weight::Kilograms, UnitSystem::Metric, 500.0, Weight::Grams)]
+    #[case(20.0, Weight::Ounces, UnitSystem::USCustomary, 1.25, Weight::Pounds)]
+    #[case(1.0, Weight::Kilograms, UnitSystem::Nautical, 1.0, Weight::Kilograms)]
+    fn test_best_weight_unit(
+        #[case] amount: Float,
+        #[case] units: Weight,
+        #[case] system: UnitSystem,
+        #[case] expected_amount: Float,
+        #[case] expected_units: Weight,
+    ) {
+        let actual = best_unit(&Quantity::new(amount, units), system, None);
+        assert_approx_eq!(actual.amount, expected_amount, 0.01);
+        assert_eq!(actual.units, expected_units);
+    }
+
+    #[test]
+    fn test_best_unit_with_thresholds() {
+        let thresholds = [(Distance::Miles, 0.1)];
+        let half_mile = Quantity::new(2640.0, Distance::Feet);
+        let actual = best_unit(&half_mile, UnitSystem::USCustomary, Some(&thresholds));
+        assert_approx_eq!(actual.amount, 0.5, 0.0001);
+        assert_eq!(actual.units, Distance::Miles);
+
+        let thresholds = [(Time::Hours, 2.0)];
+        let time = Quantity::new(90.0, Time::Minutes);
+        let actual = best_unit(&time, UnitSystem::Metric, Some(&thresholds));
+        assert_approx_eq!(actual.amount, 90.0, 0.0001);
+        assert_eq!(actual.units, Time::Minutes);
+    }
+
+    #[test]
+    fn test_best_unit_with_single_unit_systems() {
+        let speed = Quantity::new(10.0, Speed::MetersPerSecond);
+        let actual = best_unit(&speed, UnitSystem::Nautical, None);
+        assert_approx_eq!(actual.amount, 19.43844, 0.001);
+        assert_eq!(actual.units, Speed::Knots);
+
+        let temperature = Quantity::new(-40.0, Temperature::Celsius);
+        let actual = best_unit(&temperature, UnitSystem::USCustomary, None);
+        assert_approx_eq!(actual.amount, -40.0, 0.001);
+        assert_eq!(actual.units, Temperature::Fahrenheit);
+    }
+}
@@ -1,35 +1,246 @@
-use super::quantity::Unit;
+use crate::math::Float;
 
+use super::{
+    format::UnitNames,
+    parse::UnitAliases,
+    quantity::{AdditiveUnit, Convertable, Quantity, Unit},
+};
+
+/// An absolute temperature, such as a reading from a thermometer. The difference between two
+/// temperatures is a `TemperatureDifference`.
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Temperature {
     Fahrenheit = 1,
     Celsius = 2,
+    Kelvin = 3,
+    Rankine = 4,
 }
 
 impl Unit for Temperature {
-    fn multiplier_to_base(&self) -> f32 {
+    fn multiplier_to_base(&self) -> Float {
         match self {
             Temperature::Fahrenheit => 5.0 / 9.0,
             Temperature::Celsius => 1.0,
+            Temperature::Kelvin => 1.0,
+            Temperature::Rankine => 5.0 / 9.0,
         }
     }
 
-    fn offset_from_base(&self) -> f32 {
+    fn offset_from_base(&self) -> Float {
         match self {
             Temperature::Fahrenheit => -32.0,
             Temperature::Celsius => 0.0,
+            Temperature::Kelvin => -273.15,
+            Temperature::Rankine => -491.67,
         }
     }
 }
 
+impl Temperature {
+    /// Returns the unit used for differences between temperatures in this unit
+    pub fn difference_units(&self) -> TemperatureDifference {
+        match self {
+            Temperature::Fahrenheit => TemperatureDifference::Fahrenheit,
+            Temperature::Celsius => TemperatureDifference::Celsius,
+            Temperature::Kelvin => TemperatureDifference::Kelvin,
+            Temperature::Rankine => TemperatureDifference::Rankine,
+        }
+    }
+}
+
+/// A change in temperature (ex. a 10 °F drop). Unlike `Temperature`, conversions between these units
+/// ignore the offset between scales, so a difference of 1 °C is a difference of 1.8 °F.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum TemperatureDifference {
+    Fahrenheit = 1,
+    Celsius = 2,
+    Kelvin = 3,
+    Rankine = 4,
+}
+
+impl Unit for TemperatureDifference {
+    fn multiplier_to_base(&self) -> Float {
+        match self {
+            TemperatureDifference::Fahrenheit => 5.0 / 9.0,
+            TemperatureDifference::Celsius => 1.0,
+            TemperatureDifference::Kelvin => 1.0,
+            TemperatureDifference::Rankine => 5.0 / 9.0,
+        }
+    }
+}
+
+impl AdditiveUnit for TemperatureDifference {}
+
+impl TemperatureDifference {
+    /// Returns the absolute temperature unit with the same scale
+    pub fn temperature_units(&self) -> Temperature {
+        match self {
+            TemperatureDifference::Fahrenheit => Temperature::Fahrenheit,
+            TemperatureDifference::Celsius => Temperature::Celsius,
+            TemperatureDifference::Kelvin => Temperature::Kelvin,
+            TemperatureDifference::Rankine => Temperature::Rankine,
+        }
+    }
+}
+
+/// Temperature - Temperature = TemperatureDifference (in the units of the left temperature)
+impl std::ops::Sub for Quantity<Temperature> {
+    type Output = Quantity<TemperatureDifference>;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        let converted = rhs.convert(self.units);
+        Quantity::new(
+            self.amount - converted.amount,
+            self.units.difference_units(),
+        )
+    }
+}
+
+/// Temperature + TemperatureDifference = Temperature
+impl std::ops::Add<Quantity<TemperatureDifference>> for Quantity<Temperature> {
+    type Output = Quantity<Temperature>;
+
+    fn add(self, rhs: Quantity<TemperatureDifference>) -> Self::Output {
+        let converted = rhs.convert(self.units.difference_units());
+        Quantity::new(self.amount + converted.amount, self.units)
+    }
+}
+
+/// TemperatureDifference + Temperature = Temperature
+impl std::ops::Add<Quantity<Temperature>> for Quantity<TemperatureDifference> {
+    type Output = Quantity<Temperature>;
+
+    fn add(self, rhs: Quantity<Temperature>) -> Self::Output {
+        rhs + self
+    }
+}
+
+/// Temperature - TemperatureDifference = Temperature
+impl std::ops::Sub<Quantity<TemperatureDifference>> for Quantity<Temperature> {
+    type Output = Quantity<Temperature>;
+
+    fn sub(self, rhs: Quantity<TemperatureDifference>) -> Self::Output {
+        self + rhs * -1.0
+    }
+}
+
+impl UnitAliases for Temperature {
+    fn all() -> &'static [Self] {
+        &[
+            Temperature::Fahrenheit,
+            Temperature::Celsius,
+            Temperature::Kelvin,
+            Temperature::Rankine,
+        ]
+    }
+
+    fn aliases(&self) -> &'static [&'static str] {
+        temperature_aliases(self.difference_units())
+    }
+}
+
+impl UnitAliases for TemperatureDifference {
+    fn all() -> &'static [Self] {
+        &[
+            TemperatureDifference::Fahrenheit,
+            TemperatureDifference::Celsius,
+            TemperatureDifference::Kelvin,
+            TemperatureDifference::Rankine,
+        ]
+    }
+
+    fn aliases(&self) -> &'static [&'static str] {
+        temperature_aliases(*self)
+    }
+}
+
+fn temperature_aliases(units: TemperatureDifference) -> &'static [&'static str] {
+    match units {
+        TemperatureDifference::Fahrenheit => {
+            &["°f", "f", "℉", "degf", "fahrenheit", "degrees fahrenheit"]
+        }
+        TemperatureDifference::Celsius => &[
+            "°c",
+            "c",
+            "℃",
+            "degc",
+            "celsius",
+            "degrees celsius",
+            "centigrade",
+        ],
+        TemperatureDifference::Kelvin => &["k", "°k", "kelvin", "kelvins"],
+        TemperatureDifference::Rankine => &["°r", "°ra", "degr", "rankine", "degrees rankine"],
+    }
+}
+
+impl UnitNames for Temperature {
+    fn symbol(&self) -> &'static str {
+        self.difference_units().symbol()
+    }
+
+    fn name(&self) -> &'static str {
+        self.difference_units().name()
+    }
+
+    fn plural_name(&self) -> &'static str {
+        self.difference_units().plural_name()
+    }
+}
+
+impl UnitNames for TemperatureDifference {
+    fn symbol(&self) -> &'static str {
+        match self {
+            TemperatureDifference::Fahrenheit => "°F",
+            TemperatureDifference::Celsius => "°C",
+            TemperatureDifference::Kelvin => "K",
+            TemperatureDifference::Rankine => "°R",
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        match self {
+            TemperatureDifference::Fahrenheit => "degree Fahrenheit",
+            TemperatureDifference::Celsius => "degree Celsius",
+            TemperatureDifference::Kelvin => "kelvin",
+            TemperatureDifference::Rankine => "degree Rankine",
+        }
+    }
+
+    fn plural_name(&self) -> &'static str {
+        match self {
+            TemperatureDifference::Fahrenheit => "degrees Fahrenheit",
+            TemperatureDifference::Celsius => "degrees Celsius",
+            TemperatureDifference::Kelvin => "kelvins",
+            TemperatureDifference::Rankine => "degrees Rankine",
+        }
+    }
+}
+
+impl std::fmt::Display for Temperature {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.symbol())
+    }
+}
+
+impl std::fmt::Display for TemperatureDifference {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.symbol())
+    }
+}
+
 #[cfg(test)]
 mod tests {
-
     use rstest::rstest;
 
-    use crate::units::{
-        quantity::{Convertable, Quantity},
-        temperature::Temperature,
+    use crate::{
+        assert_approx_eq,
+        math::Float,
+        units::{
+            quantity::{Convertable, Quantity},
+            temperature::{Temperature, TemperatureDifference},
+        },
     };
 
     #[rstest]
@@ -39,11 +250,17 @@ mod tests {
     #[case(1.0, Temperature::Fahrenheit, Temperature::Fahrenheit, 1.0)]
     #[case(-40.0, Temperature::Fahrenheit, Temperature::Celsius, -40.0)]
     #[case(-40.0, Temperature::Celsius, Temperature::Fahrenheit, -40.0)]
+    #[case(0.0, Temperature::Celsius, Temperature::Kelvin, 273.15)]
+    #[case(300.0, Temperature::Kelvin, Temperature::Celsius, 26.85)]
+    #[case(0.0, Temperature::Kelvin, Temperature::Rankine, 0.0)]
+    #[case(32.0, Temperature::Fahrenheit, Temperature::Rankine, 491.67)]
+    #[case(491.67, Temperature::Rankine, Temperature::Kelvin, 273.15)]
+    #[case(212.0, Temperature::Fahrenheit, Temperature::Kelvin, 373.15)]
     fn can_convert_between_temperature_units(
-        #[case] amount: f32,
+        #[case] amount: Float,
         #[case] units: Temperature,
         #[case] to_units: Temperature,
-        #[case] expected_amount: f32,
+        #[case] expected_amount: Float,
     ) {
         let actual = Quantity { amount, units }.convert(to_units);
         assert!(
@@ -57,4 +274,114 @@ mod tests {
 
         assert_eq!(actual.units, to_units);
     }
+
+    #[rstest]
+    #[case(
+        1.0,
+        TemperatureDifference::Celsius,
+        TemperatureDifference::Fahrenheit,
+        1.8
+    )]
+    #[case(
+        10.0,
+        TemperatureDifference::Fahrenheit,
+        TemperatureDifference::Celsius,
+        5.5556
+    )]
+    #[case(
+        10.0,
+        TemperatureDifference::Kelvin,
+        TemperatureDifference::Celsius,
+        10.0
+    )]
+    #[case(
+        9.0,
+        TemperatureDifference::Rankine,
+        TemperatureDifference::Kelvin,
+        5.0
+    )]
+    #[case(-18.0, TemperatureDifference::Fahrenheit, TemperatureDifference::Rankine, -18.0)]
+    fn can_convert_between_temperature_difference_units(
+        #[case] amount: Float,
+        #[case] units: TemperatureDifference,
+        #[case] to_units: TemperatureDifference,
+        #[case] expected_amount: Float,
+    ) {
+        let actual = Quantity { amount, units }.convert(to_units);
+        assert_approx_eq!(actual.amount, expected_amount, 0.0001);
+        assert_eq!(actual.units, to_units);
+    }
+
+    #[rstest]
+    #[case(
+        Quantity::new(20.0, Temperature::Celsius),
+        Quantity::new(10.0, Temperature::Celsius),
+        Quantity::new(10.0, TemperatureDifference::Celsius)
+    )]
+    #[case(
+        Quantity::new(10.0, Temperature::Celsius),
+        Quantity::new(50.0, Temperature::Fahrenheit),
+        Quantity::new(0.0, TemperatureDifference::Celsius)
+    )]
+    #[case(
+        Quantity::new(50.0, Temperature::Fahrenheit),
+        Quantity::new(0.0, Temperature::Celsius),
+        Quantity::new(18.0, TemperatureDifference::Fahrenheit)
+    )]
+    #[case(
+        Quantity::new(0.0, Temperature::Celsius),
+        Quantity::new(300.0, Temperature::Kelvin),
+        Quantity::new(-26.85, TemperatureDifference::Celsius)
+    )]
+    fn can_subtract_temperatures(
+        #[case] a: Quantity<Temperature>,
+        #[case] b: Quantity<Temperature>,
+        #[case] expected: Quantity<TemperatureDifference>,
+    ) {
+        let actual = a - b;
+        assert_approx_eq!(actual.amount, expected.amount, 0.001);
+        assert_eq!(actual.units, expected.units);
+    }
+
+    #[rstest]
+    #[case(
+        Quantity::new(10.0, Temperature::Celsius),
+        Quantity::new(10.0, TemperatureDifference::Fahrenheit),
+        Quantity::new(15.5556, Temperature::Celsius)
+    )]
+    #[case(
+        Quantity::new(50.0, Temperature::Fahrenheit),
+        Quantity::new(10.0, TemperatureDifference::Celsius),
+        Quantity::new(68.0, Temperature::Fahrenheit)
+    )]
+    #[case(
+        Quantity::new(273.15, Temperature::Kelvin),
+        Quantity::new(-5.0, TemperatureDifference::Celsius),
+        Quantity::new(268.15, Temperature::Kelvin)
+    )]
+    fn can_add_temperature_differences(
+        #[case] temperature: Quantity<Temperature>,
+        #[case] difference: Quantity<TemperatureDifference>,
+        #[case] expected: Quantity<Temperature>,
+    ) {
+        let actual = temperature + difference;
+        assert_approx_eq!(actual.amount, expected.amount, 0.001);
+        assert_eq!(actual.units, expected.units);
+
+        let actual = difference + temperature;
+        assert_approx_eq!(actual.amount, expected.amount, 0.001);
+        assert_eq!(actual.units, expected.units);
+
+        let actual = expected - difference;
+        assert_approx_eq!(actual.amount, temperature.amount, 0.001);
+        assert_eq!(actual.units, temperature.units);
+    }
+
+    #[test]
+    fn can_add_temperature_differences_together() {
+        let actual = Quantity::new(1.0, TemperatureDifference::Celsius)
+            + Quantity::new(9.0, TemperatureDifference::Fahrenheit);
+        assert_approx_eq!(actual.amount, 6.0, 0.0001);
+        assert_eq!(actual.units, TemperatureDifference::Celsius);
+    }
 }
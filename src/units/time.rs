@@ -1,6 +1,13 @@
-use super::quantity::Unit;
+use crate::math::Float;
+
+use super::{
+    format::UnitNames,
+    parse::UnitAliases,
+    quantity::{AdditiveUnit, Unit},
+};
 
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Time {
     Milliseconds = 1,
     Seconds = 2,
@@ -10,7 +17,7 @@ pub enum Time {
 }
 
 impl Unit for Time {
-    fn multiplier_to_base(&self) -> f32 {
+    fn multiplier_to_base(&self) -> Float {
         match self {
             Time::Milliseconds => 0.001,
             Time::Seconds => 1.0,
@@ -21,10 +28,73 @@ impl Unit for Time {
     }
 }
 
+impl AdditiveUnit for Time {}
+
+impl UnitAliases for Time {
+    fn all() -> &'static [Self] {
+        &[
+            Time::Milliseconds,
+            Time::Seconds,
+            Time::Minutes,
+            Time::Hours,
+            Time::Days,
+        ]
+    }
+
+    fn aliases(&self) -> &'static [&'static str] {
+        match self {
+            Time::Milliseconds => &["ms", "msec", "millisecond", "milliseconds"],
+            Time::Seconds => &["s", "sec", "secs", "second", "seconds"],
+            Time::Minutes => &["m", "min", "mins", "minute", "minutes"],
+            Time::Hours => &["h", "hr", "hrs", "hour", "hours"],
+            Time::Days => &["d", "day", "days"],
+        }
+    }
+}
+
+impl UnitNames for Time {
+    fn symbol(&self) -> &'static str {
+        match self {
+            Time::Milliseconds => "ms",
+            Time::Seconds => "s",
+            Time::Minutes => "min",
+            Time::Hours => "h",
+            Time::Days => "d",
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        match self {
+            Time::Milliseconds => "millisecond",
+            Time::Seconds => "second",
+            Time::Minutes => "minute",
+            Time::Hours => "hour",
+            Time::Days => "day",
+        }
+    }
+
+    fn plural_name(&self) -> &'static str {
+        match self {
+            Time::Milliseconds => "milliseconds",
+            Time::Seconds => "seconds",
+            Time::Minutes => "minutes",
+            Time::Hours => "hours",
+            Time::Days => "days",
+        }
+    }
+}
+
+impl std::fmt::Display for Time {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.symbol())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use rstest::rstest;
 
+    use crate::math::Float;
     use crate::units::{
         quantity::{Convertable, Quantity},
         time::Time,
@@ -44,10 +114,10 @@ mod tests {
     #[case(1.0, Time::Days, Time::Hours, 24.0)]
     #[case(1.0, Time::Minutes, Time::Milliseconds, 60000.0)]
     fn can_convert_between_time_units(
-        #[case] amount: f32,
+        #[case] amount: Float,
         #[case] units: Time,
         #[case] to_units: Time,
-        #[case] expected_amount: f32,
+        #[case] expected_amount: Float,
     ) {
         let actual = Quantity { amount, units }.convert(to_units);
         assert!(
@@ -1,6 +1,13 @@
-use super::quantity::Unit;
+use crate::math::Float;
+
+use super::{
+    format::UnitNames,
+    parse::UnitAliases,
+    quantity::{AdditiveUnit, Unit},
+};
 
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Volume {
     Liters = 1,
     Milliliter = 2,
@@ -21,7 +28,7 @@ pub enum Volume {
 }
 
 impl Unit for Volume {
-    fn multiplier_to_base(&self) -> f32 {
+    fn multiplier_to_base(&self) -> Float {
         match self {
             Volume::Liters => 1.0,
             Volume::Milliliter => 0.001,
@@ -43,10 +50,172 @@ impl Unit for Volume {
     }
 }
 
+impl AdditiveUnit for Volume {}
+
+impl UnitAliases for Volume {
+    fn all() -> &'static [Self] {
+        &[
+            Volume::Liters,
+            Volume::Milliliter,
+            Volume::USCups,
+            Volume::USPints,
+            Volume::USQuarts,
+            Volume::USOunces,
+            Volume::USGallons,
+            Volume::ImperialCups,
+            Volume::ImperialPints,
+            Volume::ImperialQuarts,
+            Volume::ImperialOunces,
+            Volume::ImperialGallons,
+            Volume::USTeaspoons,
+            Volume::USTablespoons,
+            Volume::ImperialTeaspoons,
+            Volume::ImperialTablespoons,
+        ]
+    }
+
+    fn aliases(&self) -> &'static [&'static str] {
+        match self {
+            Volume::Liters => &["l", "liter", "liters", "litre", "litres"],
+            Volume::Milliliter => &[
+                "ml",
+                "cc",
+                "milliliter",
+                "milliliters",
+                "millilitre",
+                "millilitres",
+            ],
+            Volume::USCups => &["cup", "cups", "us cup", "us cups"],
+            Volume::USPints => &["pt", "pint", "pints", "us pt", "us pint", "us pints"],
+            Volume::USQuarts => &["qt", "quart", "quarts", "us qt", "us quart", "us quarts"],
+            Volume::USOunces => &[
+                "fl oz",
+                "oz",
+                "fluid ounce",
+                "fluid ounces",
+                "us fl oz",
+                "us fluid ounce",
+                "us fluid ounces",
+            ],
+            Volume::USGallons => &[
+                "gal",
+                "gallon",
+                "gallons",
+                "us gal",
+                "us gallon",
+                "us gallons",
+            ],
+            Volume::ImperialCups => &["imp cup", "imp cups", "imperial cup", "imperial cups"],
+            Volume::ImperialPints => &["imp pt", "imperial pint", "imperial pints"],
+            Volume::ImperialQuarts => &["imp qt", "imperial quart", "imperial quarts"],
+            Volume::ImperialOunces => &[
+                "imp fl oz",
+                "imp oz",
+                "imperial fluid ounce",
+                "imperial fluid ounces",
+            ],
+            Volume::ImperialGallons => &["imp gal", "imperial gallon", "imperial gallons"],
+            Volume::USTeaspoons => &[
+                "tsp",
+                "teaspoon",
+                "teaspoons",
+                "us tsp",
+                "us teaspoon",
+                "us teaspoons",
+            ],
+            Volume::USTablespoons => &[
+                "tbsp",
+                "tbs",
+                "tablespoon",
+                "tablespoons",
+                "us tbsp",
+                "us tablespoon",
+                "us tablespoons",
+            ],
+            Volume::ImperialTeaspoons => &["imp tsp", "imperial teaspoon", "imperial teaspoons"],
+            Volume::ImperialTablespoons => {
+                &["imp tbsp", "imperial tablespoon", "imperial tablespoons"]
+            }
+        }
+    }
+}
+
+impl UnitNames for Volume {
+    fn symbol(&self) -> &'static str {
+        match self {
+            Volume::Liters => "L",
+            Volume::Milliliter => "mL",
+            Volume::USCups => "cup",
+            Volume::USPints => "pt",
+            Volume::USQuarts => "qt",
+            Volume::USOunces => "fl oz",
+            Volume::USGallons => "gal",
+            Volume::ImperialCups => "imp cup",
+            Volume::ImperialPints => "imp pt",
+            Volume::ImperialQuarts => "imp qt",
+            Volume::ImperialOunces => "imp fl oz",
+            Volume::ImperialGallons => "imp gal",
+            Volume::USTeaspoons => "tsp",
+            Volume::USTablespoons => "tbsp",
+            Volume::ImperialTeaspoons => "imp tsp",
+            Volume::ImperialTablespoons => "imp tbsp",
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        match self {
+            Volume::Liters => "liter",
+            Volume::Milliliter => "milliliter",
+            Volume::USCups => "US cup",
+            Volume::USPints => "US pint",
+            Volume::USQuarts => "US quart",
+            Volume::USOunces => "US fluid ounce",
+            Volume::USGallons => "US gallon",
+            Volume::ImperialCups => "imperial cup",
+            Volume::ImperialPints => "imperial pint",
+            Volume::ImperialQuarts => "imperial quart",
+            Volume::ImperialOunces => "imperial fluid ounce",
+            Volume::ImperialGallons => "imperial gallon",
+            Volume::USTeaspoons => "US teaspoon",
+            Volume::USTablespoons => "US tablespoon",
+            Volume::ImperialTeaspoons => "imperial teaspoon",
+            Volume::ImperialTablespoons => "imperial tablespoon",
+        }
+    }
+
+    fn plural_name(&self) -> &'static str {
+        match self {
+            Volume::Liters => "liters",
+            Volume::Milliliter => "milliliters",
+            Volume::USCups => "US cups",
+            Volume::USPints => "US pints",
+            Volume::USQuarts => "US quarts",
+            Volume::USOunces => "US fluid ounces",
+            Volume::USGallons => "US gallons",
+            Volume::ImperialCups => "imperial cups",
+            Volume::ImperialPints => "imperial pints",
+            Volume::ImperialQuarts => "imperial quarts",
+            Volume::ImperialOunces => "imperial fluid ounces",
+            Volume::ImperialGallons => "imperial gallons",
+            Volume::USTeaspoons => "US teaspoons",
+            Volume::USTablespoons => "US tablespoons",
+            Volume::ImperialTeaspoons => "imperial teaspoons",
+            Volume::ImperialTablespoons => "imperial tablespoons",
+        }
+    }
+}
+
+impl std::fmt::Display for Volume {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.symbol())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use rstest::rstest;
 
+    use crate::math::Float;
     use crate::units::{
         quantity::{Convertable, Quantity},
         volume::Volume,
@@ -64,10 +233,10 @@ mod tests {
     #[case(1.0, Volume::USTablespoons, Volume::USTeaspoons, 3.0)]
     #[case(1.0, Volume::ImperialTablespoons, Volume::ImperialTeaspoons, 3.0)]
     fn can_convert_between_volume_units(
-        #[case] amount: f32,
+        #[case] amount: Float,
         #[case] units: Volume,
         #[case] to_units: Volume,
-        #[case] expected_amount: f32,
+        #[case] expected_amount: Float,
     ) {
         let actual = Quantity { amount, units }.convert(to_units);
         assert!(
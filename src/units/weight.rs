@@ -1,6 +1,13 @@
-use super::quantity::Unit;
+use crate::math::Float;
+
+use super::{
+    format::UnitNames,
+    parse::UnitAliases,
+    quantity::{AdditiveUnit, Unit},
+};
 
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Weight {
     Pounds = 1,
     Ounces = 2,
@@ -9,7 +16,7 @@ pub enum Weight {
 }
 
 impl Unit for Weight {
-    fn multiplier_to_base(&self) -> f32 {
+    fn multiplier_to_base(&self) -> Float {
         match self {
             Weight::Pounds => 453.592,
             Weight::Ounces => 28.3495,
@@ -19,11 +26,71 @@ impl Unit for Weight {
     }
 }
 
+impl AdditiveUnit for Weight {}
+
+impl UnitAliases for Weight {
+    fn all() -> &'static [Self] {
+        &[
+            Weight::Pounds,
+            Weight::Ounces,
+            Weight::Kilograms,
+            Weight::Grams,
+        ]
+    }
+
+    fn aliases(&self) -> &'static [&'static str] {
+        match self {
+            Weight::Pounds => &["lb", "lbs", "pound", "pounds"],
+            Weight::Ounces => &["oz", "ounce", "ounces"],
+            Weight::Kilograms => &["kg", "kgs", "kilo", "kilos", "kilogram", "kilograms"],
+            Weight::Grams => &["g", "gram", "grams", "gramme", "grammes"],
+        }
+    }
+}
+
+impl UnitNames for Weight {
+    fn symbol(&self) -> &'static str {
+        match self {
+            Weight::Pounds => "lb",
+            Weight::Ounces => "oz",
+            Weight::Kilograms => "kg",
+            Weight::Grams => "g",
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        match self {
+            Weight::Pounds => "pound",
+            Weight::Ounces => "ounce",
+            Weight::Kilograms => "kilogram",
+            Weight::Grams => "gram",
+        }
+    }
+
+    fn plural_name(&self) -> &'static str {
+        match self {
+            Weight::Pounds => "pounds",
+            Weight::Ounces => "ounces",
+            Weight::Kilograms => "kilograms",
+            Weight::Grams => "grams",
+        }
+    }
+}
+
+impl std::fmt::Display for Weight {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.symbol())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use rstest::rstest;
 
-    use crate::units::quantity::{Convertable, Quantity};
+    use crate::{
+        math::Float,
+        units::quantity::{Convertable, Quantity},
+    };
 
     use super::Weight;
 
@@ -33,10 +100,10 @@ mod tests {
     #[case(4.0, Weight::Ounces, Weight::Pounds, 0.25)]
     #[case(4.0, Weight::Grams, Weight::Ounces, 0.141096)]
     fn can_convert_between_weight_units(
-        #[case] amount: f32,
+        #[case] amount: Float,
         #[case] units: Weight,
         #[case] to_units: Weight,
-        #[case] expected_amount: f32,
+        #[case] expected_amount: Float,
     ) {
         let actual = Quantity { amount, units }.convert(to_units);
         assert!(